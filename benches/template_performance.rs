@@ -25,8 +25,10 @@ fn bench_todo_generation(c: &mut Criterion) {
                     project_context: None,
                     quality_config: None,
                     max_todos: Some(20),
+                    max_requirements: None,
                     include_estimates: true,
                     default_priority: None,
+                    id_scheme: pdmt::models::todo::TodoIdScheme::default(),
                 };
 
                 engine.generate("todo_list", input).await.unwrap()
@@ -35,5 +37,47 @@ fn bench_todo_generation(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_todo_generation);
+fn bench_batch_generation(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let mut engine = TemplateEngine::new();
+    rt.block_on(engine.load_builtin_templates()).unwrap();
+
+    let inputs: Vec<TodoInput> = (0..100)
+        .map(|idx| TodoInput {
+            project_name: format!("Benchmark Project {}", idx),
+            requirements: vec![
+                "Implement authentication".to_string(),
+                "Create REST API".to_string(),
+            ],
+            granularity: pdmt::models::todo::TodoGranularity::High,
+            project_context: None,
+            quality_config: None,
+            max_todos: Some(20),
+            max_requirements: None,
+            include_estimates: true,
+            default_priority: None,
+            id_scheme: pdmt::models::todo::TodoIdScheme::default(),
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("generate_100_documents");
+    group.bench_function("individual", |b| {
+        b.iter(|| {
+            rt.block_on(async {
+                for input in &inputs {
+                    engine.generate("todo_list", input).await.unwrap();
+                }
+            });
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| {
+            rt.block_on(engine.generate_batch("todo_list", inputs.iter().collect()))
+                .unwrap();
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_todo_generation, bench_batch_generation);
 criterion_main!(benches);
@@ -8,7 +8,11 @@
 use clap::{Parser, ValueEnum};
 use console::{style, Term};
 use dialoguer::{Confirm, Input, Select};
-use pdmt::models::todo::{Todo, TodoGranularity, TodoInput, TodoList, TodoPriority};
+use pdmt::models::generator::{
+    estimate_task_hours, generate_todos, todos_per_requirement, TaskTemplateLibrary,
+};
+use pdmt::models::markdown::MarkdownOptions;
+use pdmt::models::todo::{TodoGranularity, TodoIdScheme, TodoInput, TodoList, TodoPriority};
 // JSON and collections used for structured data handling
 
 #[derive(Parser, Debug)]
@@ -244,8 +248,10 @@ async fn get_interactive_input(args: &Args) -> Result<TodoInput, Box<dyn std::er
         project_context: None,
         quality_config: None,
         max_todos,
+        max_requirements: None,
         include_estimates,
         default_priority: Some(TodoPriority::Medium),
+        id_scheme: TodoIdScheme::default(),
     })
 }
 
@@ -274,8 +280,10 @@ fn get_args_input(args: &Args) -> Result<TodoInput, Box<dyn std::error::Error>>
         project_context: None,
         quality_config: None,
         max_todos: Some(args.max_todos),
+        max_requirements: None,
         include_estimates: args.estimates,
         default_priority: Some(TodoPriority::Medium),
+        id_scheme: TodoIdScheme::default(),
     })
 }
 
@@ -286,47 +294,14 @@ async fn generate_deterministic_todos(
 ) -> Result<TodoList, Box<dyn std::error::Error>> {
     let term = Term::stdout();
 
-    // Create deterministic todo list based on input
-    let mut todo_list = TodoList::new();
-
-    // Generate todos based on requirements and granularity
-    let todos_per_requirement = match input.granularity {
-        TodoGranularity::High => 4,
-        TodoGranularity::Medium => 2,
-        TodoGranularity::Low => 1,
-    };
-
-    let max_todos = input.max_todos.unwrap_or(20);
-    let mut todo_count = 0;
-
     if verbose {
         term.write_line(&format!(
             "  Generating {} todos per requirement",
-            todos_per_requirement
+            todos_per_requirement(input.granularity)
         ))?;
     }
 
-    for (req_idx, requirement) in input.requirements.iter().enumerate() {
-        if todo_count >= max_todos {
-            break;
-        }
-
-        let todos_for_req = generate_todos_for_requirement(
-            requirement,
-            req_idx,
-            todos_per_requirement,
-            &input.granularity,
-            input.include_estimates,
-        )?;
-
-        for todo in todos_for_req {
-            if todo_count >= max_todos {
-                break;
-            }
-            todo_list.add_todo(todo);
-            todo_count += 1;
-        }
-    }
+    let mut todo_list = generate_todos(input, &TaskTemplateLibrary::default())?;
 
     // Apply quality gates if requested
     if quality_mode {
@@ -341,216 +316,6 @@ async fn generate_deterministic_todos(
     Ok(todo_list)
 }
 
-fn generate_todos_for_requirement(
-    requirement: &str,
-    req_idx: usize,
-    count: usize,
-    granularity: &TodoGranularity,
-    include_estimates: bool,
-) -> Result<Vec<Todo>, Box<dyn std::error::Error>> {
-    let mut todos = Vec::new();
-
-    // Define task templates based on granularity
-    let task_templates = match granularity {
-        TodoGranularity::High => get_high_granularity_templates(requirement),
-        TodoGranularity::Medium => get_medium_granularity_templates(requirement),
-        TodoGranularity::Low => get_low_granularity_templates(requirement),
-    };
-
-    for (idx, template) in task_templates.iter().take(count).enumerate() {
-        let mut todo = Todo::new(template);
-        todo.id = format!("todo_{}_{}", req_idx, idx);
-
-        // Set deterministic priority based on position
-        todo.priority = match idx {
-            0 => TodoPriority::High,                  // First task is high priority
-            n if n == count - 1 => TodoPriority::Low, // Last task is low priority
-            _ => TodoPriority::Medium,                // Middle tasks are medium priority
-        };
-
-        // Add time estimates if requested
-        if include_estimates {
-            todo.estimated_hours = Some(estimate_hours_for_task(template));
-        }
-
-        // Add dependencies for sequential tasks
-        if idx > 0 {
-            todo.dependencies
-                .push(format!("todo_{}_{}", req_idx, idx - 1));
-        }
-
-        // Add tags based on task content
-        todo.tags = extract_tags_from_content(template);
-
-        todos.push(todo);
-    }
-
-    Ok(todos)
-}
-
-fn get_high_granularity_templates(requirement: &str) -> Vec<String> {
-    let req_lower = requirement.to_lowercase();
-
-    if req_lower.contains("auth") {
-        vec![
-            "Design user authentication schema".to_string(),
-            "Implement user registration endpoint".to_string(),
-            "Create login/logout functionality".to_string(),
-            "Add password hashing and validation".to_string(),
-            "Implement JWT token management".to_string(),
-            "Create user session handling".to_string(),
-        ]
-    } else if req_lower.contains("api") || req_lower.contains("endpoint") {
-        vec![
-            "Design API endpoint specifications".to_string(),
-            "Implement CRUD operations for core entities".to_string(),
-            "Add request validation middleware".to_string(),
-            "Create API response formatting".to_string(),
-            "Implement error handling for API routes".to_string(),
-            "Add API rate limiting".to_string(),
-        ]
-    } else if req_lower.contains("database") {
-        vec![
-            "Design database schema and relationships".to_string(),
-            "Create database migration scripts".to_string(),
-            "Implement database connection pool".to_string(),
-            "Add database query optimization".to_string(),
-            "Create data access layer".to_string(),
-            "Implement database backup strategy".to_string(),
-        ]
-    } else if req_lower.contains("test") {
-        vec![
-            "Create unit test framework setup".to_string(),
-            "Write unit tests for core functions".to_string(),
-            "Implement integration test suite".to_string(),
-            "Add API endpoint testing".to_string(),
-            "Create test data fixtures".to_string(),
-            "Setup test coverage reporting".to_string(),
-        ]
-    } else {
-        vec![
-            format!("Analyze requirements for {}", requirement),
-            format!("Design solution architecture for {}", requirement),
-            format!("Implement core functionality for {}", requirement),
-            format!("Add error handling for {}", requirement),
-            format!("Create documentation for {}", requirement),
-            format!("Test and validate {}", requirement),
-        ]
-    }
-}
-
-fn get_medium_granularity_templates(requirement: &str) -> Vec<String> {
-    let req_lower = requirement.to_lowercase();
-
-    if req_lower.contains("auth") {
-        vec![
-            "Implement user authentication system".to_string(),
-            "Create authentication middleware and security".to_string(),
-        ]
-    } else if req_lower.contains("api") {
-        vec![
-            "Design and implement REST API endpoints".to_string(),
-            "Add API validation and error handling".to_string(),
-        ]
-    } else if req_lower.contains("database") {
-        vec![
-            "Setup database schema and connections".to_string(),
-            "Implement data access layer".to_string(),
-        ]
-    } else if req_lower.contains("test") {
-        vec![
-            "Create comprehensive test suite".to_string(),
-            "Setup test automation and coverage".to_string(),
-        ]
-    } else {
-        vec![
-            format!("Implement {}", requirement),
-            format!("Test and document {}", requirement),
-        ]
-    }
-}
-
-fn get_low_granularity_templates(requirement: &str) -> Vec<String> {
-    vec![format!("Implement complete {}", requirement)]
-}
-
-fn estimate_hours_for_task(task: &str) -> f32 {
-    let task_lower = task.to_lowercase();
-
-    // Base estimate
-    let mut hours: f32 = 2.0;
-
-    // Adjust based on complexity keywords
-    if task_lower.contains("design") || task_lower.contains("architect") {
-        hours += 2.0;
-    }
-    if task_lower.contains("implement") || task_lower.contains("create") {
-        hours += 3.0;
-    }
-    if task_lower.contains("test") || task_lower.contains("validate") {
-        hours += 1.5;
-    }
-    if task_lower.contains("database") || task_lower.contains("schema") {
-        hours += 2.0;
-    }
-    if task_lower.contains("api") || task_lower.contains("endpoint") {
-        hours += 1.5;
-    }
-    if task_lower.contains("security") || task_lower.contains("auth") {
-        hours += 3.0;
-    }
-    if task_lower.contains("integration") || task_lower.contains("middleware") {
-        hours += 2.5;
-    }
-
-    // Cap at reasonable limits
-    hours.max(0.5).min(16.0)
-}
-
-fn extract_tags_from_content(content: &str) -> Vec<String> {
-    let content_lower = content.to_lowercase();
-    let mut tags = Vec::new();
-
-    // Technical area tags
-    if content_lower.contains("auth") || content_lower.contains("login") {
-        tags.push("authentication".to_string());
-    }
-    if content_lower.contains("api") || content_lower.contains("endpoint") {
-        tags.push("api".to_string());
-    }
-    if content_lower.contains("database") || content_lower.contains("schema") {
-        tags.push("database".to_string());
-    }
-    if content_lower.contains("test") || content_lower.contains("validate") {
-        tags.push("testing".to_string());
-    }
-    if content_lower.contains("security") {
-        tags.push("security".to_string());
-    }
-    if content_lower.contains("frontend") || content_lower.contains("ui") {
-        tags.push("frontend".to_string());
-    }
-    if content_lower.contains("backend") {
-        tags.push("backend".to_string());
-    }
-
-    // Task type tags
-    if content_lower.starts_with("design") {
-        tags.push("design".to_string());
-    }
-    if content_lower.starts_with("implement") || content_lower.starts_with("create") {
-        tags.push("implementation".to_string());
-    }
-    if content_lower.starts_with("test") {
-        tags.push("testing".to_string());
-    }
-    if content_lower.contains("document") {
-        tags.push("documentation".to_string());
-    }
-
-    tags
-}
-
 fn apply_quality_gates(todo_list: &mut TodoList) -> Result<(), Box<dyn std::error::Error>> {
     for todo in &mut todo_list.todos {
         // Apply quality checks
@@ -577,7 +342,7 @@ fn apply_quality_gates(todo_list: &mut TodoList) -> Result<(), Box<dyn std::erro
 
         if !todo.quality_gates.time_estimate_check {
             // Add default estimate
-            todo.estimated_hours = Some(estimate_hours_for_task(&todo.content));
+            todo.estimated_hours = Some(estimate_task_hours(&todo.content));
             todo.quality_gates.time_estimate_check = true;
         }
     }
@@ -685,53 +450,7 @@ fn show_quality_summary(
 }
 
 fn format_as_markdown(todo_list: &TodoList) -> Result<String, Box<dyn std::error::Error>> {
-    let mut markdown = String::new();
-
-    markdown.push_str("# Todo List\n\n");
-
-    // Add metadata
-    markdown.push_str("## Summary\n\n");
-    markdown.push_str(&format!(
-        "- **Total todos**: {}\n",
-        todo_list.metadata.total_count
-    ));
-    markdown.push_str(&format!(
-        "- **Total estimated hours**: {:.1}\n",
-        todo_list.metadata.total_estimated_hours
-    ));
-    markdown.push_str(&format!(
-        "- **Average hours per task**: {:.1}\n",
-        todo_list.metadata.avg_estimated_hours
-    ));
-    markdown.push_str("\n");
-
-    // Add todos
-    markdown.push_str("## Tasks\n\n");
-    for (idx, todo) in todo_list.todos.iter().enumerate() {
-        markdown.push_str(&format!("### {}. {}\n\n", idx + 1, todo.content));
-        markdown.push_str(&format!("- **ID**: `{}`\n", todo.id));
-        markdown.push_str(&format!("- **Status**: {}\n", todo.status));
-        markdown.push_str(&format!("- **Priority**: {}\n", todo.priority));
-
-        if let Some(hours) = todo.estimated_hours {
-            markdown.push_str(&format!("- **Estimated hours**: {:.1}\n", hours));
-        }
-
-        if !todo.dependencies.is_empty() {
-            markdown.push_str(&format!(
-                "- **Dependencies**: {}\n",
-                todo.dependencies.join(", ")
-            ));
-        }
-
-        if !todo.tags.is_empty() {
-            markdown.push_str(&format!("- **Tags**: {}\n", todo.tags.join(", ")));
-        }
-
-        markdown.push_str("\n");
-    }
-
-    Ok(markdown)
+    Ok(todo_list.to_markdown(MarkdownOptions::default()))
 }
 
 fn format_as_text(todo_list: &TodoList) -> Result<String, Box<dyn std::error::Error>> {
@@ -101,6 +101,17 @@ pub enum TemplateError {
         /// Maximum allowed size
         limit: usize,
     },
+
+    /// Generation quota exhausted
+    #[error("Generation quota exhausted: {used} of {limit} items used, {requested} requested")]
+    QuotaExceeded {
+        /// Items already generated in this session
+        used: usize,
+        /// Items the rejected generation would have produced
+        requested: usize,
+        /// Maximum items allowed in this session
+        limit: usize,
+    },
 }
 
 /// Quality validation errors
@@ -285,6 +296,17 @@ pub enum TodoValidationError {
         limit: usize,
     },
 
+    /// Requirement count exceeds limit
+    #[error(
+        "Requirement count {count} exceeds limit {limit}; split the input into smaller batches"
+    )]
+    RequirementLimit {
+        /// Actual count
+        count: usize,
+        /// Maximum allowed
+        limit: usize,
+    },
+
     /// Dependency not found
     #[error("Dependency '{dependency}' not found for todo '{id}'")]
     DependencyNotFound {
@@ -339,6 +361,12 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<toml::ser::Error> for Error {
+    fn from(err: toml::ser::Error) -> Self {
+        Self::Serialization(err.to_string())
+    }
+}
+
 impl From<handlebars::RenderError> for TemplateError {
     fn from(err: handlebars::RenderError) -> Self {
         Self::RenderingFailed {
@@ -401,6 +429,13 @@ impl TemplateError {
         }
     }
 
+    /// Create an inheritance error
+    pub fn inheritance<S: Into<String>>(message: S) -> Self {
+        Self::InheritanceError {
+            message: message.into(),
+        }
+    }
+
     /// Create a size limit error
     #[must_use]
     pub const fn size_limit(size: usize, limit: usize) -> Self {
@@ -205,12 +205,55 @@ pub mod utils {
         Ok(())
     }
 
+    /// Strip a leading UTF-8 byte order mark, as saved by some Windows editors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pdmt::utils::strip_bom;
+    ///
+    /// assert_eq!(strip_bom("\u{feff}id: todo_list"), "id: todo_list");
+    /// assert_eq!(strip_bom("id: todo_list"), "id: todo_list");
+    /// ```
+    pub fn strip_bom(text: &str) -> &str {
+        text.strip_prefix('\u{feff}').unwrap_or(text)
+    }
+
+    /// Convert a count to `f32` for averages and ratios
+    ///
+    /// Counts above 2^24 lose precision, which is far beyond any list this
+    /// crate handles, so the lossy cast is accepted here in one place.
+    #[cfg(feature = "todo-validation")]
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) const fn count_as_f32(count: usize) -> f32 {
+        count as f32
+    }
+
     /// Generate a unique content ID
     #[cfg(feature = "todo-validation")]
     pub fn generate_content_id() -> String {
         uuid::Uuid::new_v4().to_string()
     }
 
+    /// Namespace of the name-based UUIDs made by [`generate_seeded_content_id`]
+    #[cfg(feature = "todo-validation")]
+    pub const CONTENT_ID_NAMESPACE: uuid::Uuid =
+        uuid::Uuid::from_u128(0x5f0e_8c1a_3b7d_4e29_a6c4_9d21_7b38_e05f);
+
+    /// Generate a content ID derived from a seed and key
+    ///
+    /// Returns a version 5 UUID in [`CONTENT_ID_NAMESPACE`] named by the key and the
+    /// seed, so the same seed and key always produce the same ID across runs
+    /// and platforms.
+    #[cfg(feature = "todo-validation")]
+    pub fn generate_seeded_content_id(seed: u64, key: &str) -> String {
+        uuid::Uuid::new_v5(
+            &CONTENT_ID_NAMESPACE,
+            format!("{}\0{}", key, seed).as_bytes(),
+        )
+        .to_string()
+    }
+
     /// Get current timestamp for content generation
     #[cfg(feature = "todo-validation")]
     pub fn current_timestamp() -> chrono::DateTime<chrono::Utc> {
@@ -251,4 +294,15 @@ mod tests {
         assert_ne!(id1, id2);
         assert!(!id1.is_empty());
     }
+
+    #[cfg(feature = "todo-validation")]
+    #[test]
+    fn test_seeded_content_id_generation() {
+        let id = utils::generate_seeded_content_id(42, "todo_list");
+        assert_eq!(id, utils::generate_seeded_content_id(42, "todo_list"));
+        assert_ne!(id, utils::generate_seeded_content_id(43, "todo_list"));
+        assert_ne!(id, utils::generate_seeded_content_id(42, "readme"));
+        let parsed = uuid::Uuid::parse_str(&id).unwrap();
+        assert_eq!(parsed.get_version(), Some(uuid::Version::Sha1));
+    }
 }
@@ -4,4 +4,4 @@
 pub mod tools;
 
 #[cfg(feature = "mcp-tools")]
-pub use tools::create_template_tool;
+pub use tools::{create_template_tool, handle_template_tool};
@@ -1,5 +1,9 @@
 //! MCP tool definitions
 
+use crate::error::{McpError, Result};
+use crate::models::content::ContentFormat;
+use crate::template::engine::TemplateEngine;
+use crate::template::schema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -41,6 +45,11 @@ pub fn create_template_tool() -> ToolDefinition {
                     "enum": ["yaml", "json", "markdown"],
                     "default": "yaml",
                     "description": "Output format"
+                },
+                "seed": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Seed for reproducible content IDs and timestamps"
                 }
             },
             "required": ["template_id", "input"],
@@ -48,3 +57,132 @@ pub fn create_template_tool() -> ToolDefinition {
         }),
     }
 }
+
+/// Arguments accepted by the deterministic template tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateToolInput {
+    /// ID of the template to use
+    pub template_id: String,
+
+    /// Input data for template generation
+    pub input: Value,
+
+    /// Output format
+    #[serde(default = "default_output_format")]
+    pub output_format: ContentFormat,
+
+    /// Seed for reproducible content IDs and timestamps
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+const fn default_output_format() -> ContentFormat {
+    ContentFormat::Yaml
+}
+
+/// Handle a call to the deterministic template tool
+///
+/// Validates the arguments against the tool's input schema, generates the
+/// content and converts it to the requested format. When a `seed` is given,
+/// the content ID is derived from the seed and output and the timestamp is
+/// pinned to the Unix epoch, so repeated calls return identical results.
+pub async fn handle_template_tool(engine: &TemplateEngine, arguments: Value) -> Result<Value> {
+    schema::ensure_valid(&create_template_tool().input_schema, &arguments)?;
+    let args: TemplateToolInput =
+        serde_json::from_value(arguments).map_err(|e| McpError::InvalidRequest {
+            message: e.to_string(),
+        })?;
+
+    let generated = engine.generate(&args.template_id, &args.input).await?;
+
+    #[cfg_attr(not(feature = "todo-validation"), allow(unused_mut))]
+    let mut response = serde_json::json!({
+        "template_id": generated.template_id,
+        "content": generated.as_format(args.output_format)?,
+        "format": args.output_format,
+    });
+
+    #[cfg(feature = "todo-validation")]
+    {
+        let (id, generated_at) = match args.seed {
+            Some(seed) => {
+                let key = format!("{}\n{}", args.template_id, generated.content);
+                // Pin the clock to the Unix epoch
+                (
+                    crate::utils::generate_seeded_content_id(seed, &key),
+                    chrono::DateTime::<chrono::Utc>::default(),
+                )
+            }
+            None => (generated.id.clone(), generated.generated_at),
+        };
+        response["id"] = Value::String(id);
+        response["generated_at"] = serde_json::to_value(generated_at)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    async fn engine() -> TemplateEngine {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        engine
+    }
+
+    fn arguments(seed: Option<u64>) -> Value {
+        let mut args = json!({
+            "template_id": "todo_list",
+            "input": {"project_name": "Seeded", "requirements": ["login", "logout"]},
+            "output_format": "json"
+        });
+        if let Some(seed) = seed {
+            args["seed"] = json!(seed);
+        }
+        args
+    }
+
+    #[tokio::test]
+    async fn test_seeded_calls_are_identical() {
+        let engine = engine().await;
+
+        let first = handle_template_tool(&engine, arguments(Some(7)))
+            .await
+            .unwrap();
+        let second = handle_template_tool(&engine, arguments(Some(7)))
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+
+        #[cfg(feature = "todo-validation")]
+        {
+            let other = handle_template_tool(&engine, arguments(Some(8)))
+                .await
+                .unwrap();
+            assert_ne!(first["id"], other["id"]);
+
+            let unseeded = handle_template_tool(&engine, arguments(None))
+                .await
+                .unwrap();
+            assert_ne!(first["id"], unseeded["id"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_arguments_rejected() {
+        let engine = engine().await;
+
+        let err = handle_template_tool(&engine, json!({"template_id": "todo_list", "seed": -1}))
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required property 'input'"));
+    }
+}
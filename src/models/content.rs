@@ -23,6 +23,11 @@ pub struct GeneratedContent {
     #[cfg(feature = "quality-proxy")]
     pub quality_report: Option<crate::models::quality::QualityReport>,
 
+    /// Todo list validation result, when the engine validates generated todos
+    #[cfg(feature = "todo-validation")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<crate::validators::todo::TodoValidationResult>,
+
     /// Generation timestamp
     #[cfg(feature = "todo-validation")]
     pub generated_at: chrono::DateTime<chrono::Utc>,
@@ -53,6 +58,10 @@ pub struct GenerationMetadata {
     #[cfg(feature = "quality-proxy")]
     pub refactoring_applied: bool,
 
+    /// Names of post-processors applied to the rendered output, in order
+    #[serde(default)]
+    pub post_processors: Vec<String>,
+
     /// Custom metadata fields
     pub custom_fields: HashMap<String, serde_json::Value>,
 }
@@ -69,6 +78,8 @@ pub enum ContentFormat {
     Markdown,
     /// Plain text
     Text,
+    /// TOML format
+    Toml,
 }
 
 /// Content validation status
@@ -87,6 +98,140 @@ pub enum ValidationStatus {
     Pending,
 }
 
+/// Largest comparison table [`ContentDiff::between`] will allocate
+///
+/// Bounds diff memory to about 32 MiB on 64-bit targets, enough for two
+/// differing regions of roughly 2000 lines each.
+pub const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// A line present in only one of two compared outputs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DiffLine {
+    /// Line only in the first output
+    Removed {
+        /// 1-based line number in the first output
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+    /// Line only in the second output
+    Added {
+        /// 1-based line number in the second output
+        line: usize,
+        /// Text of the line
+        text: String,
+    },
+}
+
+/// Line-level differences between two generated outputs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentDiff {
+    /// Removed and added lines, in document order
+    pub lines: Vec<DiffLine>,
+}
+
+impl ContentDiff {
+    /// Compute the line differences between two texts
+    ///
+    /// Lines are split on `\n` only, so trailing newlines and carriage
+    /// returns count as differences. Unchanged lines are not listed.
+    ///
+    /// After trimming the common prefix and suffix, the differing regions are
+    /// compared line by line only while the comparison table stays under
+    /// [`MAX_DIFF_CELLS`]; larger regions are reported as wholly removed and
+    /// then wholly added.
+    pub fn between(old: &str, new: &str) -> Self {
+        let old: Vec<&str> = old.split('\n').collect();
+        let new: Vec<&str> = new.split('\n').collect();
+
+        // Only the part between a common prefix and suffix needs comparing
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let a = &old[prefix..old.len() - suffix];
+        let b = &new[prefix..new.len() - suffix];
+
+        let width = b.len() + 1;
+        let cells = (a.len() + 1).checked_mul(width);
+        if cells.is_none_or(|cells| cells > MAX_DIFF_CELLS) {
+            return Self::replaced(prefix, a, b);
+        }
+
+        // common[i * width + j]: longest common subsequence of a[i..] and b[j..]
+        let mut common = vec![0usize; (a.len() + 1) * width];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                common[i * width + j] = if a[i] == b[j] {
+                    common[(i + 1) * width + j + 1] + 1
+                } else {
+                    common[(i + 1) * width + j].max(common[i * width + j + 1])
+                };
+            }
+        }
+
+        let mut lines = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            if i < a.len() && j < b.len() && a[i] == b[j] {
+                i += 1;
+                j += 1;
+            } else if j == b.len()
+                || (i < a.len() && common[(i + 1) * width + j] >= common[i * width + j + 1])
+            {
+                lines.push(DiffLine::Removed {
+                    line: prefix + i + 1,
+                    text: a[i].to_string(),
+                });
+                i += 1;
+            } else {
+                lines.push(DiffLine::Added {
+                    line: prefix + j + 1,
+                    text: b[j].to_string(),
+                });
+                j += 1;
+            }
+        }
+        Self { lines }
+    }
+
+    /// Coarse diff listing every line of `a` as removed and of `b` as added
+    fn replaced(prefix: usize, a: &[&str], b: &[&str]) -> Self {
+        let removed = a.iter().enumerate().map(|(i, text)| DiffLine::Removed {
+            line: prefix + i + 1,
+            text: (*text).to_string(),
+        });
+        let added = b.iter().enumerate().map(|(j, text)| DiffLine::Added {
+            line: prefix + j + 1,
+            text: (*text).to_string(),
+        });
+        Self {
+            lines: removed.chain(added).collect(),
+        }
+    }
+
+    /// Whether the texts had no differing lines
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl std::fmt::Display for ContentDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            match line {
+                DiffLine::Removed { line, text } => writeln!(f, "-{}: {}", line, text)?,
+                DiffLine::Added { line, text } => writeln!(f, "+{}: {}", line, text)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl GeneratedContent {
     /// Create new generated content
     pub fn new(template_id: String, content: String, input_data: serde_json::Value) -> Self {
@@ -98,6 +243,8 @@ impl GeneratedContent {
             #[cfg(feature = "quality-proxy")]
             quality_report: None,
             #[cfg(feature = "todo-validation")]
+            validation: None,
+            #[cfg(feature = "todo-validation")]
             generated_at: crate::utils::current_timestamp(),
             metadata: GenerationMetadata::default(),
             input_data,
@@ -121,7 +268,24 @@ impl GeneratedContent {
                 // Extract plain text from YAML content
                 self.to_plain_text()
             }
+            ContentFormat::Toml => self.to_toml(),
+        }
+    }
+
+    /// Get content as specified format, refusing lossy conversions
+    ///
+    /// Returns a constraint violation for formats where
+    /// [`ContentFormat::is_lossless`] is false; use [`as_format`](Self::as_format)
+    /// to convert anyway.
+    pub fn convert_checked(&self, format: ContentFormat) -> crate::Result<String> {
+        if !format.is_lossless() {
+            return Err(crate::error::ValidationError::Constraint {
+                constraint: "lossless_conversion".to_string(),
+                details: format!("converting to {} discards document structure", format),
+            }
+            .into());
         }
+        self.as_format(format)
     }
 
     /// Convert content to markdown format
@@ -196,6 +360,29 @@ impl GeneratedContent {
         Ok(())
     }
 
+    /// Convert YAML content to a TOML document
+    ///
+    /// TOML has no null and its documents are always tables, so content with
+    /// a non-mapping root or a null anywhere is rejected.
+    fn to_toml(&self) -> crate::Result<String> {
+        let value: serde_yaml::Value = serde_yaml::from_str(&self.content)?;
+        if !value.is_mapping() {
+            return Err(crate::Error::Serialization(format!(
+                "TOML requires a table at the top level, but the content is {}",
+                yaml_kind(&value)
+            )));
+        }
+        if let Some(path) = find_null(&value, "$") {
+            return Err(crate::Error::Serialization(format!(
+                "TOML cannot represent the null value at {}",
+                path
+            )));
+        }
+
+        let table = toml::Value::try_from(&value)?;
+        Ok(toml::to_string(&table)?)
+    }
+
     /// Convert content to plain text
     fn to_plain_text(&self) -> crate::Result<String> {
         let value: serde_yaml::Value = serde_yaml::from_str(&self.content)?;
@@ -248,6 +435,15 @@ impl GeneratedContent {
             .unwrap_or(false)
     }
 
+    /// Compare the content of two generations line by line
+    ///
+    /// Returns `None` when the content is byte-identical. Only `content` is
+    /// compared; IDs, timestamps and metadata such as `processing_time_ms`
+    /// differ between runs and are ignored.
+    pub fn diff(&self, other: &Self) -> Option<ContentDiff> {
+        (self.content != other.content).then(|| ContentDiff::between(&self.content, &other.content))
+    }
+
     /// Get processing time as duration
     pub const fn processing_duration(&self) -> std::time::Duration {
         std::time::Duration::from_millis(self.metadata.processing_time_ms)
@@ -263,11 +459,58 @@ impl Default for GenerationMetadata {
             validation_passes: 0,
             #[cfg(feature = "quality-proxy")]
             refactoring_applied: false,
+            post_processors: Vec::new(),
             custom_fields: HashMap::new(),
         }
     }
 }
 
+impl ContentFormat {
+    /// Whether converting content to this format preserves its structure
+    ///
+    /// YAML, JSON and TOML round-trip the parsed document (TOML conversion
+    /// fails rather than drop anything it cannot represent); Markdown and
+    /// plain text flatten it for display.
+    pub const fn is_lossless(&self) -> bool {
+        matches!(
+            self,
+            ContentFormat::Yaml | ContentFormat::Json | ContentFormat::Toml
+        )
+    }
+}
+
+/// Describe the kind of a YAML node for error messages
+const fn yaml_kind(value: &serde_yaml::Value) -> &'static str {
+    match value {
+        serde_yaml::Value::Null => "null",
+        serde_yaml::Value::Bool(_) => "a boolean",
+        serde_yaml::Value::Number(_) => "a number",
+        serde_yaml::Value::String(_) => "a string",
+        serde_yaml::Value::Sequence(_) => "a sequence",
+        serde_yaml::Value::Mapping(_) => "a mapping",
+        serde_yaml::Value::Tagged(_) => "a tagged value",
+    }
+}
+
+/// Path of the first null in a YAML document, e.g. `$.todos[0].assignee`
+fn find_null(value: &serde_yaml::Value, path: &str) -> Option<String> {
+    match value {
+        serde_yaml::Value::Null => Some(path.to_string()),
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .enumerate()
+            .find_map(|(i, item)| find_null(item, &format!("{}[{}]", path, i))),
+        serde_yaml::Value::Mapping(map) => map.iter().find_map(|(key, item)| {
+            let key = key
+                .as_str()
+                .map_or_else(|| format!("{:?}", key), str::to_string);
+            find_null(item, &format!("{}.{}", path, key))
+        }),
+        serde_yaml::Value::Tagged(tagged) => find_null(&tagged.value, path),
+        _ => None,
+    }
+}
+
 impl std::fmt::Display for ContentFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -275,6 +518,7 @@ impl std::fmt::Display for ContentFormat {
             ContentFormat::Json => write!(f, "json"),
             ContentFormat::Markdown => write!(f, "markdown"),
             ContentFormat::Text => write!(f, "text"),
+            ContentFormat::Toml => write!(f, "toml"),
         }
     }
 }
@@ -288,6 +532,7 @@ impl std::str::FromStr for ContentFormat {
             "json" => Ok(ContentFormat::Json),
             "markdown" | "md" => Ok(ContentFormat::Markdown),
             "text" | "txt" => Ok(ContentFormat::Text),
+            "toml" => Ok(ContentFormat::Toml),
             _ => Err(crate::Error::invalid_input(format!(
                 "Unknown format: {}",
                 s
@@ -314,6 +559,87 @@ mod tests {
         assert!(content.metadata.is_deterministic);
     }
 
+    #[test]
+    fn test_diff_ignores_volatile_metadata() {
+        let first = GeneratedContent::new("t".to_string(), "a\nb\nc".to_string(), json!({}));
+        let mut second = GeneratedContent::new("t".to_string(), "a\nb\nc".to_string(), json!({}));
+        second.metadata.processing_time_ms = 42;
+
+        assert!(first.diff(&second).is_none());
+    }
+
+    #[test]
+    fn test_diff_reports_changed_lines() {
+        let first = GeneratedContent::new(
+            "t".to_string(),
+            "todos:\n  - id: a\n  - id: b\n  - id: c".to_string(),
+            json!({}),
+        );
+        let second = GeneratedContent::new(
+            "t".to_string(),
+            "todos:\n  - id: a\n  - id: x\n  - id: c\n  - id: d".to_string(),
+            json!({}),
+        );
+
+        let diff = first.diff(&second).unwrap();
+        assert_eq!(
+            diff.lines,
+            vec![
+                DiffLine::Removed {
+                    line: 3,
+                    text: "  - id: b".to_string(),
+                },
+                DiffLine::Added {
+                    line: 3,
+                    text: "  - id: x".to_string(),
+                },
+                DiffLine::Added {
+                    line: 5,
+                    text: "  - id: d".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "-3:   - id: b\n+3:   - id: x\n+5:   - id: d\n"
+        );
+
+        // A trailing newline is a difference too
+        let trailing = ContentDiff::between("a", "a\n");
+        assert_eq!(
+            trailing.lines,
+            vec![DiffLine::Added {
+                line: 2,
+                text: String::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_large_diff_falls_back_to_replacement() {
+        let old: Vec<String> = (0..3000).map(|i| format!("old {i}")).collect();
+        let new: Vec<String> = (0..3000).map(|i| format!("new {i}")).collect();
+        let old = format!("header\n{}\nfooter", old.join("\n"));
+        let new = format!("header\n{}\nfooter", new.join("\n"));
+
+        let diff = ContentDiff::between(&old, &new);
+        assert_eq!(diff.lines.len(), 6000);
+        assert_eq!(
+            diff.lines[0],
+            DiffLine::Removed {
+                line: 2,
+                text: "old 0".to_string(),
+            }
+        );
+        assert_eq!(
+            diff.lines[3000],
+            DiffLine::Added {
+                line: 2,
+                text: "new 0".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_content_format_conversion() -> crate::Result<()> {
         let yaml_content = "todos:\n  - content: test task\n    status: pending";
@@ -364,6 +690,65 @@ mod tests {
         assert!("invalid".parse::<ContentFormat>().is_err());
     }
 
+    #[test]
+    fn test_lossless_conversion() {
+        assert!(ContentFormat::Yaml.is_lossless());
+        assert!(ContentFormat::Json.is_lossless());
+        assert!(!ContentFormat::Markdown.is_lossless());
+        assert!(!ContentFormat::Text.is_lossless());
+
+        let content = GeneratedContent::new(
+            "test".to_string(),
+            "todos:\n  - id: a\n    hours: 2\n".to_string(),
+            json!({}),
+        );
+
+        let json_output = content.convert_checked(ContentFormat::Json).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_output).unwrap();
+        assert_eq!(parsed, json!({"todos": [{"id": "a", "hours": 2}]}));
+
+        assert!(content.convert_checked(ContentFormat::Text).is_err());
+        assert!(content.as_format(ContentFormat::Text).is_ok());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let yaml = "name: demo\nversion: 2\nenabled: true\nowners:\n  - ada\n  - grace\nlimits:\n  hours: 1.5\n";
+        let content = GeneratedContent::new("test".to_string(), yaml.to_string(), json!({}));
+
+        assert_eq!(
+            "toml".parse::<ContentFormat>().unwrap(),
+            ContentFormat::Toml
+        );
+        assert_eq!(ContentFormat::Toml.to_string(), "toml");
+        assert!(ContentFormat::Toml.is_lossless());
+
+        let toml_output = content.convert_checked(ContentFormat::Toml).unwrap();
+        let parsed: toml::Value = toml::from_str(&toml_output).unwrap();
+        let original: toml::Value = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(parsed, original);
+        assert_eq!(parsed["limits"]["hours"].as_float(), Some(1.5));
+    }
+
+    #[test]
+    fn test_toml_rejects_unrepresentable_content() {
+        let sequence =
+            GeneratedContent::new("test".to_string(), "- a\n- b\n".to_string(), json!({}));
+        let err = sequence.as_format(ContentFormat::Toml).unwrap_err();
+        assert!(matches!(err, crate::Error::Serialization(_)));
+        assert!(err.to_string().contains("table at the top level"));
+
+        let null = GeneratedContent::new(
+            "test".to_string(),
+            "todos:\n  - id: a\n    assignee: ~\n".to_string(),
+            json!({}),
+        );
+        let err = null.as_format(ContentFormat::Toml).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("null value at $.todos[0].assignee"));
+    }
+
     #[test]
     fn test_generation_metadata() {
         let mut metadata = GenerationMetadata::default();
@@ -0,0 +1,640 @@
+//! Deterministic todo generation
+//!
+//! Expands [`TodoInput`] requirements into todos without an LLM, using a
+//! [`TaskTemplateLibrary`] that maps requirement keywords to ordered subtask
+//! templates.
+
+use crate::error::{Error, Result, TodoValidationError, ValidationError};
+use crate::models::todo::{Todo, TodoGranularity, TodoIdScheme, TodoInput, TodoList, TodoPriority};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Placeholder replaced with the requirement text in task templates
+pub const REQUIREMENT_PLACEHOLDER: &str = "{requirement}";
+
+/// Default cap on generated todos when `TodoInput::max_todos` is unset
+pub const DEFAULT_MAX_TODOS: usize = 20;
+
+/// Ordered subtask templates for requirements matching any of `keywords`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTemplateSet {
+    /// Case-insensitive substrings that select this set
+    pub keywords: Vec<String>,
+
+    /// Templates for high granularity
+    #[serde(default)]
+    pub high: Vec<String>,
+
+    /// Templates for medium granularity
+    #[serde(default)]
+    pub medium: Vec<String>,
+
+    /// Templates for low granularity
+    #[serde(default)]
+    pub low: Vec<String>,
+}
+
+impl TaskTemplateSet {
+    /// Create an empty set matching the given keywords
+    pub fn new<I, S>(keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keywords: keywords.into_iter().map(Into::into).collect(),
+            high: Vec::new(),
+            medium: Vec::new(),
+            low: Vec::new(),
+        }
+    }
+
+    /// Set the templates used for a granularity
+    #[must_use]
+    pub fn with_templates<I, S>(mut self, granularity: TodoGranularity, templates: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        *self.templates_mut(granularity) = templates.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Templates for a granularity
+    pub fn templates(&self, granularity: TodoGranularity) -> &[String] {
+        match granularity {
+            TodoGranularity::High => &self.high,
+            TodoGranularity::Medium => &self.medium,
+            TodoGranularity::Low => &self.low,
+        }
+    }
+
+    fn templates_mut(&mut self, granularity: TodoGranularity) -> &mut Vec<String> {
+        match granularity {
+            TodoGranularity::High => &mut self.high,
+            TodoGranularity::Medium => &mut self.medium,
+            TodoGranularity::Low => &mut self.low,
+        }
+    }
+
+    fn matches(&self, requirement: &str) -> bool {
+        let requirement = requirement.to_lowercase();
+        self.keywords
+            .iter()
+            .any(|keyword| requirement.contains(&keyword.to_lowercase()))
+    }
+}
+
+/// Keyword-to-subtask mapping used by [`generate_todos`]
+///
+/// Sets are matched in order, so sets added with [`TaskTemplateLibrary::register`]
+/// take precedence over the built-in ones. Requirements matching no set, or a
+/// set without templates for the requested granularity, use the fallback set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTemplateLibrary {
+    /// Keyword sets, checked in order
+    pub sets: Vec<TaskTemplateSet>,
+
+    /// Generic templates for unmatched requirements
+    pub fallback: TaskTemplateSet,
+}
+
+impl TaskTemplateLibrary {
+    /// Create a library with only the generic fallback templates
+    pub fn empty() -> Self {
+        Self {
+            sets: Vec::new(),
+            fallback: TaskTemplateSet::new(Vec::<String>::new())
+                .with_templates(
+                    TodoGranularity::High,
+                    [
+                        "Analyze requirements for {requirement}",
+                        "Design solution architecture for {requirement}",
+                        "Implement core functionality for {requirement}",
+                        "Add error handling for {requirement}",
+                        "Create documentation for {requirement}",
+                        "Test and validate {requirement}",
+                    ],
+                )
+                .with_templates(
+                    TodoGranularity::Medium,
+                    ["Implement {requirement}", "Test and document {requirement}"],
+                )
+                .with_templates(TodoGranularity::Low, ["Implement complete {requirement}"]),
+        }
+    }
+
+    /// Register a keyword set, taking precedence over existing sets
+    pub fn register(&mut self, set: TaskTemplateSet) {
+        self.sets.insert(0, set);
+    }
+
+    /// Expand a requirement into subtask descriptions for a granularity
+    pub fn templates_for(&self, requirement: &str, granularity: TodoGranularity) -> Vec<String> {
+        let templates = self
+            .sets
+            .iter()
+            .filter(|set| set.matches(requirement))
+            .map(|set| set.templates(granularity))
+            .find(|templates| !templates.is_empty())
+            .unwrap_or_else(|| self.fallback.templates(granularity));
+
+        templates
+            .iter()
+            .map(|template| template.replace(REQUIREMENT_PLACEHOLDER, requirement))
+            .collect()
+    }
+}
+
+impl Default for TaskTemplateLibrary {
+    /// Library with built-in sets for auth, API, database and testing work
+    fn default() -> Self {
+        let mut library = Self::empty();
+        library.sets = vec![
+            TaskTemplateSet::new(["auth"])
+                .with_templates(
+                    TodoGranularity::High,
+                    [
+                        "Design user authentication schema",
+                        "Implement user registration endpoint",
+                        "Create login/logout functionality",
+                        "Add password hashing and validation",
+                        "Implement JWT token management",
+                        "Create user session handling",
+                    ],
+                )
+                .with_templates(
+                    TodoGranularity::Medium,
+                    [
+                        "Implement user authentication system",
+                        "Create authentication middleware and security",
+                    ],
+                ),
+            TaskTemplateSet::new(["api", "endpoint"])
+                .with_templates(
+                    TodoGranularity::High,
+                    [
+                        "Design API endpoint specifications",
+                        "Implement CRUD operations for core entities",
+                        "Add request validation middleware",
+                        "Create API response formatting",
+                        "Implement error handling for API routes",
+                        "Add API rate limiting",
+                    ],
+                )
+                .with_templates(
+                    TodoGranularity::Medium,
+                    [
+                        "Design and implement REST API endpoints",
+                        "Add API validation and error handling",
+                    ],
+                ),
+            TaskTemplateSet::new(["database"])
+                .with_templates(
+                    TodoGranularity::High,
+                    [
+                        "Design database schema and relationships",
+                        "Create database migration scripts",
+                        "Implement database connection pool",
+                        "Add database query optimization",
+                        "Create data access layer",
+                        "Implement database backup strategy",
+                    ],
+                )
+                .with_templates(
+                    TodoGranularity::Medium,
+                    [
+                        "Setup database schema and connections",
+                        "Implement data access layer",
+                    ],
+                ),
+            TaskTemplateSet::new(["test"])
+                .with_templates(
+                    TodoGranularity::High,
+                    [
+                        "Create unit test framework setup",
+                        "Write unit tests for core functions",
+                        "Implement integration test suite",
+                        "Add API endpoint testing",
+                        "Create test data fixtures",
+                        "Setup test coverage reporting",
+                    ],
+                )
+                .with_templates(
+                    TodoGranularity::Medium,
+                    [
+                        "Create comprehensive test suite",
+                        "Setup test automation and coverage",
+                    ],
+                ),
+        ];
+        library
+    }
+}
+
+/// Number of subtasks generated per requirement at each granularity
+pub const fn todos_per_requirement(granularity: TodoGranularity) -> usize {
+    match granularity {
+        TodoGranularity::High => 4,
+        TodoGranularity::Medium => 2,
+        TodoGranularity::Low => 1,
+    }
+}
+
+/// Deterministically expand requirements into a todo list
+///
+/// Each requirement yields up to [`todos_per_requirement`] sequential todos
+/// (each depending on the previous one), capped overall by
+/// `input.max_todos` (default [`DEFAULT_MAX_TODOS`]). The first subtask of a
+/// requirement is high priority and the last is low.
+///
+/// Inputs with more requirements than `input.max_requirements` (default
+/// [`DEFAULT_MAX_REQUIREMENTS`](crate::models::todo::DEFAULT_MAX_REQUIREMENTS))
+/// are rejected before any expansion.
+///
+/// Requirements are cleaned with [`normalize_requirement`] before expansion;
+/// one left empty, such as `"!!!"`, is rejected as invalid input.
+/// IDs follow `input.id_scheme` (see [`TodoIdScheme`]) and depend only on the
+/// input, so identical input always yields identical IDs.
+pub fn generate_todos(input: &TodoInput, library: &TaskTemplateLibrary) -> Result<TodoList> {
+    if input.requirements.is_empty() {
+        return Err(Error::invalid_input("requirements cannot be empty"));
+    }
+    if let Some(limit) = input.max_requirements {
+        let count = input.requirements.len();
+        if count > limit {
+            return Err(
+                ValidationError::from(TodoValidationError::RequirementLimit { count, limit })
+                    .into(),
+            );
+        }
+    }
+
+    let per_requirement = todos_per_requirement(input.granularity);
+    let max_todos = input.max_todos.unwrap_or(DEFAULT_MAX_TODOS);
+    let mut todo_list = TodoList::new();
+
+    let mut used_slugs = HashSet::new();
+
+    for (req_idx, requirement) in input.requirements.iter().enumerate() {
+        let requirement = normalize_requirement(requirement);
+        if requirement.is_empty() {
+            return Err(Error::invalid_input(format!(
+                "requirement {} is empty after normalization",
+                req_idx
+            )));
+        }
+        let templates = library.templates_for(&requirement, input.granularity);
+        let prefix = match input.id_scheme {
+            TodoIdScheme::RequirementIndex => format!("todo_{}", req_idx),
+            TodoIdScheme::Slug => {
+                let slug = slugify(&requirement);
+                let mut candidate = slug.clone();
+                let mut suffix = req_idx;
+                while !used_slugs.insert(candidate.clone()) {
+                    candidate = format!("{}_{}", slug, suffix);
+                    suffix += 1;
+                }
+                candidate
+            }
+            TodoIdScheme::Global => "todo".to_string(),
+        };
+        let mut previous_id: Option<String> = None;
+
+        for (idx, content) in templates.iter().take(per_requirement).enumerate() {
+            if todo_list.todos.len() >= max_todos {
+                break;
+            }
+
+            let mut todo = Todo::new(content.as_str());
+            todo.id = match input.id_scheme {
+                TodoIdScheme::Global => format!("{}_{}", prefix, todo_list.todos.len()),
+                _ => format!("{}_{}", prefix, idx),
+            };
+            todo.priority = match idx {
+                0 => TodoPriority::High,
+                n if n == per_requirement - 1 => TodoPriority::Low,
+                _ => input.default_priority.unwrap_or(TodoPriority::Medium),
+            };
+            if input.include_estimates {
+                todo.estimated_hours = Some(estimate_task_hours(content));
+            }
+            if let Some(previous) = previous_id.replace(todo.id.clone()) {
+                todo.dependencies.push(previous);
+            }
+            todo.tags = tags_for_task(content);
+
+            todo_list.add_todo(todo);
+        }
+    }
+
+    todo_list.update_metadata();
+    Ok(todo_list)
+}
+
+/// Clean up requirement text before it is expanded into todos
+///
+/// Trims, collapses runs of whitespace to single spaces and strips trailing
+/// `.`, `,`, `;`, `:`, `!` and `?`. Casing is kept, so acronyms such as
+/// `API` or `USER` survive (`"  create USER auth!!  "` becomes
+/// `"create USER auth"`).
+pub fn normalize_requirement(requirement: &str) -> String {
+    requirement
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', ',', ';', ':', '!', '?', ' '])
+        .to_string()
+}
+
+/// Lowercase ASCII slug of a requirement (`"User Auth!"` becomes `user_auth`)
+fn slugify(requirement: &str) -> String {
+    let slug = requirement
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if slug.is_empty() {
+        "requirement".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Keyword-based time estimate for a task description (0.5-16 hours)
+pub fn estimate_task_hours(task: &str) -> f32 {
+    let task = task.to_lowercase();
+    let adjustments: [(&[&str], f32); 7] = [
+        (&["design", "architect"], 2.0),
+        (&["implement", "create"], 3.0),
+        (&["test", "validate"], 1.5),
+        (&["database", "schema"], 2.0),
+        (&["api", "endpoint"], 1.5),
+        (&["security", "auth"], 3.0),
+        (&["integration", "middleware"], 2.5),
+    ];
+
+    let hours: f32 = 2.0
+        + adjustments
+            .iter()
+            .filter(|(words, _)| words.iter().any(|word| task.contains(word)))
+            .map(|(_, hours)| hours)
+            .sum::<f32>();
+
+    hours.clamp(0.5, 16.0)
+}
+
+/// Technical-area and task-type tags for a task description
+fn tags_for_task(content: &str) -> Vec<String> {
+    let content = content.to_lowercase();
+    let areas: [(&[&str], &str); 7] = [
+        (&["auth", "login"], "authentication"),
+        (&["api", "endpoint"], "api"),
+        (&["database", "schema"], "database"),
+        (&["test", "validate"], "testing"),
+        (&["security"], "security"),
+        (&["frontend", "ui"], "frontend"),
+        (&["backend"], "backend"),
+    ];
+
+    let mut tags: Vec<String> = areas
+        .iter()
+        .filter(|(words, _)| words.iter().any(|word| content.contains(word)))
+        .map(|(_, tag)| (*tag).to_string())
+        .collect();
+
+    if content.starts_with("design") {
+        tags.push("design".to_string());
+    }
+    if content.starts_with("implement") || content.starts_with("create") {
+        tags.push("implementation".to_string());
+    }
+    if content.starts_with("test") && !tags.iter().any(|t| t == "testing") {
+        tags.push("testing".to_string());
+    }
+    if content.contains("document") {
+        tags.push("documentation".to_string());
+    }
+
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::DEFAULT_MAX_REQUIREMENTS;
+
+    fn input(requirements: &[&str], granularity: TodoGranularity) -> TodoInput {
+        TodoInput {
+            requirements: requirements.iter().map(|r| (*r).to_string()).collect(),
+            granularity,
+            ..TodoInput::default()
+        }
+    }
+
+    #[test]
+    fn test_builtin_and_fallback_templates() {
+        let list = generate_todos(
+            &input(&["User auth", "Billing"], TodoGranularity::Medium),
+            &TaskTemplateLibrary::default(),
+        )
+        .unwrap();
+
+        let contents: Vec<&str> = list.todos.iter().map(|t| t.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Implement user authentication system",
+                "Create authentication middleware and security",
+                "Implement Billing",
+                "Test and document Billing",
+            ]
+        );
+        assert_eq!(list.todos[1].dependencies, vec!["todo_0_0"]);
+        assert_eq!(list.todos[0].priority, TodoPriority::High);
+        assert_eq!(list.todos[1].priority, TodoPriority::Low);
+    }
+
+    #[test]
+    fn test_custom_keyword_mapping() {
+        let mut library = TaskTemplateLibrary::default();
+        library.register(TaskTemplateSet::new(["payment", "auth"]).with_templates(
+            TodoGranularity::Medium,
+            [
+                "Integrate payment provider for {requirement}",
+                "Reconcile ledger entries",
+            ],
+        ));
+
+        let list = generate_todos(
+            &input(&["Payment auth flow"], TodoGranularity::Medium),
+            &library,
+        )
+        .unwrap();
+
+        assert_eq!(list.todos.len(), 2);
+        assert_eq!(
+            list.todos[0].content,
+            "Integrate payment provider for Payment auth flow"
+        );
+        assert_eq!(list.todos[1].content, "Reconcile ledger entries");
+
+        // Custom sets without templates for a granularity defer to later sets
+        let list = generate_todos(
+            &input(&["Payment auth flow"], TodoGranularity::High),
+            &library,
+        )
+        .unwrap();
+        assert_eq!(list.todos[0].content, "Design user authentication schema");
+    }
+
+    #[test]
+    fn test_generation_limits() {
+        let mut limited = input(&["Reporting", "Exports"], TodoGranularity::High);
+        limited.max_todos = Some(5);
+        let list = generate_todos(&limited, &TaskTemplateLibrary::default()).unwrap();
+        assert_eq!(list.todos.len(), 5);
+        assert_eq!(list.todos[4].id, "todo_1_0");
+
+        let err = generate_todos(
+            &input(&[], TodoGranularity::High),
+            &TaskTemplateLibrary::empty(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input: requirements cannot be empty"
+        );
+    }
+
+    #[test]
+    fn test_id_schemes_are_stable() {
+        let library = TaskTemplateLibrary::default();
+        let ids = |input: &TodoInput| -> Vec<String> {
+            generate_todos(input, &library)
+                .unwrap()
+                .todos
+                .into_iter()
+                .map(|t| t.id)
+                .collect()
+        };
+
+        let mut input = input(
+            &["User Auth!", "Reporting", "user auth"],
+            TodoGranularity::Medium,
+        );
+        assert_eq!(ids(&input), ids(&input));
+        assert_eq!(ids(&input)[..2], ["todo_0_0", "todo_0_1"]);
+
+        input.id_scheme = TodoIdScheme::Slug;
+        assert_eq!(ids(&input), ids(&input));
+        assert_eq!(
+            ids(&input),
+            vec![
+                "user_auth_0",
+                "user_auth_1",
+                "reporting_0",
+                "reporting_1",
+                "user_auth_2_0",
+                "user_auth_2_1"
+            ]
+        );
+
+        input.id_scheme = TodoIdScheme::Global;
+        let list = generate_todos(&input, &library).unwrap();
+        assert_eq!(list.todos[3].id, "todo_3");
+        assert_eq!(list.todos[3].dependencies, vec!["todo_2"]);
+        assert!(list.todos[2].dependencies.is_empty());
+    }
+
+    #[test]
+    fn test_slug_ids_never_collide() {
+        let mut input = input(&["a b", "a b", "a_b_1"], TodoGranularity::Low);
+        input.id_scheme = TodoIdScheme::Slug;
+
+        let list = generate_todos(&input, &TaskTemplateLibrary::default()).unwrap();
+        let ids: Vec<&str> = list.todos.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["a_b_0", "a_b_1_0", "a_b_1_2_0"]);
+        let unique: HashSet<&str> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), ids.len());
+    }
+
+    #[test]
+    fn test_requirement_limit() {
+        // The default input is limited without any extra configuration
+        let requirements: Vec<String> = (0..=DEFAULT_MAX_REQUIREMENTS)
+            .map(|i| format!("Feature {}", i))
+            .collect();
+        let mut input = TodoInput {
+            requirements,
+            ..TodoInput::default()
+        };
+
+        let err = generate_todos(&input, &TaskTemplateLibrary::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::Todo(
+                TodoValidationError::RequirementLimit {
+                    count: 101,
+                    limit: 100
+                }
+            ))
+        ));
+        assert!(err
+            .to_string()
+            .contains("Requirement count 101 exceeds limit 100"));
+
+        // Deserialized input that omits the field gets the same limit
+        let json = serde_json::to_value(&input).unwrap();
+        let mut object = json.as_object().unwrap().clone();
+        object.remove("max_requirements");
+        let parsed: TodoInput = serde_json::from_value(object.into()).unwrap();
+        assert_eq!(parsed.max_requirements, Some(DEFAULT_MAX_REQUIREMENTS));
+
+        input.max_requirements = None;
+        assert!(generate_todos(&input, &TaskTemplateLibrary::default()).is_ok());
+    }
+
+    #[test]
+    fn test_normalize_requirement() {
+        assert_eq!(
+            normalize_requirement("  create USER auth!!  "),
+            "create USER auth"
+        );
+        assert_eq!(
+            normalize_requirement("dark\tmode \n toggle ?!"),
+            "dark mode toggle"
+        );
+        assert_eq!(normalize_requirement("v2.0 API"), "v2.0 API");
+
+        let err = generate_todos(
+            &input(&["Add login", "!!!"], TodoGranularity::Medium),
+            &TaskTemplateLibrary::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidInput(_)));
+        assert!(err
+            .to_string()
+            .contains("requirement 1 is empty after normalization"));
+
+        let list = generate_todos(
+            &input(&["  dark   mode  toggle!! "], TodoGranularity::Medium),
+            &TaskTemplateLibrary::default(),
+        )
+        .unwrap();
+        assert_eq!(list.todos[0].content, "Implement dark mode toggle");
+        assert_eq!(list.todos[1].content, "Test and document dark mode toggle");
+    }
+
+    #[test]
+    fn test_estimate_task_hours() {
+        assert!((estimate_task_hours("Write release notes") - 2.0).abs() < f32::EPSILON);
+        assert!((estimate_task_hours("Design database schema") - 6.0).abs() < f32::EPSILON);
+        assert!(
+            (estimate_task_hours("Implement auth API security integration") - 12.0).abs()
+                < f32::EPSILON
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Todo list importers
+//!
+//! Parses todo lists from JSON, YAML or Markdown checkbox lists, with
+//! format sniffing for paste and CLI workflows where the format is unknown.
+//! A leading UTF-8 byte order mark is ignored.
+
+use crate::error::Error;
+use crate::models::todo::{Todo, TodoList, TodoStatus};
+use crate::utils::strip_bom;
+use serde::{Deserialize, Serialize};
+
+/// Formats accepted by the importers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    /// A serialized `TodoList` as JSON
+    Json,
+    /// A serialized `TodoList` as YAML
+    Yaml,
+    /// A Markdown checkbox list (`- [ ] task`, `- [x] done`)
+    Markdown,
+}
+
+impl ImportFormat {
+    /// Guess the format of the input from its leading characters and structure
+    ///
+    /// Input starting with `{` or `[` is JSON. Otherwise the input is Markdown
+    /// when it contains checkbox items and YAML when it is structured like a
+    /// YAML mapping: the first line is a top-level `key:` or a `---` document
+    /// marker, and most lines are keys, `- ` items or indented values. A
+    /// stray `Note: ...` line in prose or a checkbox list is not enough.
+    /// Input matching both or neither is rejected.
+    pub fn detect(input: &str) -> crate::Result<Self> {
+        let input = strip_bom(input);
+        let trimmed = input.trim_start();
+        if trimmed.is_empty() {
+            return Err(Error::invalid_input("cannot detect format of empty input"));
+        }
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            return Ok(Self::Json);
+        }
+
+        let has_checkboxes = input.lines().any(|line| parse_checkbox(line).is_some());
+        let has_yaml_keys = looks_like_yaml(input);
+
+        match (has_checkboxes, has_yaml_keys) {
+            (true, false) => Ok(Self::Markdown),
+            (false, true) => Ok(Self::Yaml),
+            (true, true) => Err(Error::invalid_input(
+                "ambiguous input: contains both Markdown checkboxes and YAML keys",
+            )),
+            (false, false) => Err(Error::invalid_input(
+                "unrecognized input: expected JSON, YAML or a Markdown checkbox list",
+            )),
+        }
+    }
+}
+
+impl TodoList {
+    /// Parse a todo list serialized as JSON
+    pub fn from_json(input: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(strip_bom(input))?)
+    }
+
+    /// Parse a todo list serialized as YAML
+    pub fn from_yaml(input: &str) -> crate::Result<Self> {
+        Ok(serde_yaml::from_str(strip_bom(input))?)
+    }
+
+    /// Build a todo list from a Markdown checkbox list
+    ///
+    /// Each `- [ ]`, `* [ ]` or `+ [ ]` item becomes a pending todo and each
+    /// checked item (`[x]`) a completed one. Other lines are ignored.
+    pub fn from_markdown(input: &str) -> crate::Result<Self> {
+        let mut list = Self::new();
+        for (checked, content) in strip_bom(input).lines().filter_map(parse_checkbox) {
+            let mut todo = Todo::new(content);
+            if checked {
+                todo.status = TodoStatus::Completed;
+            }
+            list.add_todo(todo);
+        }
+
+        if list.todos.is_empty() {
+            return Err(Error::invalid_input("no checkbox items found in Markdown"));
+        }
+        list.update_metadata();
+        Ok(list)
+    }
+
+    /// Parse a todo list, detecting whether it is JSON, YAML or Markdown
+    ///
+    /// See [`ImportFormat::detect`] for the detection rules.
+    pub fn from_str_auto(input: &str) -> crate::Result<Self> {
+        match ImportFormat::detect(input)? {
+            ImportFormat::Json => Self::from_json(input),
+            ImportFormat::Yaml => Self::from_yaml(input),
+            ImportFormat::Markdown => Self::from_markdown(input),
+        }
+    }
+}
+
+/// Parse a Markdown checkbox item into its checked state and text
+fn parse_checkbox(line: &str) -> Option<(bool, &str)> {
+    let item = line
+        .trim_start()
+        .strip_prefix(['-', '*', '+'])?
+        .strip_prefix(' ')?
+        .trim_start();
+
+    let (checked, rest) = if let Some(rest) = item.strip_prefix("[ ]") {
+        (false, rest)
+    } else if let Some(rest) = item
+        .strip_prefix("[x]")
+        .or_else(|| item.strip_prefix("[X]"))
+    {
+        (true, rest)
+    } else {
+        return None;
+    };
+
+    let content = rest.trim();
+    (!content.is_empty()).then_some((checked, content))
+}
+
+/// Whether the input is structured like a YAML mapping
+///
+/// Blank lines and `#` comments are skipped. Checkbox items count against
+/// YAML so a Markdown list with a `key: value` line stays Markdown.
+fn looks_like_yaml(input: &str) -> bool {
+    let mut lines = input
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .peekable();
+    if !lines.peek().is_some_and(|first| is_yaml_key_line(first)) {
+        return false;
+    }
+
+    let (mut structural, mut total) = (0usize, 0usize);
+    for line in lines {
+        total += 1;
+        let is_item = line.starts_with("- ") || line == "-";
+        let is_nested = line.starts_with([' ', '\t']);
+        if parse_checkbox(line).is_none() && (is_yaml_key_line(line) || is_item || is_nested) {
+            structural += 1;
+        }
+    }
+    structural * 2 > total
+}
+
+/// Whether a line is a top-level YAML mapping key or document marker
+fn is_yaml_key_line(line: &str) -> bool {
+    if line == "---" {
+        return true;
+    }
+    let Some((key, rest)) = line.split_once(':') else {
+        return false;
+    };
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && (rest.is_empty() || rest.starts_with(' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_list() -> TodoList {
+        let mut list = TodoList::new();
+        list.add_todo(Todo::new("Design database schema"));
+        list.add_todo(Todo::new("Implement REST endpoints"));
+        list
+    }
+
+    #[test]
+    fn test_from_str_auto_json() {
+        let list = sample_list();
+        let json = serde_json::to_string_pretty(&list).unwrap();
+
+        assert_eq!(ImportFormat::detect(&json).unwrap(), ImportFormat::Json);
+        assert!(TodoList::from_str_auto(&json).unwrap().logically_eq(&list));
+    }
+
+    #[test]
+    fn test_from_str_auto_yaml() {
+        let list = sample_list();
+        let yaml = serde_yaml::to_string(&list).unwrap();
+
+        assert_eq!(ImportFormat::detect(&yaml).unwrap(), ImportFormat::Yaml);
+        assert!(TodoList::from_str_auto(&yaml).unwrap().logically_eq(&list));
+    }
+
+    #[test]
+    fn test_from_str_auto_markdown() {
+        let markdown = "# Sprint\n\n- [ ] Design database schema\n* [x] Set up CI pipeline\nSome notes\n- plain bullet\n";
+
+        assert_eq!(
+            ImportFormat::detect(markdown).unwrap(),
+            ImportFormat::Markdown
+        );
+        let list = TodoList::from_str_auto(markdown).unwrap();
+        assert_eq!(list.todos.len(), 2);
+        assert_eq!(list.todos[0].content, "Design database schema");
+        assert_eq!(list.todos[0].status, TodoStatus::Pending);
+        assert_eq!(list.todos[1].content, "Set up CI pipeline");
+        assert_eq!(list.todos[1].status, TodoStatus::Completed);
+        assert_eq!(list.metadata.total_count, 2);
+    }
+
+    #[test]
+    fn test_from_str_auto_strips_bom() {
+        let list = sample_list();
+        let yaml = format!("\u{feff}{}", serde_yaml::to_string(&list).unwrap());
+        let json = format!("\u{feff}{}", serde_json::to_string(&list).unwrap());
+
+        assert!(TodoList::from_str_auto(&yaml).unwrap().logically_eq(&list));
+        assert!(TodoList::from_str_auto(&json).unwrap().logically_eq(&list));
+
+        let markdown = TodoList::from_str_auto("\u{feff}- [ ] Write docs").unwrap();
+        assert_eq!(markdown.todos[0].content, "Write docs");
+    }
+
+    #[test]
+    fn test_from_str_auto_rejects_ambiguous_input() {
+        let mixed = "project: Demo\ntodos:\n- [ ] Design database schema\n";
+        let err = TodoList::from_str_auto(mixed).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+
+        assert!(TodoList::from_str_auto("   \n").is_err());
+        assert!(TodoList::from_str_auto("just some prose").is_err());
+    }
+
+    #[test]
+    fn test_detect_ignores_colons_in_prose() {
+        let markdown =
+            "- [ ] Design database schema\n- [ ] Write migrations\nNote: ask about indexes\n";
+        assert_eq!(
+            ImportFormat::detect(markdown).unwrap(),
+            ImportFormat::Markdown
+        );
+
+        let prose =
+            "Meeting recap\nNote: the schema is frozen\nTodo: follow up next week\nThanks all\n";
+        assert!(ImportFormat::detect(prose).is_err());
+
+        let commented = "# exported list\n---\ntodos:\n  - content: Write docs\n";
+        assert_eq!(ImportFormat::detect(commented).unwrap(), ImportFormat::Yaml);
+    }
+}
@@ -0,0 +1,612 @@
+//! Markdown rendering for todo lists
+//!
+//! Configurable Markdown export shared by examples and downstream tools.
+
+use crate::models::todo::{Todo, TodoList, TodoPriority, TodoStatus};
+use crate::utils::count_as_f32;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+
+/// How tasks are grouped in Markdown output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarkdownGrouping {
+    /// Single task section in list order
+    #[default]
+    None,
+    /// One section per priority, highest first
+    Priority,
+    /// One section per tag, alphabetically; untagged todos last
+    Tag,
+}
+
+/// Options for [`TodoList::to_markdown`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarkdownOptions {
+    /// Whether to include the summary section
+    pub include_summary: bool,
+
+    /// How tasks are grouped
+    pub group_by: MarkdownGrouping,
+
+    /// Whether to list each todo's dependencies
+    pub show_dependencies: bool,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            include_summary: true,
+            group_by: MarkdownGrouping::None,
+            show_dependencies: true,
+        }
+    }
+}
+
+impl TodoList {
+    /// Render the todo list as Markdown
+    pub fn to_markdown(&self, opts: MarkdownOptions) -> String {
+        let mut markdown = String::from("# Todo List\n\n");
+        let unit = self.metadata.estimate_unit.label();
+
+        if opts.include_summary {
+            let total_hours: f32 = self.todos.iter().filter_map(|t| t.estimated_hours).sum();
+            let avg_hours = if self.todos.is_empty() {
+                0.0
+            } else {
+                total_hours / count_as_f32(self.todos.len())
+            };
+
+            markdown.push_str("## Summary\n\n");
+            writeln!(markdown, "- **Total todos**: {}", self.todos.len()).unwrap();
+            writeln!(
+                markdown,
+                "- **Total estimated {}**: {:.1}",
+                unit, total_hours
+            )
+            .unwrap();
+            writeln!(
+                markdown,
+                "- **Average {} per task**: {:.1}",
+                unit, avg_hours
+            )
+            .unwrap();
+
+            let blocked: Vec<&Todo> = self
+                .todos
+                .iter()
+                .filter(|t| t.status == TodoStatus::Blocked)
+                .collect();
+            if !blocked.is_empty() {
+                writeln!(markdown, "- **Blocked todos**: {}", blocked.len()).unwrap();
+                for todo in blocked {
+                    write!(markdown, "  - {}", escape_markdown(&todo.content)).unwrap();
+                    if let Some(reason) = &todo.blocked_reason {
+                        write!(markdown, ": {}", escape_markdown(reason)).unwrap();
+                    }
+                    markdown.push('\n');
+                }
+            }
+            markdown.push('\n');
+        }
+
+        if self.todos.is_empty() {
+            markdown.push_str("_No todos._\n");
+            return markdown;
+        }
+
+        match opts.group_by {
+            MarkdownGrouping::None => {
+                let todos: Vec<&Todo> = self.todos.iter().collect();
+                write_section(&mut markdown, "Tasks", &todos, unit, opts);
+            }
+            MarkdownGrouping::Priority => {
+                for priority in [
+                    TodoPriority::Critical,
+                    TodoPriority::High,
+                    TodoPriority::Medium,
+                    TodoPriority::Low,
+                ] {
+                    let todos = self.todos_by_priority(priority);
+                    if !todos.is_empty() {
+                        let title = format!("{} Priority", capitalize(&priority.to_string()));
+                        write_section(&mut markdown, &title, &todos, unit, opts);
+                    }
+                }
+            }
+            MarkdownGrouping::Tag => {
+                let mut tags: Vec<&str> = self
+                    .todos
+                    .iter()
+                    .flat_map(|t| t.tags.iter().map(String::as_str))
+                    .collect();
+                tags.sort_unstable();
+                tags.dedup();
+
+                for tag in tags {
+                    let todos: Vec<&Todo> = self
+                        .todos
+                        .iter()
+                        .filter(|t| t.tags.iter().any(|t| t == tag))
+                        .collect();
+                    let title = format!("Tag: {}", escape_markdown(tag));
+                    write_section(&mut markdown, &title, &todos, unit, opts);
+                }
+
+                let untagged: Vec<&Todo> =
+                    self.todos.iter().filter(|t| t.tags.is_empty()).collect();
+                if !untagged.is_empty() {
+                    write_section(&mut markdown, "Untagged", &untagged, unit, opts);
+                }
+            }
+        }
+
+        markdown
+    }
+}
+
+impl TodoList {
+    /// Render the todos as a GitHub-flavored task list
+    ///
+    /// Completed todos are checked; cancelled ones are left unchecked and
+    /// struck through. Each item is indented by its dependency depth and
+    /// followed by its priority and estimate in the list's unit, e.g.
+    /// `  - [ ] Implement login (high) ~4.0h`; blocked todos with a reason
+    /// end in `(blocked: <reason>)`. Todos appear in
+    /// [`TodoList::topological_order`], or list order when the graph is cyclic.
+    pub fn to_github_checklist(&self) -> String {
+        let ordered = self
+            .topological_order()
+            .unwrap_or_else(|_| self.todos.iter().collect());
+        let depths = dependency_depths(&self.todos);
+        let suffix = self.metadata.estimate_unit.suffix();
+
+        let mut checklist = String::new();
+        for todo in ordered {
+            let indent = "  ".repeat(depths.get(todo.id.as_str()).copied().unwrap_or(0));
+            let checkbox = if todo.status == TodoStatus::Completed {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let content = escape_markdown(&todo.content);
+            let content = if todo.status == TodoStatus::Cancelled {
+                format!("~~{}~~", content)
+            } else {
+                content
+            };
+
+            write!(
+                checklist,
+                "{}- {} {} ({})",
+                indent, checkbox, content, todo.priority
+            )
+            .unwrap();
+            if let Some(hours) = todo.estimated_hours {
+                write!(checklist, " ~{:.1}{}", hours, suffix).unwrap();
+            }
+            if let Some(reason) = blocked_reason(todo) {
+                write!(checklist, " (blocked: {})", escape_markdown(reason)).unwrap();
+            }
+            checklist.push('\n');
+        }
+        checklist
+    }
+}
+
+/// Length of the longest chain of known dependencies below each todo
+///
+/// Todos without dependencies have depth 0. Depths are computed in
+/// topological order without recursion, so long chains cannot overflow the
+/// stack. When only cycles remain, the first remaining todo in list order is
+/// resolved from its already-resolved dependencies, so the edges that would
+/// close the cycle are not followed.
+fn dependency_depths(todos: &[Todo]) -> HashMap<&str, usize> {
+    let index: HashMap<&str, usize> = todos
+        .iter()
+        .enumerate()
+        .map(|(idx, todo)| (todo.id.as_str(), idx))
+        .collect();
+    let deps: Vec<HashSet<usize>> = todos
+        .iter()
+        .map(|todo| {
+            todo.dependencies
+                .iter()
+                .filter_map(|dep| index.get(dep.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let mut dependents = vec![Vec::new(); todos.len()];
+    for (idx, todo_deps) in deps.iter().enumerate() {
+        for &dep in todo_deps {
+            dependents[dep].push(idx);
+        }
+    }
+
+    let mut unresolved: Vec<usize> = deps.iter().map(HashSet::len).collect();
+    let mut depth: Vec<Option<usize>> = vec![None; todos.len()];
+    let mut ready: VecDeque<usize> = (0..todos.len()).filter(|&i| unresolved[i] == 0).collect();
+    let mut next_unvisited = 0;
+
+    loop {
+        while let Some(idx) = ready.pop_front() {
+            if depth[idx].is_some() {
+                continue;
+            }
+            depth[idx] = Some(
+                deps[idx]
+                    .iter()
+                    .filter_map(|&dep| depth[dep].map(|d| d + 1))
+                    .max()
+                    .unwrap_or(0),
+            );
+            for &dependent in &dependents[idx] {
+                unresolved[dependent] -= 1;
+                if unresolved[dependent] == 0 {
+                    ready.push_back(dependent);
+                }
+            }
+        }
+
+        // Everything left is on or behind a cycle; break it at list order
+        while next_unvisited < todos.len() && depth[next_unvisited].is_some() {
+            next_unvisited += 1;
+        }
+        if next_unvisited == todos.len() {
+            break;
+        }
+        ready.push_back(next_unvisited);
+    }
+
+    todos
+        .iter()
+        .enumerate()
+        .map(|(idx, todo)| (todo.id.as_str(), depth[idx].unwrap_or(0)))
+        .collect()
+}
+
+/// Write a titled section listing the given todos, labelling estimates in `unit`
+fn write_section(
+    markdown: &mut String,
+    title: &str,
+    todos: &[&Todo],
+    unit: &str,
+    opts: MarkdownOptions,
+) {
+    writeln!(markdown, "## {}\n", title).unwrap();
+
+    for (idx, todo) in todos.iter().enumerate() {
+        writeln!(
+            markdown,
+            "### {}. {}\n",
+            idx + 1,
+            escape_markdown(&todo.content)
+        )
+        .unwrap();
+        writeln!(markdown, "- **ID**: `{}`", todo.id).unwrap();
+        writeln!(markdown, "- **Status**: {}", todo.status).unwrap();
+        if let Some(reason) = blocked_reason(todo) {
+            writeln!(
+                markdown,
+                "- **Blocked reason**: {}",
+                escape_markdown(reason)
+            )
+            .unwrap();
+        }
+        writeln!(markdown, "- **Priority**: {}", todo.priority).unwrap();
+
+        if let Some(hours) = todo.estimated_hours {
+            writeln!(markdown, "- **Estimated {}**: {:.1}", unit, hours).unwrap();
+        }
+
+        if opts.show_dependencies && !todo.dependencies.is_empty() {
+            writeln!(
+                markdown,
+                "- **Dependencies**: {}",
+                todo.dependencies.join(", ")
+            )
+            .unwrap();
+        }
+
+        if !todo.tags.is_empty() {
+            let tags: Vec<String> = todo.tags.iter().map(|t| escape_markdown(t)).collect();
+            writeln!(markdown, "- **Tags**: {}", tags.join(", ")).unwrap();
+        }
+
+        if !todo.links.is_empty() {
+            let links: Vec<String> = todo
+                .links
+                .iter()
+                .map(|link| {
+                    format!(
+                        "[{}]({})",
+                        escape_markdown(&link.title),
+                        encode_link_url(&link.url)
+                    )
+                })
+                .collect();
+            writeln!(markdown, "- **Links**: {}", links.join(", ")).unwrap();
+        }
+
+        markdown.push('\n');
+    }
+}
+
+/// The reason a todo is blocked, if it is blocked and one was recorded
+fn blocked_reason(todo: &Todo) -> Option<&str> {
+    if todo.status == TodoStatus::Blocked {
+        todo.blocked_reason.as_deref()
+    } else {
+        None
+    }
+}
+
+/// Backslash-escape characters that Markdown would treat as formatting
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Percent-encode the bytes of a URL that would end or break a Markdown link
+///
+/// Characters valid in URLs are kept as written, including existing `%`
+/// escapes; spaces, parentheses, angle brackets, other ASCII punctuation
+/// that Markdown treats specially, control characters and non-ASCII bytes
+/// are encoded.
+pub fn encode_link_url(url: &str) -> String {
+    let mut encoded = String::with_capacity(url.len());
+    for byte in url.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'*+,;=%".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{:02X}", byte).unwrap();
+        }
+    }
+    encoded
+}
+
+/// Uppercase the first character of a string
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().collect::<String>() + chars.as_str()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::{EstimateUnit, Link};
+
+    fn sample_list() -> TodoList {
+        let mut list = TodoList::new();
+
+        let mut schema = Todo::new("Design database schema");
+        schema.id = "schema".to_string();
+        schema.priority = TodoPriority::Critical;
+        schema.estimated_hours = Some(4.0);
+        schema.tags = vec!["backend".to_string()];
+
+        let mut api = Todo::new("Implement REST endpoints");
+        api.id = "api".to_string();
+        api.priority = TodoPriority::High;
+        api.estimated_hours = Some(6.0);
+        api.dependencies = vec!["schema".to_string()];
+        api.tags = vec!["backend".to_string()];
+
+        let mut docs = Todo::new("Document public API");
+        docs.id = "docs".to_string();
+        docs.priority = TodoPriority::Low;
+        docs.links = vec![Link::new("Spec", "https://example.com/spec")];
+
+        list.add_todo(schema);
+        list.add_todo(api);
+        list.add_todo(docs);
+        list
+    }
+
+    #[test]
+    fn test_markdown_default_options() {
+        let markdown = sample_list().to_markdown(MarkdownOptions::default());
+
+        assert!(markdown.starts_with("# Todo List\n\n## Summary\n\n"));
+        assert!(markdown.contains("- **Total todos**: 3"));
+        assert!(markdown.contains("- **Total estimated hours**: 10.0"));
+        assert!(markdown.contains("## Tasks\n\n### 1. Design database schema"));
+        assert!(markdown.contains("- **Dependencies**: schema"));
+        assert!(markdown.contains("- **Links**: [Spec](https://example.com/spec)"));
+    }
+
+    #[test]
+    fn test_markdown_labels_estimate_unit() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Implement checkout flow");
+        todo.estimated_hours = Some(8.0);
+        list.add_todo(todo);
+        list.metadata.estimate_unit = EstimateUnit::Points;
+
+        let markdown = list.to_markdown(MarkdownOptions::default());
+        assert!(markdown.contains("- **Total estimated points**: 8.0"));
+        assert!(markdown.contains("- **Estimated points**: 8.0"));
+        assert!(!markdown.contains("hours"));
+        assert!(list.to_github_checklist().contains("~8.0pt"));
+    }
+
+    #[test]
+    fn test_markdown_escapes_content_and_handles_empty_lists() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Fix *bold* parsing in [docs]_page");
+        todo.tags = vec!["front_end".to_string()];
+        list.add_todo(todo);
+
+        let markdown = list.to_markdown(MarkdownOptions::default());
+        assert!(markdown.contains(r"### 1. Fix \*bold\* parsing in \[docs\]\_page"));
+        assert!(markdown.contains(r"- **Tags**: front\_end"));
+
+        for group_by in [MarkdownGrouping::None, MarkdownGrouping::Tag] {
+            let opts = MarkdownOptions {
+                group_by,
+                ..MarkdownOptions::default()
+            };
+            let empty = TodoList::new().to_markdown(opts);
+            assert!(empty.contains("- **Total todos**: 0"));
+            assert!(empty.ends_with("_No todos._\n"));
+        }
+    }
+
+    #[test]
+    fn test_markdown_links_are_escaped() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Review design document");
+        todo.links = vec![Link::new(
+            "Spec [draft]",
+            "https://example.com/a doc (v2)?q=ü",
+        )];
+        list.add_todo(todo);
+
+        let markdown = list.to_markdown(MarkdownOptions::default());
+        assert!(markdown.contains(
+            r"- **Links**: [Spec \[draft\]](https://example.com/a%20doc%20%28v2%29?q=%C3%BC)"
+        ));
+        assert_eq!(
+            encode_link_url("https://example.com/x%20y#top"),
+            "https://example.com/x%20y#top"
+        );
+    }
+
+    #[test]
+    fn test_github_checklist_status_mapping() {
+        let statuses = [
+            (TodoStatus::Pending, "- [ ] Handle Pending work (medium)"),
+            (
+                TodoStatus::InProgress,
+                "- [ ] Handle InProgress work (medium)",
+            ),
+            (TodoStatus::Blocked, "- [ ] Handle Blocked work (medium)"),
+            (
+                TodoStatus::Completed,
+                "- [x] Handle Completed work (medium)",
+            ),
+            (
+                TodoStatus::Cancelled,
+                "- [ ] ~~Handle Cancelled work~~ (medium)",
+            ),
+        ];
+
+        let mut list = TodoList::new();
+        for (status, _) in statuses {
+            let mut todo = Todo::new(format!("Handle {:?} work", status));
+            todo.id = status.to_string();
+            todo.status = status;
+            list.add_todo(todo);
+        }
+
+        let checklist = list.to_github_checklist();
+        for (_, line) in statuses {
+            assert!(
+                checklist.lines().any(|l| l == line),
+                "missing {line:?} in\n{checklist}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_exports_render_blocked_reason() {
+        let mut list = sample_list();
+        list.todos[1].block("Waiting on *schema* review");
+
+        let markdown = list.to_markdown(MarkdownOptions::default());
+        assert!(markdown.contains(
+            "- **Blocked todos**: 1\n  - Implement REST endpoints: Waiting on \\*schema\\* review\n"
+        ));
+        assert!(markdown.contains(
+            "- **Status**: blocked\n- **Blocked reason**: Waiting on \\*schema\\* review\n"
+        ));
+
+        let checklist = list.to_github_checklist();
+        assert!(checklist.contains(
+            "- [ ] Implement REST endpoints (high) ~6.0h (blocked: Waiting on \\*schema\\* review)\n"
+        ));
+    }
+
+    #[test]
+    fn test_dependency_depths_handle_long_chains_and_cycles() {
+        let mut todos: Vec<Todo> = (0..100_000)
+            .map(|i| {
+                let mut todo = Todo::new(format!("Implement step {}", i));
+                todo.id = format!("s{}", i);
+                if i > 0 {
+                    todo.dependencies = vec![format!("s{}", i - 1)];
+                }
+                todo
+            })
+            .collect();
+        let depths = dependency_depths(&todos);
+        assert_eq!(depths["s99999"], 99_999);
+
+        // a <-> b cycle with c behind it: the cycle is broken at `a`
+        todos.clear();
+        for (id, deps) in [("a", vec!["b"]), ("b", vec!["a"]), ("c", vec!["b"])] {
+            let mut todo = Todo::new(format!("Implement {}", id));
+            todo.id = id.to_string();
+            todo.dependencies = deps.into_iter().map(String::from).collect();
+            todos.push(todo);
+        }
+        let depths = dependency_depths(&todos);
+        assert_eq!((depths["a"], depths["b"], depths["c"]), (0, 1, 2));
+    }
+
+    #[test]
+    fn test_github_checklist_nests_by_dependency_depth() {
+        let checklist = sample_list().to_github_checklist();
+
+        assert_eq!(
+            checklist,
+            "- [ ] Design database schema (critical) ~4.0h\n  \
+             - [ ] Implement REST endpoints (high) ~6.0h\n\
+             - [ ] Document public API (low)\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_grouped_by_priority() {
+        let opts = MarkdownOptions {
+            include_summary: false,
+            group_by: MarkdownGrouping::Priority,
+            show_dependencies: false,
+        };
+        let markdown = sample_list().to_markdown(opts);
+
+        assert!(!markdown.contains("## Summary"));
+        assert!(!markdown.contains("**Dependencies**"));
+
+        let critical = markdown.find("## Critical Priority").unwrap();
+        let high = markdown.find("## High Priority").unwrap();
+        let low = markdown.find("## Low Priority").unwrap();
+        assert!(critical < high && high < low);
+        assert!(!markdown.contains("## Medium Priority"));
+    }
+
+    #[test]
+    fn test_markdown_grouped_by_tag() {
+        let opts = MarkdownOptions {
+            group_by: MarkdownGrouping::Tag,
+            ..MarkdownOptions::default()
+        };
+        let markdown = sample_list().to_markdown(opts);
+
+        assert!(markdown.contains("## Tag: backend\n\n### 1. Design database schema"));
+        assert!(markdown.contains("## Untagged\n\n### 1. Document public API"));
+    }
+}
@@ -9,6 +9,27 @@ pub mod template;
 #[cfg(feature = "todo-validation")]
 pub mod todo;
 
+#[cfg(feature = "todo-validation")]
+pub mod portfolio;
+
+#[cfg(feature = "todo-validation")]
+pub mod markdown;
+
+#[cfg(feature = "todo-validation")]
+pub mod import;
+
+#[cfg(feature = "todo-validation")]
+pub mod stats;
+
+#[cfg(feature = "todo-validation")]
+pub mod schedule;
+
+#[cfg(feature = "todo-validation")]
+pub mod generator;
+
+#[cfg(feature = "todo-validation")]
+pub mod rebalance;
+
 #[cfg(feature = "quality-proxy")]
 pub mod quality;
 
@@ -0,0 +1,144 @@
+//! Portfolio data models
+//!
+//! Aggregation of multiple todo lists into a single roll-up view.
+
+use crate::models::todo::{longest_weighted_chain, Todo, TodoList, TodoStatus};
+use crate::utils::count_as_f32;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Project name used for lists without a project context
+pub const UNNAMED_PROJECT: &str = "unnamed";
+
+/// Collection of todo lists spanning multiple projects
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Portfolio {
+    /// Todo lists included in the portfolio
+    pub lists: Vec<TodoList>,
+}
+
+impl Portfolio {
+    /// Create an empty portfolio
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a portfolio from existing todo lists
+    pub const fn from_lists(lists: Vec<TodoList>) -> Self {
+        Self { lists }
+    }
+
+    /// Add a todo list to the portfolio
+    pub fn add_list(&mut self, list: TodoList) {
+        self.lists.push(list);
+    }
+
+    /// Iterate over every todo in the portfolio
+    pub fn todos(&self) -> impl Iterator<Item = &Todo> {
+        self.lists.iter().flat_map(|list| list.todos.iter())
+    }
+
+    /// Total number of todos across all lists
+    pub fn total_count(&self) -> usize {
+        self.lists.iter().map(|list| list.todos.len()).sum()
+    }
+
+    /// Total estimated hours across all lists
+    pub fn total_hours(&self) -> f32 {
+        self.todos().filter_map(|t| t.estimated_hours).sum()
+    }
+
+    /// Estimated hours grouped by project name
+    ///
+    /// Lists without a project context are grouped under [`UNNAMED_PROJECT`].
+    pub fn hours_by_project(&self) -> HashMap<String, f32> {
+        let mut hours = HashMap::new();
+        for list in &self.lists {
+            let name = list
+                .project
+                .as_ref()
+                .map_or(UNNAMED_PROJECT, |p| p.name.as_str());
+            let list_hours: f32 = list.todos.iter().filter_map(|t| t.estimated_hours).sum();
+            *hours.entry(name.to_string()).or_insert(0.0) += list_hours;
+        }
+        hours
+    }
+
+    /// Fraction of completed todos across all lists (0.0-1.0)
+    pub fn overall_completion(&self) -> f32 {
+        let total = self.total_count();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let completed = self
+            .todos()
+            .filter(|t| t.status == TodoStatus::Completed)
+            .count();
+        count_as_f32(completed) / count_as_f32(total)
+    }
+
+    /// Longest dependency chain across all lists
+    ///
+    /// Dependencies are resolved by todo ID, so a todo in one list may depend
+    /// on a todo in another. Returns an empty vec if the combined graph has a cycle.
+    pub fn critical_path(&self) -> Vec<String> {
+        let todos: Vec<&Todo> = self.todos().collect();
+        longest_weighted_chain(&todos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::{test_todo, ProjectContext};
+
+    fn project(name: &str) -> ProjectContext {
+        ProjectContext {
+            name: name.to_string(),
+            description: None,
+            project_type: None,
+            target_date: None,
+            stakeholders: Vec::new(),
+            tech_stack: Vec::new(),
+            budget_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_portfolio_aggregation() {
+        let mut api = TodoList::new();
+        api.project = Some(project("api"));
+        api.add_todo(
+            test_todo("api_1", &[])
+                .estimated_hours(4.0)
+                .status(TodoStatus::Completed)
+                .build(),
+        );
+        api.add_todo(test_todo("api_2", &["api_1"]).estimated_hours(6.0).build());
+
+        let mut web = TodoList::new();
+        web.project = Some(project("web"));
+        web.add_todo(test_todo("web_1", &["api_2"]).estimated_hours(3.0).build());
+
+        let portfolio = Portfolio::from_lists(vec![api.clone(), web.clone()]);
+
+        let expected_hours =
+            api.metadata.total_estimated_hours + web.metadata.total_estimated_hours;
+        assert!((portfolio.total_hours() - expected_hours).abs() < f32::EPSILON);
+        assert_eq!(portfolio.total_count(), 3);
+
+        let by_project = portfolio.hours_by_project();
+        assert_eq!(by_project.get("api"), Some(&10.0));
+        assert_eq!(by_project.get("web"), Some(&3.0));
+
+        assert!((portfolio.overall_completion() - 1.0 / 3.0).abs() < f32::EPSILON);
+
+        // Cross-project dependency links the chains together
+        assert_eq!(portfolio.critical_path(), vec!["api_1", "api_2", "web_1"]);
+
+        let yaml = serde_yaml::to_string(&portfolio).unwrap();
+        let restored: Portfolio = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(restored.total_count(), 3);
+    }
+}
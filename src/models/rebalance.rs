@@ -0,0 +1,327 @@
+//! Granularity rebalancing for todo lists
+//!
+//! Brings generated lists toward a consistent task size: todos far above a
+//! target estimate are split on the conjunctions in their content when each
+//! part is its own action, and todos far below it are reported for review
+//! rather than merged.
+
+use crate::models::todo::{Todo, TodoList, TodoStatus, DEFAULT_ACTION_VERBS};
+use crate::utils::count_as_f32;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Estimates above this multiple of the target are split
+pub const SPLIT_FACTOR: f32 = 2.0;
+
+/// Estimates below this fraction of the target are flagged
+pub const UNDERSIZED_FACTOR: f32 = 0.25;
+
+/// Separators that join independent pieces of work in task text
+const CONJUNCTIONS: &[&str] = &[
+    ", and then ",
+    ", then ",
+    ", and ",
+    " and then ",
+    " then ",
+    " and ",
+    "; ",
+    ", ",
+    " & ",
+];
+
+/// A change made by an automatic fix
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoFixChange {
+    /// ID of the todo that was changed
+    pub todo_id: String,
+
+    /// What was done
+    pub description: String,
+
+    /// IDs of the todos that replace it
+    pub new_ids: Vec<String>,
+}
+
+/// A todo an automatic fix left alone but wants reviewed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoFixFlag {
+    /// ID of the flagged todo
+    pub todo_id: String,
+
+    /// Why it was flagged
+    pub reason: String,
+}
+
+/// Outcome of an automatic fix
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoFixReport {
+    /// Changes made, in list order
+    pub changes: Vec<AutoFixChange>,
+
+    /// Todos left unchanged that need a human decision
+    pub flagged: Vec<AutoFixFlag>,
+}
+
+impl AutoFixReport {
+    /// Whether the fix neither changed nor flagged anything
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.flagged.is_empty()
+    }
+}
+
+/// Split task text into the independent pieces of work it joins
+///
+/// Splits on commas, semicolons, `and`, `&` and `then`, but only when every
+/// piece starts with a default action verb, so
+/// `"Implement login and add password reset"` becomes two pieces while
+/// `"Handle read and write errors"` and `"Design schema and API"` stay whole.
+/// Returns a single piece when the text has no such split.
+pub fn split_on_conjunctions(content: &str) -> Vec<String> {
+    let content = content.trim();
+    let mut pieces = vec![content.to_string()];
+    for conjunction in CONJUNCTIONS {
+        pieces = pieces
+            .iter()
+            .flat_map(|piece| piece.split(conjunction))
+            .map(str::trim)
+            .filter(|piece| !piece.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
+    if pieces.len() < 2 || !pieces.iter().all(|piece| leads_with_verb(piece)) {
+        return vec![content.to_string()];
+    }
+    pieces.iter().map(|piece| capitalize(piece)).collect()
+}
+
+/// Whether the first word of `piece` is a default action verb
+fn leads_with_verb(piece: &str) -> bool {
+    piece.split_whitespace().next().is_some_and(|word| {
+        DEFAULT_ACTION_VERBS
+            .iter()
+            .any(|verb| word.eq_ignore_ascii_case(verb))
+    })
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+impl TodoList {
+    /// Move task sizes toward `target_hours`
+    ///
+    /// Open todos estimated above [`SPLIT_FACTOR`] times the target are split
+    /// with [`split_on_conjunctions`]; each piece gets an equal share of the
+    /// estimate and the original's dependencies, and todos that depended on
+    /// the original depend on every piece. Pieces take the IDs `<id>_1`,
+    /// `<id>_2`, and so on. Oversized todos that do not split, and todos
+    /// estimated below [`UNDERSIZED_FACTOR`] times the target, are flagged
+    /// instead. Completed and cancelled todos are left alone. A non-positive
+    /// target does nothing.
+    pub fn rebalance_granularity(&mut self, target_hours: f32) -> AutoFixReport {
+        let mut report = AutoFixReport::default();
+        if !(target_hours.is_finite() && target_hours > 0.0) {
+            return report;
+        }
+
+        let mut taken: HashSet<String> = self.todos.iter().map(|t| t.id.clone()).collect();
+        let mut rebalanced = Vec::with_capacity(self.todos.len());
+        for todo in std::mem::take(&mut self.todos) {
+            let closed = matches!(todo.status, TodoStatus::Completed | TodoStatus::Cancelled);
+            let Some(hours) = todo.estimated_hours.filter(|_| !closed) else {
+                rebalanced.push(todo);
+                continue;
+            };
+
+            if hours < target_hours * UNDERSIZED_FACTOR {
+                report.flagged.push(AutoFixFlag {
+                    todo_id: todo.id.clone(),
+                    reason: format!(
+                        "Estimate {:.1}h is far below the {:.1}h target; consider merging",
+                        hours, target_hours
+                    ),
+                });
+                rebalanced.push(todo);
+                continue;
+            }
+            if hours <= target_hours * SPLIT_FACTOR {
+                rebalanced.push(todo);
+                continue;
+            }
+
+            let pieces = split_on_conjunctions(&todo.content);
+            if pieces.len() < 2 {
+                report.flagged.push(AutoFixFlag {
+                    todo_id: todo.id.clone(),
+                    reason: format!(
+                        "Estimate {:.1}h is far above the {:.1}h target but does not list separate actions to split",
+                        hours, target_hours
+                    ),
+                });
+                rebalanced.push(todo);
+                continue;
+            }
+
+            let share = hours / count_as_f32(pieces.len());
+            let mut new_ids = Vec::with_capacity(pieces.len());
+            let mut suffix = 0;
+            for piece in pieces {
+                let id = loop {
+                    suffix += 1;
+                    let candidate = format!("{}_{}", todo.id, suffix);
+                    if taken.insert(candidate.clone()) {
+                        break candidate;
+                    }
+                };
+                new_ids.push(id.clone());
+                rebalanced.push(Todo {
+                    id,
+                    content: piece,
+                    estimated_hours: Some(share),
+                    remaining_hours: None,
+                    history: Vec::new(),
+                    ..todo.clone()
+                });
+            }
+            report.changes.push(AutoFixChange {
+                todo_id: todo.id.clone(),
+                description: format!(
+                    "Split {:.1}h todo into {} todos of {:.1}h",
+                    hours,
+                    new_ids.len(),
+                    share
+                ),
+                new_ids,
+            });
+        }
+
+        for change in &report.changes {
+            for todo in &mut rebalanced {
+                if let Some(position) = todo.dependencies.iter().position(|d| *d == change.todo_id)
+                {
+                    todo.dependencies
+                        .splice(position..=position, change.new_ids.iter().cloned());
+                }
+            }
+        }
+
+        self.todos = rebalanced;
+        self.update_metadata();
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_on_conjunctions() {
+        assert_eq!(
+            split_on_conjunctions("Implement login, add password reset, then deploy to staging"),
+            vec!["Implement login", "Add password reset", "Deploy to staging"]
+        );
+        assert_eq!(
+            split_on_conjunctions("Implement checkout flow"),
+            vec!["Implement checkout flow"]
+        );
+    }
+
+    #[test]
+    fn test_split_keeps_phrases_that_are_not_actions() {
+        for content in [
+            "Handle read and write errors",
+            "Design schema and API",
+            "Add tests and documentation",
+        ] {
+            assert_eq!(split_on_conjunctions(content), vec![content]);
+        }
+    }
+
+    #[test]
+    fn test_rebalance_granularity() {
+        let mut list = TodoList::new();
+        let large = Todo::builder("Implement login, add password reset and build account settings")
+            .id("auth")
+            .estimated_hours(30.0)
+            .build();
+        let small = Todo::builder("Update changelog entry")
+            .id("changelog")
+            .estimated_hours(0.5)
+            .build();
+        let dependent = Todo::builder("Deploy auth service")
+            .id("deploy")
+            .estimated_hours(6.0)
+            .dependencies(["auth"])
+            .build();
+        list.add_todo(large);
+        list.add_todo(small);
+        list.add_todo(dependent);
+
+        let report = list.rebalance_granularity(8.0);
+
+        assert_eq!(report.changes.len(), 1);
+        let change = &report.changes[0];
+        assert_eq!(change.todo_id, "auth");
+        assert_eq!(change.new_ids, vec!["auth_1", "auth_2", "auth_3"]);
+        assert_eq!(change.description, "Split 30.0h todo into 3 todos of 10.0h");
+
+        let contents: Vec<&str> = list.todos.iter().map(|t| t.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec![
+                "Implement login",
+                "Add password reset",
+                "Build account settings",
+                "Update changelog entry",
+                "Deploy auth service",
+            ]
+        );
+        assert!(list.todos[..3]
+            .iter()
+            .all(|t| t.estimated_hours == Some(10.0)));
+        assert_eq!(
+            list.todos[4].dependencies,
+            vec!["auth_1", "auth_2", "auth_3"]
+        );
+        assert!((list.metadata.total_estimated_hours - 36.5).abs() < f32::EPSILON);
+
+        assert_eq!(report.flagged.len(), 1);
+        assert_eq!(report.flagged[0].todo_id, "changelog");
+        assert!(report.flagged[0].reason.contains("far below"));
+    }
+
+    #[test]
+    fn test_rebalance_flags_unsplittable_todos() {
+        let mut list = TodoList::new();
+        list.add_todo(
+            Todo::builder("Implement payment provider integration")
+                .id("payments")
+                .estimated_hours(30.0)
+                .build(),
+        );
+
+        let report = list.rebalance_granularity(8.0);
+
+        assert!(report.changes.is_empty());
+        assert_eq!(report.flagged[0].todo_id, "payments");
+        assert_eq!(list.todos.len(), 1);
+        assert!(TodoList::new().rebalance_granularity(0.0).is_empty());
+
+        let mut list = TodoList::new();
+        list.add_todo(
+            Todo::builder("Handle read and write errors")
+                .id("errors")
+                .estimated_hours(30.0)
+                .build(),
+        );
+        let report = list.rebalance_granularity(8.0);
+        assert!(report.changes.is_empty());
+        assert!(report.flagged[0].reason.contains("separate actions"));
+        assert_eq!(list.todos[0].content, "Handle read and write errors");
+    }
+}
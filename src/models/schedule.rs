@@ -0,0 +1,415 @@
+//! Calendar-aware scheduling
+//!
+//! Turns estimated hours into calendar dates using a [`WorkCalendar`], so
+//! work never lands on weekends or holidays. Schedules assume either
+//! unlimited parallelism or a fixed number of workers.
+
+use crate::error::Error;
+use crate::models::todo::{stable_todo_key, Todo, TodoList};
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// Working days, holidays and daily capacity used for scheduling
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkCalendar {
+    /// Days of the week on which work happens
+    pub working_days: Vec<Weekday>,
+
+    /// Dates on which no work happens, even on working days
+    pub holidays: Vec<NaiveDate>,
+
+    /// Working hours available per working day
+    pub hours_per_day: f32,
+}
+
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        Self {
+            working_days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            holidays: Vec::new(),
+            hours_per_day: 8.0,
+        }
+    }
+}
+
+impl WorkCalendar {
+    /// Add a holiday
+    #[must_use]
+    pub fn with_holiday(mut self, date: NaiveDate) -> Self {
+        self.holidays.push(date);
+        self
+    }
+
+    /// Whether work happens on the given date
+    pub fn is_working_day(&self, date: NaiveDate) -> bool {
+        self.working_days.contains(&date.weekday()) && !self.holidays.contains(&date)
+    }
+
+    /// The `n`th working day on or after `from` (`n = 0` is the first one)
+    ///
+    /// Saturates at [`NaiveDate::MAX`] when the calendar runs out of dates
+    /// first.
+    pub fn nth_working_day(&self, from: NaiveDate, n: usize) -> NaiveDate {
+        let mut date = from;
+        let mut remaining = n;
+        loop {
+            if self.is_working_day(date) {
+                if remaining == 0 {
+                    return date;
+                }
+                remaining -= 1;
+            }
+            match date.succ_opt() {
+                Some(next) => date = next,
+                None => return date,
+            }
+        }
+    }
+
+    /// Date on which `hours` of work starting at the beginning of `start` finish
+    ///
+    /// Zero hours finish on the first working day on or after `start`.
+    pub fn add_work_hours(&self, start: NaiveDate, hours: f32) -> NaiveDate {
+        let days = whole_days((hours / self.hours_per_day).ceil());
+        self.nth_working_day(start, days.saturating_sub(1))
+    }
+
+    /// Check that the calendar can make progress
+    fn ensure_usable(&self) -> crate::Result<()> {
+        if self.working_days.is_empty() {
+            return Err(Error::config("work calendar has no working days"));
+        }
+        if self.hours_per_day.is_nan() || self.hours_per_day <= 0.0 {
+            return Err(Error::config(
+                "work calendar hours per day must be positive",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Calendar date of a working-hour offset from the start of `start`
+    fn date_at_offset(&self, start: NaiveDate, offset: f32) -> NaiveDate {
+        self.nth_working_day(start, whole_days((offset / self.hours_per_day).floor()))
+    }
+}
+
+/// Convert an already rounded day count to an index
+///
+/// Float-to-integer `as` casts saturate, so negative and NaN counts become
+/// zero and counts beyond `usize::MAX` clamp to it.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn whole_days(days: f32) -> usize {
+    days as usize
+}
+
+/// Scheduled dates for a single todo
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledTodo {
+    /// Todo identifier
+    pub id: String,
+
+    /// First working day of the todo
+    pub start: NaiveDate,
+
+    /// Last working day of the todo
+    pub finish: NaiveDate,
+}
+
+/// Calendar schedule for a todo list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Schedule {
+    /// Requested project start date
+    pub start: NaiveDate,
+
+    /// Date on which the last todo finishes
+    pub finish: NaiveDate,
+
+    /// Per-todo dates, in list order
+    pub todos: Vec<ScheduledTodo>,
+}
+
+impl TodoList {
+    /// Schedule the list on a calendar with unlimited parallelism
+    ///
+    /// Each todo starts as soon as all of its dependencies have finished and
+    /// takes its remaining effort (zero when unestimated or completed).
+    /// Dependencies on unknown IDs are ignored. Fails when the dependency
+    /// graph has a cycle or the calendar has no working capacity.
+    pub fn schedule(&self, start: NaiveDate, calendar: &WorkCalendar) -> crate::Result<Schedule> {
+        calendar.ensure_usable()?;
+
+        let by_id: HashMap<&str, _> = self.todos.iter().map(|t| (t.id.as_str(), t)).collect();
+        let mut spans: HashMap<&str, (f32, f32)> = HashMap::new();
+        for group in self.parallel_groups()? {
+            for id in &group {
+                let todo = by_id[id.as_str()];
+                let begin = todo
+                    .dependencies
+                    .iter()
+                    .filter_map(|dep| spans.get(dep.as_str()))
+                    .map(|&(_, end)| end)
+                    .fold(0.0, f32::max);
+                spans.insert(todo.id.as_str(), (begin, begin + scheduled_hours(todo)));
+            }
+        }
+
+        Ok(self.schedule_from_spans(&spans, start, calendar))
+    }
+
+    /// Schedule the list on a calendar with a fixed number of workers
+    ///
+    /// Whenever a worker is free it takes the ready todo (all dependencies
+    /// finished) with the highest priority, ties going to the lowest ID; a
+    /// worker with nothing ready waits for the next todo to become ready.
+    /// Efforts and unknown dependencies are treated as in
+    /// [`TodoList::schedule`]. Fails when `workers` is zero, the dependency
+    /// graph has a cycle or the calendar has no working capacity.
+    pub fn schedule_with_resources(
+        &self,
+        workers: usize,
+        start: NaiveDate,
+        calendar: &WorkCalendar,
+    ) -> crate::Result<Schedule> {
+        calendar.ensure_usable()?;
+        if workers == 0 {
+            return Err(Error::invalid_input("schedule needs at least one worker"));
+        }
+        // Rejects dependency cycles, so some todo is always ready below
+        self.parallel_groups()?;
+
+        let known: HashSet<&str> = self.todos.iter().map(|t| t.id.as_str()).collect();
+        let mut spans: HashMap<&str, (f32, f32)> = HashMap::new();
+        let mut free_at = vec![0.0_f32; workers];
+        let mut pending: Vec<&Todo> = self.todos.iter().collect();
+        while !pending.is_empty() {
+            // Todos whose dependencies are all scheduled, with when they become ready
+            let ready: Vec<(usize, f32)> = pending
+                .iter()
+                .enumerate()
+                .filter_map(|(index, todo)| {
+                    todo.dependencies
+                        .iter()
+                        .filter(|dep| known.contains(dep.as_str()))
+                        .try_fold(0.0_f32, |at, dep| {
+                            spans.get(dep.as_str()).map(|&(_, end)| at.max(end))
+                        })
+                        .map(|at| (index, at))
+                })
+                .collect();
+            let Some((worker, available)) = free_at
+                .iter()
+                .copied()
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+            else {
+                break;
+            };
+            let earliest = ready
+                .iter()
+                .map(|&(_, at)| at)
+                .fold(f32::INFINITY, f32::min);
+            let begin = available.max(earliest);
+            let Some(index) = ready
+                .iter()
+                .filter(|&&(_, at)| at <= begin)
+                .map(|&(index, _)| index)
+                .min_by_key(|&index| {
+                    (
+                        Reverse(pending[index].priority),
+                        stable_todo_key(pending[index]),
+                    )
+                })
+            else {
+                break;
+            };
+
+            let todo = pending.swap_remove(index);
+            let end = begin + scheduled_hours(todo);
+            spans.insert(todo.id.as_str(), (begin, end));
+            free_at[worker] = end;
+        }
+
+        Ok(self.schedule_from_spans(&spans, start, calendar))
+    }
+
+    /// Turn working-hour spans keyed by todo ID into calendar dates
+    fn schedule_from_spans(
+        &self,
+        spans: &HashMap<&str, (f32, f32)>,
+        start: NaiveDate,
+        calendar: &WorkCalendar,
+    ) -> Schedule {
+        let project_end = spans.values().map(|&(_, end)| end).fold(0.0, f32::max);
+        let todos = self
+            .todos
+            .iter()
+            .map(|todo| {
+                let (begin, end) = spans[todo.id.as_str()];
+                let first_day = calendar.date_at_offset(start, begin);
+                ScheduledTodo {
+                    id: todo.id.clone(),
+                    start: first_day,
+                    finish: calendar.add_work_hours(start, end).max(first_day),
+                }
+            })
+            .collect();
+
+        Schedule {
+            start,
+            finish: calendar.add_work_hours(start, project_end),
+            todos,
+        }
+    }
+}
+
+/// Working hours a todo occupies in a schedule
+fn scheduled_hours(todo: &Todo) -> f32 {
+    todo.effort_remaining().unwrap_or(0.0).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::{test_todo, TodoPriority};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_skips_weekend() {
+        let mut list = TodoList::new();
+        list.add_todo(test_todo("design", &[]).estimated_hours(8.0).build());
+        list.add_todo(
+            test_todo("build", &["design"])
+                .estimated_hours(12.0)
+                .build(),
+        );
+        list.add_todo(test_todo("docs", &[]).estimated_hours(4.0).build());
+
+        // 2026-10-16 is a Friday
+        let schedule = list
+            .schedule(date(2026, 10, 16), &WorkCalendar::default())
+            .unwrap();
+
+        assert_eq!(schedule.todos[0].start, date(2026, 10, 16));
+        assert_eq!(schedule.todos[0].finish, date(2026, 10, 16));
+        assert_eq!(schedule.todos[1].start, date(2026, 10, 19));
+        assert_eq!(schedule.todos[1].finish, date(2026, 10, 20));
+        assert_eq!(schedule.todos[2].finish, date(2026, 10, 16));
+        assert_eq!(schedule.finish, date(2026, 10, 20));
+    }
+
+    #[test]
+    fn test_schedule_skips_holidays() {
+        let mut list = TodoList::new();
+        list.add_todo(test_todo("a", &[]).estimated_hours(16.0).build());
+
+        let calendar = WorkCalendar::default().with_holiday(date(2026, 10, 19));
+        let schedule = list.schedule(date(2026, 10, 17), &calendar).unwrap();
+
+        assert_eq!(schedule.todos[0].start, date(2026, 10, 20));
+        assert_eq!(schedule.finish, date(2026, 10, 21));
+    }
+
+    #[test]
+    fn test_schedule_with_one_worker_serializes() {
+        let mut list = TodoList::new();
+        list.add_todo(test_todo("a", &[]).estimated_hours(8.0).build());
+        list.add_todo(test_todo("b", &[]).estimated_hours(8.0).build());
+        list.add_todo(test_todo("c", &[]).estimated_hours(8.0).build());
+        list.todos[2].priority = TodoPriority::Critical;
+
+        // 2026-10-19 is a Monday
+        let monday = date(2026, 10, 19);
+        let calendar = WorkCalendar::default();
+        let serial = list.schedule_with_resources(1, monday, &calendar).unwrap();
+
+        // Highest priority first, then by ID
+        let days: Vec<(&str, NaiveDate, NaiveDate)> = serial
+            .todos
+            .iter()
+            .map(|t| (t.id.as_str(), t.start, t.finish))
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                ("a", date(2026, 10, 20), date(2026, 10, 20)),
+                ("b", date(2026, 10, 21), date(2026, 10, 21)),
+                ("c", monday, monday),
+            ]
+        );
+        assert_eq!(serial.finish, date(2026, 10, 21));
+
+        let parallel = list.schedule_with_resources(3, monday, &calendar).unwrap();
+        assert_eq!(parallel, list.schedule(monday, &calendar).unwrap());
+        assert_eq!(parallel.finish, monday);
+    }
+
+    #[test]
+    fn test_schedule_with_resources_respects_dependencies() {
+        let mut list = TodoList::new();
+        list.add_todo(test_todo("design", &[]).estimated_hours(8.0).build());
+        list.add_todo(test_todo("build", &["design"]).estimated_hours(8.0).build());
+        list.add_todo(test_todo("docs", &[]).estimated_hours(4.0).build());
+
+        let monday = date(2026, 10, 19);
+        let schedule = list
+            .schedule_with_resources(2, monday, &WorkCalendar::default())
+            .unwrap();
+
+        assert_eq!(schedule.todos[1].start, date(2026, 10, 20));
+        assert_eq!(schedule.todos[2].start, monday);
+        assert_eq!(schedule.finish, date(2026, 10, 20));
+        assert!(list
+            .schedule_with_resources(0, monday, &WorkCalendar::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_schedule_errors() {
+        let mut cyclic = TodoList::new();
+        cyclic.add_todo(test_todo("a", &["b"]).estimated_hours(1.0).build());
+        cyclic.add_todo(test_todo("b", &["a"]).estimated_hours(1.0).build());
+        assert!(cyclic
+            .schedule(date(2026, 10, 16), &WorkCalendar::default())
+            .is_err());
+
+        let idle = WorkCalendar {
+            working_days: Vec::new(),
+            ..WorkCalendar::default()
+        };
+        assert!(TodoList::new().schedule(date(2026, 10, 16), &idle).is_err());
+    }
+
+    #[test]
+    fn test_nth_working_day_saturates_at_last_date() {
+        let every_day = WorkCalendar {
+            working_days: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ],
+            ..WorkCalendar::default()
+        };
+        let near_end = NaiveDate::MAX.pred_opt().unwrap();
+
+        assert_eq!(every_day.nth_working_day(near_end, 5), NaiveDate::MAX);
+        assert_eq!(
+            every_day.add_work_hours(near_end, f32::INFINITY),
+            NaiveDate::MAX
+        );
+    }
+}
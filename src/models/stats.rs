@@ -0,0 +1,285 @@
+//! Todo list statistics
+//!
+//! Consolidated, serializable summary of a todo list for dashboards and reports.
+
+use crate::models::todo::{longest_chain_by, longest_weighted_chain, Todo, TodoList};
+use crate::models::todo::{TodoPriority, TodoStatus};
+use crate::utils::count_as_f32;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Contingency added on top of raw estimates for buffered hours (20%)
+pub const ESTIMATE_BUFFER_RATIO: f32 = 0.2;
+
+/// Consolidated statistics for a todo list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TodoStats {
+    /// Total number of todos
+    pub total_count: usize,
+
+    /// Count by status
+    pub status_counts: HashMap<TodoStatus, usize>,
+
+    /// Count by priority
+    pub priority_counts: HashMap<TodoPriority, usize>,
+
+    /// Sum of all time estimates
+    pub total_hours: f32,
+
+    /// Average estimated hours per todo
+    pub avg_hours: f32,
+
+    /// Total hours including the [`ESTIMATE_BUFFER_RATIO`] contingency
+    pub buffered_hours: f32,
+
+    /// Fraction of todos completed (0.0-1.0)
+    pub completion_ratio: f32,
+
+    /// Hours of work left, using remaining effort where recorded
+    pub remaining_hours: f32,
+
+    /// Fraction of estimated hours completed (0.0-1.0)
+    pub hours_completion_ratio: f32,
+
+    /// Complexity score distribution
+    pub complexity: ComplexityPercentiles,
+
+    /// Dependency graph statistics
+    pub dependencies: DependencyStats,
+}
+
+/// Complexity score percentiles (nearest-rank)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ComplexityPercentiles {
+    /// Median complexity
+    pub p50: u8,
+
+    /// 90th percentile complexity
+    pub p90: u8,
+
+    /// Maximum complexity
+    pub max: u8,
+}
+
+/// Dependency graph statistics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DependencyStats {
+    /// Total number of dependency relationships
+    pub total_dependencies: usize,
+
+    /// Number of todos in the longest dependency chain (0 when cyclic)
+    pub max_depth: usize,
+
+    /// IDs along the longest hours-weighted chain (empty when cyclic)
+    pub critical_path: Vec<String>,
+
+    /// Estimated hours along the critical path
+    pub critical_path_hours: f32,
+
+    /// Whether the dependency graph contains cycles
+    pub has_cycles: bool,
+}
+
+impl TodoList {
+    /// Compute consolidated statistics for the list
+    pub fn stats(&self) -> TodoStats {
+        let total_count = self.todos.len();
+
+        let mut status_counts = HashMap::new();
+        let mut priority_counts = HashMap::new();
+        for todo in &self.todos {
+            *status_counts.entry(todo.status).or_insert(0) += 1;
+            *priority_counts.entry(todo.priority).or_insert(0) += 1;
+        }
+
+        let total_hours: f32 = self.todos.iter().filter_map(|t| t.estimated_hours).sum();
+        let remaining_hours = self.remaining_hours();
+        let completed_count = status_counts
+            .get(&TodoStatus::Completed)
+            .copied()
+            .unwrap_or(0);
+
+        let (avg_hours, completion_ratio) = if total_count > 0 {
+            (
+                total_hours / count_as_f32(total_count),
+                count_as_f32(completed_count) / count_as_f32(total_count),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let hours_completion_ratio = if total_hours > 0.0 {
+            ((total_hours - remaining_hours) / total_hours).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        TodoStats {
+            total_count,
+            status_counts,
+            priority_counts,
+            total_hours,
+            avg_hours,
+            buffered_hours: total_hours * (1.0 + ESTIMATE_BUFFER_RATIO),
+            completion_ratio,
+            remaining_hours,
+            hours_completion_ratio,
+            complexity: ComplexityPercentiles::from_todos(&self.todos),
+            dependencies: DependencyStats::from_list(self),
+        }
+    }
+}
+
+impl ComplexityPercentiles {
+    fn from_todos(todos: &[Todo]) -> Self {
+        let mut scores: Vec<u8> = todos.iter().map(Todo::complexity_score).collect();
+        scores.sort_unstable();
+
+        let percentile = |percent: usize| {
+            let rank = (percent * scores.len()).div_ceil(100).max(1);
+            scores.get(rank - 1).copied().unwrap_or(0)
+        };
+
+        Self {
+            p50: percentile(50),
+            p90: percentile(90),
+            max: scores.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl DependencyStats {
+    fn from_list(list: &TodoList) -> Self {
+        let todos: Vec<&Todo> = list.todos.iter().collect();
+        let by_id: HashMap<&str, &Todo> = todos.iter().map(|t| (t.id.as_str(), *t)).collect();
+
+        let critical_path = longest_weighted_chain(&todos);
+        let critical_path_hours = critical_path
+            .iter()
+            .filter_map(|id| by_id.get(id.as_str()))
+            .filter_map(|t| t.estimated_hours)
+            .sum();
+
+        Self {
+            total_dependencies: list.todos.iter().map(|t| t.dependencies.len()).sum(),
+            max_depth: longest_chain_by(&todos, |_| 1.0).len(),
+            critical_path,
+            critical_path_hours,
+            has_cycles: list.validate_dependencies().is_err(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_match_hand_computed_values() {
+        let mut list = TodoList::new();
+
+        list.add_todo(
+            Todo::builder("Design database schema")
+                .id("schema")
+                .estimated_hours(4.0)
+                .status(TodoStatus::Completed)
+                .priority(TodoPriority::High)
+                .build(),
+        );
+        list.add_todo(
+            Todo::builder("Implement API endpoints")
+                .id("api")
+                .estimated_hours(6.0)
+                .dependencies(["schema"])
+                .build(),
+        );
+        list.add_todo(
+            Todo::builder("Write usage guide")
+                .id("docs")
+                .estimated_hours(2.0)
+                .build(),
+        );
+        list.add_todo(
+            Todo::builder("Optimize database performance and security")
+                .id("perf")
+                .estimated_hours(8.0)
+                .dependencies(["api"])
+                .build(),
+        );
+
+        let stats = list.stats();
+
+        assert_eq!(stats.total_count, 4);
+        assert_eq!(stats.status_counts[&TodoStatus::Completed], 1);
+        assert_eq!(stats.status_counts[&TodoStatus::Pending], 3);
+        assert_eq!(stats.priority_counts[&TodoPriority::High], 1);
+        assert_eq!(stats.priority_counts[&TodoPriority::Medium], 3);
+
+        assert!((stats.total_hours - 20.0).abs() < f32::EPSILON);
+        assert!((stats.avg_hours - 5.0).abs() < f32::EPSILON);
+        assert!((stats.buffered_hours - 24.0).abs() < 1e-4);
+        assert!((stats.completion_ratio - 0.25).abs() < f32::EPSILON);
+        assert!((stats.remaining_hours - 16.0).abs() < f32::EPSILON);
+        assert!((stats.hours_completion_ratio - 0.2).abs() < f32::EPSILON);
+
+        // Scores: docs 1, schema 2, api 2, perf 5
+        assert_eq!(
+            stats.complexity,
+            ComplexityPercentiles {
+                p50: 2,
+                p90: 5,
+                max: 5
+            }
+        );
+
+        assert_eq!(stats.dependencies.total_dependencies, 2);
+        assert_eq!(stats.dependencies.max_depth, 3);
+        assert_eq!(
+            stats.dependencies.critical_path,
+            vec!["schema", "api", "perf"]
+        );
+        assert!((stats.dependencies.critical_path_hours - 18.0).abs() < f32::EPSILON);
+        assert!(!stats.dependencies.has_cycles);
+    }
+
+    #[test]
+    fn test_hours_completion_uses_remaining_effort() {
+        let mut list = TodoList::new();
+
+        let mut api = Todo::builder("Implement API endpoints")
+            .id("api")
+            .estimated_hours(8.0)
+            .status(TodoStatus::InProgress)
+            .build();
+        api.remaining_hours = Some(2.0);
+        list.add_todo(api);
+        list.add_todo(
+            Todo::builder("Write usage guide")
+                .id("docs")
+                .estimated_hours(2.0)
+                .build(),
+        );
+
+        let stats = list.stats();
+        assert!((stats.remaining_hours - 4.0).abs() < f32::EPSILON);
+        assert!((stats.hours_completion_ratio - 0.6).abs() < f32::EPSILON);
+
+        list.todos[0].set_status(TodoStatus::Completed);
+        assert_eq!(list.todos[0].remaining_hours, Some(0.0));
+        assert!((list.stats().hours_completion_ratio - 0.8).abs() < f32::EPSILON);
+
+        // Cancelled work is no longer outstanding
+        list.todos[1].set_status(TodoStatus::Cancelled);
+        assert_eq!(list.todos[1].effort_remaining(), Some(0.0));
+        assert!(list.remaining_hours().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_stats_empty_list() {
+        let stats = TodoList::new().stats();
+
+        assert_eq!(stats.total_count, 0);
+        assert_eq!(stats.complexity, ComplexityPercentiles::default());
+        assert_eq!(stats.dependencies.max_depth, 0);
+        assert!(stats.dependencies.critical_path.is_empty());
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Data structures specifically for todo list generation and validation.
 
+use crate::utils::count_as_f32;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 
 /// Complete todo list structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,9 +14,11 @@ pub struct TodoList {
     pub todos: Vec<Todo>,
 
     /// Metadata about the todo list
+    #[serde(default)]
     pub metadata: TodoListMetadata,
 
     /// Optional project context
+    #[serde(default)]
     pub project: Option<ProjectContext>,
 }
 
@@ -36,27 +40,62 @@ pub struct Todo {
     /// Estimated hours to complete (0.5-40 hours)
     pub estimated_hours: Option<f32>,
 
+    /// Confidence in the time estimate (0.0-1.0)
+    #[serde(default)]
+    pub estimate_confidence: Option<f32>,
+
+    /// Hours of work left (defaults to `estimated_hours` when absent)
+    #[serde(default)]
+    pub remaining_hours: Option<f32>,
+
     /// Dependencies (IDs of other todos that must complete first)
+    #[serde(default)]
     pub dependencies: Vec<String>,
 
     /// Quality gates for this todo
+    #[serde(default)]
     pub quality_gates: TodoQualityGates,
 
     /// Optional tags for categorization
+    #[serde(default)]
     pub tags: Vec<String>,
 
     /// Optional assignee
+    #[serde(default)]
     pub assignee: Option<String>,
 
+    /// Reason the todo is blocked (expected when status is `Blocked`)
+    #[serde(default)]
+    pub blocked_reason: Option<String>,
+
+    /// Conditions that must hold for the todo to count as done
+    #[serde(default)]
+    pub acceptance_criteria: Vec<String>,
+
+    /// Questions that must be answered before work can start
+    #[serde(default)]
+    pub open_questions: Vec<String>,
+
+    /// References to external artifacts (design docs, PRs, tickets)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<Link>,
+
     /// Optional due date
     #[cfg(feature = "todo-validation")]
     pub due_date: Option<chrono::DateTime<chrono::Utc>>,
 
-    /// Creation timestamp
+    /// Creation timestamp (the Unix epoch when absent, keeping parsing deterministic)
     #[cfg(feature = "todo-validation")]
+    #[serde(default)]
     pub created_at: chrono::DateTime<chrono::Utc>,
 
+    /// Audit trail of status changes made through [`Todo::set_status`]
+    #[cfg(feature = "todo-validation")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<StatusChange>,
+
     /// Custom fields
+    #[serde(default)]
     pub custom_fields: HashMap<String, serde_json::Value>,
 }
 
@@ -76,9 +115,55 @@ pub enum TodoStatus {
     Cancelled,
 }
 
+/// Reference from a todo to an external artifact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    /// Display title
+    pub title: String,
+
+    /// Target URL (http or https)
+    pub url: String,
+}
+
+impl Link {
+    /// Create a new link
+    pub fn new<T: Into<String>, U: Into<String>>(title: T, url: U) -> Self {
+        Self {
+            title: title.into(),
+            url: url.into(),
+        }
+    }
+
+    /// Whether the URL uses the http or https scheme and names a host
+    pub fn has_web_url(&self) -> bool {
+        self.url.split_once("://").is_some_and(|(scheme, rest)| {
+            (scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https"))
+                && !rest.is_empty()
+                && !rest.starts_with('/')
+        })
+    }
+}
+
+/// A recorded status transition
+#[cfg(feature = "todo-validation")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    /// Status before the change
+    pub from: TodoStatus,
+
+    /// Status after the change
+    pub to: TodoStatus,
+
+    /// When the change was made
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
 /// Todo priority levels
+///
+/// Deserialization accepts the canonical names and the aliases in
+/// [`TodoPriority::default_aliases`], case-insensitively.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase", try_from = "String")]
 pub enum TodoPriority {
     /// Low priority
     Low,
@@ -140,6 +225,10 @@ pub struct TodoListMetadata {
     /// Template version used for generation
     pub template_version: String,
 
+    /// Unit in which estimates are expressed
+    #[serde(default)]
+    pub estimate_unit: EstimateUnit,
+
     /// Custom metadata fields
     pub custom_metadata: HashMap<String, serde_json::Value>,
 }
@@ -170,6 +259,15 @@ pub struct ProjectContext {
     pub budget_hours: Option<f32>,
 }
 
+/// Requirement count accepted for generation unless an input sets its own
+pub const DEFAULT_MAX_REQUIREMENTS: usize = 100;
+
+/// Serde default for [`TodoInput::max_requirements`]
+#[allow(clippy::unnecessary_wraps)] // serde needs the field's own type
+const fn default_max_requirements() -> Option<usize> {
+    Some(DEFAULT_MAX_REQUIREMENTS)
+}
+
 /// Input for todo list generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TodoInput {
@@ -191,11 +289,84 @@ pub struct TodoInput {
     /// Maximum number of todos to generate
     pub max_todos: Option<usize>,
 
+    /// Maximum number of requirements accepted for generation
+    ///
+    /// Defaults to [`DEFAULT_MAX_REQUIREMENTS`]; `None` removes the limit.
+    #[serde(default = "default_max_requirements")]
+    pub max_requirements: Option<usize>,
+
     /// Whether to include time estimates
     pub include_estimates: bool,
 
     /// Default priority for generated todos
     pub default_priority: Option<TodoPriority>,
+
+    /// How generated todo IDs are derived
+    #[serde(default)]
+    pub id_scheme: TodoIdScheme,
+}
+
+/// Deterministic ID schemes for generated todos
+///
+/// Every scheme depends only on the input, so regenerating from the same
+/// input yields the same IDs and successive generations can be diffed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoIdScheme {
+    /// `todo_{requirement_index}_{subtask_index}`, e.g. `todo_0_1`
+    #[default]
+    RequirementIndex,
+    /// `{requirement_slug}_{subtask_index}`, e.g. `user_auth_1`; repeated
+    /// slugs get the requirement index appended (`user_auth_2_0`), counting up
+    /// from there until the prefix is unused
+    Slug,
+    /// `todo_{n}` numbered across the whole list from 0
+    Global,
+}
+
+/// Unit in which todo estimates are expressed
+///
+/// Estimates are always stored in `estimated_hours`; the unit only changes
+/// how the number is validated and labelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EstimateUnit {
+    /// Working hours
+    #[default]
+    Hours,
+    /// Story points
+    Points,
+    /// Working days
+    Days,
+}
+
+impl EstimateUnit {
+    /// Plural label used in exports, e.g. `points`
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Hours => "hours",
+            Self::Points => "points",
+            Self::Days => "days",
+        }
+    }
+
+    /// Short suffix used after numbers, e.g. `8.0pt`
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Hours => "h",
+            Self::Points => "pt",
+            Self::Days => "d",
+        }
+    }
+
+    /// Sane `(min, max)` range for a single todo's estimate
+    pub const fn default_bounds(self) -> (f32, f32) {
+        match self {
+            Self::Hours => (0.5, 40.0),
+            Self::Points => (1.0, 13.0),
+            Self::Days => (0.5, 5.0),
+        }
+    }
 }
 
 /// Granularity levels for todo generation
@@ -242,6 +413,199 @@ pub struct TodoQualityConfig {
 
     /// Maximum hours for time estimates
     pub max_estimated_hours: Option<f32>,
+
+    /// Maximum dependency depth for high and critical priority todos
+    #[serde(default)]
+    pub max_priority_depth: Option<usize>,
+
+    /// Increment that time estimates should be multiples of (e.g. 0.5 hours)
+    #[serde(default)]
+    pub estimate_granule: Option<f32>,
+
+    /// Largest list allowed without any dependencies when a graph is required
+    #[serde(default)]
+    pub max_flat_list_size: Option<usize>,
+
+    /// Minimum number of distinct meaningful words (excluding stop words)
+    #[serde(default)]
+    pub min_meaningful_tokens: Option<usize>,
+
+    /// Maximum number of todos on the critical path
+    #[serde(default)]
+    pub max_critical_path_length: Option<usize>,
+
+    /// Estimates below this many hours count as too small to be useful
+    #[serde(default)]
+    pub min_useful_estimate_hours: Option<f32>,
+
+    /// Largest share (0.0-1.0) of estimated todos allowed below the useful minimum
+    #[serde(default)]
+    pub max_tiny_todo_fraction: Option<f32>,
+
+    /// Maximum nesting depth of any custom field value
+    #[serde(default)]
+    pub max_custom_field_depth: Option<usize>,
+
+    /// Maximum size in bytes of a todo's custom fields serialized as JSON
+    #[serde(default)]
+    pub max_custom_fields_bytes: Option<usize>,
+}
+
+/// Fields of a todo that take part in logical equality
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct CanonicalTodo<'a> {
+    content: &'a str,
+    /// `TodoStatus` discriminant, since the status has no ordering
+    status: u8,
+    priority: TodoPriority,
+    estimated_hours: Option<u32>,
+    dependencies: Vec<&'a str>,
+    tags: Vec<&'a str>,
+}
+
+/// Lowest confidence used when inflating estimates, capping inflation at 10x
+pub const MIN_ESTIMATE_CONFIDENCE: f32 = 0.1;
+
+/// Definition of ready: requirements a todo must meet before work starts
+///
+/// Each flag switches one independent requirement on or off, so the flags
+/// are not a hidden state machine and are kept as plain booleans.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessPolicy {
+    /// Whether a time estimate is required
+    pub require_estimate: bool,
+
+    /// Whether an assignee is required
+    pub require_assignee: bool,
+
+    /// Whether at least one acceptance criterion is required
+    pub require_acceptance_criteria: bool,
+
+    /// Whether all open questions must be resolved
+    pub require_no_open_questions: bool,
+}
+
+/// Highest score [`Todo::complexity_score_with`] returns
+pub const MAX_COMPLEXITY_SCORE: u8 = 10;
+
+/// Shared default model so [`Todo::complexity_score`] does not rebuild it
+static DEFAULT_COMPLEXITY_MODEL: LazyLock<ComplexityModel> =
+    LazyLock::new(ComplexityModel::default);
+
+/// Keyword lists and weights used to score todo complexity
+///
+/// The default model targets software tasks; other domains can supply their
+/// own vocabulary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplexityModel {
+    /// Words that each add `keyword_weight` when they appear in the content,
+    /// ignoring case
+    pub keywords: Vec<String>,
+
+    /// Points added per matching keyword
+    pub keyword_weight: u8,
+
+    /// Technical terms; any match adds `technical_term_weight` once
+    pub technical_terms: Vec<String>,
+
+    /// Points added when at least one technical term appears
+    pub technical_term_weight: u8,
+}
+
+/// Verbs that make task text actionable when no custom list is configured
+pub const DEFAULT_ACTION_VERBS: &[&str] = &[
+    "implement",
+    "create",
+    "build",
+    "write",
+    "add",
+    "remove",
+    "update",
+    "fix",
+    "test",
+    "deploy",
+    "configure",
+    "setup",
+    "install",
+    "design",
+    "develop",
+    "refactor",
+    "optimize",
+    "migrate",
+    "integrate",
+    "debug",
+    "analyze",
+    "research",
+    "document",
+    "validate",
+    "verify",
+    "review",
+];
+
+/// Check whether task text starts with an action verb
+pub(crate) fn starts_with_action_verb(content: &str) -> bool {
+    starts_with_any_verb(content, DEFAULT_ACTION_VERBS)
+}
+
+/// Check whether task text starts with one of `verbs`, ignoring case
+pub(crate) fn starts_with_any_verb<S: AsRef<str>>(content: &str, verbs: &[S]) -> bool {
+    let lower_content = content.to_lowercase();
+    verbs.iter().any(|verb| {
+        let verb = verb.as_ref().trim().to_lowercase();
+        !verb.is_empty() && lower_content.starts_with(&verb)
+    })
+}
+
+/// Filler words that carry no information about the task
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "but", "to", "of", "in", "on", "at", "for", "with", "by",
+    "from", "as", "it", "its", "this", "that", "these", "those", "is", "are", "be", "was", "some",
+    "any", "all", "now", "then", "just", "really", "very", "okay", "ok", "please", "asap", "again",
+    "up", "out", "do", "get", "make", "thing", "things", "stuff",
+];
+
+/// Count distinct words in task text that are not stop words
+///
+/// Words are compared case-insensitively; single characters and pure
+/// numbers are ignored.
+pub(crate) fn meaningful_token_count(content: &str) -> usize {
+    meaningful_tokens(content).len()
+}
+
+/// Distinct lowercase words in task text that are not stop words
+fn meaningful_tokens(content: &str) -> HashSet<String> {
+    content
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.chars().count() > 1 && !word.chars().all(|c| c.is_ascii_digit()))
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Leading verbs of each work stage, in the order the stages usually happen
+const WORK_STAGES: &[&[&str]] = &[
+    &["research", "analyze", "design", "plan", "spec"],
+    &["implement", "build", "create", "develop", "write", "add"],
+    &["test", "verify", "validate", "review"],
+    &["deploy", "release", "ship", "publish"],
+];
+
+/// Minimum Jaccard similarity of two subjects to be treated as the same work
+const SUBJECT_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Split task text into its work stage and the meaningful words of its subject
+fn work_stage(content: &str) -> Option<(usize, HashSet<String>)> {
+    let content = content.trim();
+    let (verb, subject) = content
+        .split_once(char::is_whitespace)
+        .unwrap_or((content, ""));
+    let verb = verb.to_lowercase();
+    let stage = WORK_STAGES
+        .iter()
+        .position(|verbs| verbs.contains(&verb.as_str()))?;
+    let subject = meaningful_tokens(subject);
+    (!subject.is_empty()).then_some((stage, subject))
 }
 
 impl Todo {
@@ -253,53 +617,55 @@ impl Todo {
             status: TodoStatus::Pending,
             priority: TodoPriority::Medium,
             estimated_hours: None,
+            estimate_confidence: None,
+            remaining_hours: None,
             dependencies: Vec::new(),
             quality_gates: TodoQualityGates::default(),
             tags: Vec::new(),
             assignee: None,
+            blocked_reason: None,
+            acceptance_criteria: Vec::new(),
+            open_questions: Vec::new(),
+            links: Vec::new(),
             #[cfg(feature = "todo-validation")]
             due_date: None,
             #[cfg(feature = "todo-validation")]
             created_at: chrono::Utc::now(),
+            #[cfg(feature = "todo-validation")]
+            history: Vec::new(),
             custom_fields: HashMap::new(),
         }
     }
 
+    /// Create a todo whose ID is derived from its content and a seed
+    ///
+    /// The same content and seed always yield the same UUID-formatted ID, so
+    /// lists built this way are reproducible. Give todos with identical
+    /// content distinct seeds, e.g. a base seed plus their index.
+    pub fn new_deterministic<S: Into<String>>(content: S, seed: u64) -> Self {
+        let mut todo = Self::new(content);
+        todo.id = crate::utils::generate_seeded_content_id(seed, &todo.content);
+        todo
+    }
+
+    /// Start building a todo with the given content
+    pub fn builder<S: Into<String>>(content: S) -> TodoBuilder {
+        TodoBuilder {
+            todo: Self::new(content),
+        }
+    }
+
     /// Check if todo is actionable (starts with action verb)
     pub fn is_actionable(&self) -> bool {
-        let actionable_verbs = [
-            "implement",
-            "create",
-            "build",
-            "write",
-            "add",
-            "remove",
-            "update",
-            "fix",
-            "test",
-            "deploy",
-            "configure",
-            "setup",
-            "install",
-            "design",
-            "develop",
-            "refactor",
-            "optimize",
-            "migrate",
-            "integrate",
-            "debug",
-            "analyze",
-            "research",
-            "document",
-            "validate",
-            "verify",
-            "review",
-        ];
-
-        let lower_content = self.content.to_lowercase();
-        actionable_verbs
-            .iter()
-            .any(|verb| lower_content.starts_with(verb))
+        starts_with_action_verb(&self.content)
+    }
+
+    /// Check if todo starts with one of the given verbs, ignoring case
+    ///
+    /// Use this for localized or domain-specific lists, where
+    /// [`DEFAULT_ACTION_VERBS`] does not apply.
+    pub fn is_actionable_with(&self, verbs: &[&str]) -> bool {
+        starts_with_any_verb(&self.content, verbs)
     }
 
     /// Check if content length is within valid range
@@ -310,38 +676,37 @@ impl Todo {
 
     /// Estimate complexity score (1-10) based on content
     pub fn complexity_score(&self) -> u8 {
+        self.complexity_score_with(&DEFAULT_COMPLEXITY_MODEL)
+    }
+
+    /// Calculate complexity score (1-10) using a custom model
+    ///
+    /// Starts at 1, adds the model's weights for matching keywords and
+    /// technical terms (compared case-insensitively), and one point per two
+    /// extra actions joined by " and " or ", ".
+    pub fn complexity_score_with(&self, model: &ComplexityModel) -> u8 {
         let content = &self.content.to_lowercase();
-        let mut score = 1;
-
-        // Check for complexity indicators
-        let complexity_words = [
-            "integrate",
-            "refactor",
-            "optimize",
-            "migrate",
-            "analyze",
-            "algorithm",
-            "performance",
-            "security",
-            "architecture",
-        ];
-
-        for word in &complexity_words {
-            if content.contains(word) {
-                score += 1;
+        let mut score: u8 = 1;
+
+        for word in &model.keywords {
+            if content.contains(&word.to_lowercase()) {
+                score = score.saturating_add(model.keyword_weight);
             }
         }
 
-        // Check for technical terms
-        if content.contains("database") || content.contains("api") || content.contains("system") {
-            score += 1;
+        if model
+            .technical_terms
+            .iter()
+            .any(|term| content.contains(&term.to_lowercase()))
+        {
+            score = score.saturating_add(model.technical_term_weight);
         }
 
         // Check for multiple actions in one task
         let action_count = content.matches(" and ").count() + content.matches(", ").count();
-        score += (action_count / 2) as u8;
+        score = score.saturating_add(u8::try_from(action_count / 2).unwrap_or(u8::MAX));
 
-        score.min(10)
+        score.min(MAX_COMPLEXITY_SCORE)
     }
 
     /// Check if task has reasonable time estimate
@@ -352,6 +717,50 @@ impl Todo {
         }
     }
 
+    /// Hours of work left on the todo
+    ///
+    /// Completed and cancelled todos have no remaining effort; otherwise
+    /// `remaining_hours` takes precedence over the original estimate.
+    pub fn effort_remaining(&self) -> Option<f32> {
+        if matches!(self.status, TodoStatus::Completed | TodoStatus::Cancelled) {
+            return Some(0.0);
+        }
+        self.remaining_hours.or(self.estimated_hours)
+    }
+
+    /// Update the todo's status
+    ///
+    /// Completing a todo zeroes its remaining effort. The change is recorded
+    /// in [`Todo::history`] with the current time.
+    pub fn set_status(&mut self, status: TodoStatus) {
+        self.set_status_at(status, chrono::Utc::now());
+    }
+
+    /// Update the todo's status, recording the change at the given time
+    pub fn set_status_at(&mut self, status: TodoStatus, at: chrono::DateTime<chrono::Utc>) {
+        self.history.push(StatusChange {
+            from: self.status,
+            to: status,
+            at,
+        });
+        self.status = status;
+        if status == TodoStatus::Completed {
+            self.remaining_hours = Some(0.0);
+        }
+    }
+
+    /// Mark the todo as blocked for the given reason
+    pub fn block<S: Into<String>>(&mut self, reason: S) {
+        self.set_status(TodoStatus::Blocked);
+        self.blocked_reason = Some(reason.into());
+    }
+
+    /// Remove duplicate dependency entries, keeping the first occurrence of each
+    pub fn dedup_dependencies(&mut self) {
+        let mut seen = HashSet::new();
+        self.dependencies.retain(|dep| seen.insert(dep.clone()));
+    }
+
     /// Get progress percentage (0.0 for pending, 0.5 for `in_progress`, 1.0 for completed)
     pub const fn progress(&self) -> f32 {
         match self.status {
@@ -363,6 +772,79 @@ impl Todo {
     }
 }
 
+/// Fluent builder for [`Todo`]
+///
+/// Unset fields keep the defaults of [`Todo::new`], including a fresh UUID
+/// when no ID is given.
+#[derive(Debug, Clone)]
+pub struct TodoBuilder {
+    todo: Todo,
+}
+
+impl TodoBuilder {
+    /// Set the ID
+    #[must_use]
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.todo.id = id.into();
+        self
+    }
+
+    /// Set the status without recording a history entry
+    #[must_use]
+    pub const fn status(mut self, status: TodoStatus) -> Self {
+        self.todo.status = status;
+        self
+    }
+
+    /// Set the priority
+    #[must_use]
+    pub const fn priority(mut self, priority: TodoPriority) -> Self {
+        self.todo.priority = priority;
+        self
+    }
+
+    /// Set the estimated hours
+    #[must_use]
+    pub const fn estimated_hours(mut self, hours: f32) -> Self {
+        self.todo.estimated_hours = Some(hours);
+        self
+    }
+
+    /// Set the IDs of the todos this one depends on
+    #[must_use]
+    pub fn dependencies<I, S>(mut self, dependencies: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.todo.dependencies = dependencies.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the tags
+    #[must_use]
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.todo.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the assignee
+    #[must_use]
+    pub fn assignee<S: Into<String>>(mut self, assignee: S) -> Self {
+        self.todo.assignee = Some(assignee.into());
+        self
+    }
+
+    /// Finish building the todo
+    pub fn build(self) -> Todo {
+        self.todo
+    }
+}
+
 impl TodoList {
     /// Create a new empty todo list
     pub fn new() -> Self {
@@ -435,6 +917,7 @@ impl TodoList {
             #[cfg(feature = "todo-validation")]
             generated_at: chrono::Utc::now(),
             template_version: "1.0.0".to_string(),
+            estimate_unit: self.metadata.estimate_unit,
             custom_metadata: HashMap::new(),
         };
     }
@@ -522,6 +1005,164 @@ impl TodoList {
         }
     }
 
+    /// Order todos so that every dependency comes before its dependents
+    ///
+    /// Uses Kahn's algorithm; when several todos are ready at once the highest
+    /// priority goes first, then the lowest ID. Dependencies on unknown IDs
+    /// are ignored. Returns the cycle found by
+    /// [`TodoList::validate_dependencies`] when the graph is cyclic.
+    pub fn topological_order(&self) -> Result<Vec<&Todo>, Vec<String>> {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        self.validate_dependencies()?;
+
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        for (i, todo) in self.todos.iter().enumerate() {
+            index.entry(todo.id.as_str()).or_insert(i);
+        }
+
+        // Edges run from a dependency to the todos that depend on it
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); self.todos.len()];
+        let mut in_degree = vec![0usize; self.todos.len()];
+        for (i, todo) in self.todos.iter().enumerate() {
+            for dep in &todo.dependencies {
+                if let Some(&d) = index.get(dep.as_str()) {
+                    dependents[d].push(i);
+                    in_degree[i] += 1;
+                }
+            }
+        }
+
+        let key = |i: usize| {
+            (
+                self.todos[i].priority,
+                Reverse(stable_todo_key(&self.todos[i])),
+                i,
+            )
+        };
+        let mut ready: BinaryHeap<_> = (0..self.todos.len())
+            .filter(|&i| in_degree[i] == 0)
+            .map(key)
+            .collect();
+        let mut order = Vec::with_capacity(self.todos.len());
+        while let Some((_, _, current)) = ready.pop() {
+            order.push(&self.todos[current]);
+            for &next in &dependents[current] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(key(next));
+                }
+            }
+        }
+
+        if order.len() == self.todos.len() {
+            Ok(order)
+        } else {
+            Err((0..self.todos.len())
+                .filter(|&i| in_degree[i] > 0)
+                .map(|i| self.todos[i].id.clone())
+                .collect())
+        }
+    }
+
+    /// Split the list into batches of at most `max_per_batch` todos
+    ///
+    /// Todos are taken in [`TodoList::topological_order`], so every dependency
+    /// lands in the same or an earlier batch. Each batch keeps the project
+    /// context. A cyclic list has no such order and is split in list order.
+    /// A limit of zero is treated as one.
+    pub fn split_into_batches(&self, max_per_batch: usize) -> Vec<Self> {
+        let ordered = self
+            .topological_order()
+            .unwrap_or_else(|_| self.todos.iter().collect());
+
+        ordered
+            .chunks(max_per_batch.max(1))
+            .map(|chunk| {
+                let mut batch = Self::new();
+                batch.project.clone_from(&self.project);
+                batch.metadata.estimate_unit = self.metadata.estimate_unit;
+                for todo in chunk {
+                    batch.add_todo((*todo).clone());
+                }
+                batch.update_metadata();
+                batch
+            })
+            .collect()
+    }
+
+    /// Append the todos of another list, e.g. one generated per subsystem
+    ///
+    /// With a `prefix`, every ID from `other` is prefixed (`"api_"` turns
+    /// `todo_0` into `api_todo_0`) and dependencies between `other`'s todos
+    /// are rewritten to match; dependencies on IDs `other` does not contain
+    /// are kept as they are. Fails without changing this list if the merged
+    /// IDs would not be unique. Metadata is recomputed afterwards.
+    pub fn merge(&mut self, other: Self, prefix: Option<&str>) -> crate::Result<()> {
+        let incoming: HashSet<String> = other.todos.iter().map(|t| t.id.clone()).collect();
+        let rename = |id: &str| match prefix {
+            Some(prefix) if incoming.contains(id) => format!("{}{}", prefix, id),
+            _ => id.to_string(),
+        };
+
+        let mut seen: HashSet<String> = self.todos.iter().map(|t| t.id.clone()).collect();
+        let mut merged = Vec::with_capacity(other.todos.len());
+        for mut todo in other.todos {
+            todo.id = rename(&todo.id);
+            if !seen.insert(todo.id.clone()) {
+                return Err(crate::error::ValidationError::invalid_value(
+                    "id".to_string(),
+                    format!("duplicate todo ID '{}' when merging lists", todo.id),
+                )
+                .into());
+            }
+            todo.dependencies = todo.dependencies.iter().map(|dep| rename(dep)).collect();
+            merged.push(todo);
+        }
+
+        self.todos.extend(merged);
+        self.update_metadata();
+        Ok(())
+    }
+
+    /// Extract the roots and everything they transitively depend on
+    ///
+    /// Todos keep their list order and the project context. Unknown root IDs
+    /// are ignored, and dependencies on IDs outside the list are dropped so
+    /// the result has no dangling references.
+    #[must_use]
+    pub fn subgraph_from(&self, roots: &[String]) -> Self {
+        let by_id: HashMap<&str, &Todo> = self.todos.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        let mut included: HashSet<&str> = HashSet::new();
+        let mut stack: Vec<&str> = roots.iter().map(String::as_str).collect();
+        while let Some(id) = stack.pop() {
+            let Some(todo) = by_id.get(id) else {
+                continue;
+            };
+            if included.insert(todo.id.as_str()) {
+                stack.extend(todo.dependencies.iter().map(String::as_str));
+            }
+        }
+
+        let mut subgraph = Self::new();
+        subgraph.project.clone_from(&self.project);
+        subgraph.metadata.estimate_unit = self.metadata.estimate_unit;
+        for todo in self
+            .todos
+            .iter()
+            .filter(|t| included.contains(t.id.as_str()))
+        {
+            let mut todo = todo.clone();
+            todo.dependencies
+                .retain(|dep| included.contains(dep.as_str()));
+            subgraph.add_todo(todo);
+        }
+        subgraph.update_metadata();
+        subgraph
+    }
+
     /// Get todos by status
     pub fn todos_by_status(&self, status: TodoStatus) -> Vec<&Todo> {
         self.todos.iter().filter(|t| t.status == status).collect()
@@ -535,95 +1176,828 @@ impl TodoList {
             .collect()
     }
 
-    /// Get critical path (longest dependency chain)
-    pub fn critical_path(&self) -> Vec<String> {
-        // Implementation would calculate the longest path through the dependency graph
-        // For now, return empty path
-        Vec::new()
-    }
-}
+    /// Get pending todos whose dependencies are all completed
+    ///
+    /// Blocked todos are never ready, regardless of their dependencies.
+    pub fn ready_todos(&self) -> Vec<&Todo> {
+        let completed: HashSet<&str> = self
+            .todos
+            .iter()
+            .filter(|t| t.status == TodoStatus::Completed)
+            .map(|t| t.id.as_str())
+            .collect();
 
-impl Default for TodoList {
-    fn default() -> Self {
-        Self::new()
+        self.todos
+            .iter()
+            .filter(|t| t.status == TodoStatus::Pending)
+            .filter(|t| {
+                t.dependencies
+                    .iter()
+                    .all(|dep| completed.contains(dep.as_str()))
+            })
+            .collect()
     }
-}
 
-impl Default for TodoQualityGates {
-    fn default() -> Self {
-        Self {
-            complexity_check: false,
-            completeness_check: false,
-            actionability_check: false,
-            time_estimate_check: false,
-            custom_checks: HashMap::new(),
+    /// Explain why a todo is not ready
+    ///
+    /// Returns the IDs of all incomplete dependencies reachable from the given
+    /// todo, nearest first. Completed dependencies are not traversed, and
+    /// dependencies missing from the list are reported since they can never
+    /// complete. Returns an empty vec if the todo has nothing blocking it.
+    pub fn blocking_path(&self, id: &str) -> Vec<String> {
+        use std::collections::{HashSet, VecDeque};
+
+        let by_id: HashMap<&str, &Todo> = self.todos.iter().map(|t| (t.id.as_str(), t)).collect();
+
+        let mut path = Vec::new();
+        let mut visited: HashSet<&str> = HashSet::from([id]);
+        let mut queue: VecDeque<&str> = VecDeque::from([id]);
+
+        while let Some(current) = queue.pop_front() {
+            let Some(todo) = by_id.get(current) else {
+                continue;
+            };
+            for dep in &todo.dependencies {
+                if !visited.insert(dep.as_str()) {
+                    continue;
+                }
+                let completed = by_id
+                    .get(dep.as_str())
+                    .is_some_and(|t| t.status == TodoStatus::Completed);
+                if !completed {
+                    path.push(dep.clone());
+                    queue.push_back(dep.as_str());
+                }
+            }
         }
+
+        path
     }
-}
 
-impl Default for TodoListMetadata {
-    fn default() -> Self {
-        Self {
-            total_count: 0,
-            status_counts: HashMap::new(),
-            priority_counts: HashMap::new(),
-            total_estimated_hours: 0.0,
-            avg_estimated_hours: 0.0,
-            completion_percentage: 0.0,
-            dependency_graph_valid: true,
-            #[cfg(feature = "todo-validation")]
-            generated_at: chrono::Utc::now(),
-            template_version: "1.0.0".to_string(),
-            custom_metadata: HashMap::new(),
+    /// Group todos into phases that can each run fully in parallel
+    ///
+    /// Phase `n` holds the todos whose dependencies all sit in earlier
+    /// phases; IDs within a phase are sorted. Dependencies on unknown IDs are
+    /// ignored. Fails with [`TodoValidationError::CircularDependency`] when the
+    /// graph has a cycle.
+    ///
+    /// [`TodoValidationError::CircularDependency`]: crate::error::TodoValidationError::CircularDependency
+    pub fn parallel_groups(&self) -> crate::Result<Vec<Vec<String>>> {
+        let cycle_error = |cycle: Vec<String>| {
+            crate::error::ValidationError::from(
+                crate::error::TodoValidationError::CircularDependency { cycle },
+            )
+        };
+        self.validate_dependencies().map_err(cycle_error)?;
+
+        let ids: HashSet<&str> = self.todos.iter().map(|t| t.id.as_str()).collect();
+        let mut remaining: Vec<&Todo> = self.todos.iter().collect();
+        let mut placed: HashSet<&str> = HashSet::new();
+        let mut groups = Vec::new();
+
+        while !remaining.is_empty() {
+            let (mut ready, blocked): (Vec<&Todo>, Vec<&Todo>) =
+                remaining.into_iter().partition(|todo| {
+                    todo.dependencies
+                        .iter()
+                        .all(|dep| !ids.contains(dep.as_str()) || placed.contains(dep.as_str()))
+                });
+
+            if ready.is_empty() {
+                return Err(cycle_error(blocked.iter().map(|t| t.id.clone()).collect()).into());
+            }
+
+            ready.sort_by(|a, b| stable_todo_key(a).cmp(&stable_todo_key(b)));
+            let mut group: Vec<String> = ready.iter().map(|t| t.id.clone()).collect();
+            group.dedup();
+            placed.extend(ready.iter().map(|t| t.id.as_str()));
+            groups.push(group);
+            remaining = blocked;
         }
+
+        Ok(groups)
     }
-}
 
-impl Default for TodoQualityConfig {
-    fn default() -> Self {
-        Self {
-            max_todos_per_batch: Some(50),
-            min_task_detail_chars: Some(10),
-            max_task_detail_chars: Some(100),
-            max_complexity_per_task: Some(8),
-            require_time_estimates: true,
-            require_specific_actions: true,
-            require_dependency_graph: true,
-            prevent_circular_dependencies: true,
-            min_estimated_hours: Some(0.5),
-            max_estimated_hours: Some(40.0),
-        }
+    /// Minimum number of sequential phases needed with unlimited parallelism
+    ///
+    /// This is the number of [`TodoList::parallel_groups`]; empty lists need
+    /// zero phases.
+    pub fn phase_count(&self) -> crate::Result<usize> {
+        self.parallel_groups().map(|groups| groups.len())
     }
-}
 
-impl Default for TodoInput {
-    fn default() -> Self {
-        Self {
-            project_name: "Sample Project".to_string(),
-            requirements: vec!["Create basic functionality".to_string()],
-            granularity: TodoGranularity::Medium,
-            project_context: None,
-            quality_config: None,
-            max_todos: Some(20),
-            include_estimates: true,
-            default_priority: Some(TodoPriority::Medium),
-        }
+    /// Compare two lists ignoring IDs, timestamps and other volatile fields
+    ///
+    /// Todos are compared by content, status, priority, estimate, tags and
+    /// dependencies, regardless of order. Dependencies are resolved to the
+    /// content of the todo they point at, so regenerated lists with fresh IDs
+    /// still compare equal.
+    pub fn logically_eq(&self, other: &Self) -> bool {
+        self.todos.len() == other.todos.len() && self.canonical_todos() == other.canonical_todos()
     }
-}
 
-impl std::fmt::Display for TodoStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TodoStatus::Pending => write!(f, "pending"),
-            TodoStatus::InProgress => write!(f, "in_progress"),
-            TodoStatus::Completed => write!(f, "completed"),
-            TodoStatus::Blocked => write!(f, "blocked"),
-            TodoStatus::Cancelled => write!(f, "cancelled"),
-        }
+    /// Order-independent, ID-free representation used by [`TodoList::logically_eq`]
+    fn canonical_todos(&self) -> Vec<CanonicalTodo<'_>> {
+        let content_by_id: HashMap<&str, &str> = self
+            .todos
+            .iter()
+            .map(|t| (t.id.as_str(), t.content.as_str()))
+            .collect();
+
+        let mut canonical: Vec<CanonicalTodo<'_>> = self
+            .todos
+            .iter()
+            .map(|todo| {
+                let mut dependencies: Vec<&str> = todo
+                    .dependencies
+                    .iter()
+                    .map(|dep| content_by_id.get(dep.as_str()).copied().unwrap_or(dep))
+                    .collect();
+                dependencies.sort_unstable();
+
+                let mut tags: Vec<&str> = todo.tags.iter().map(String::as_str).collect();
+                tags.sort_unstable();
+
+                CanonicalTodo {
+                    content: &todo.content,
+                    status: todo.status as u8,
+                    priority: todo.priority,
+                    estimated_hours: todo.estimated_hours.map(f32::to_bits),
+                    dependencies,
+                    tags,
+                }
+            })
+            .collect();
+        canonical.sort();
+        canonical
     }
-}
 
-impl std::fmt::Display for TodoPriority {
+    /// Serialize to YAML in a canonical, diff-friendly form
+    ///
+    /// Todos are ordered by ID, mapping keys are sorted at every level and
+    /// the model's own floats are written in their shortest `f32` form, so
+    /// equal lists always produce byte-identical output regardless of
+    /// insertion order. Numbers in custom fields and metadata are kept as-is.
+    pub fn to_canonical_yaml(&self) -> crate::Result<String> {
+        let mut todos: Vec<&Todo> = self.todos.iter().collect();
+        todos.sort_by(|a, b| stable_todo_key(a).cmp(&stable_todo_key(b)));
+
+        let mut value = serde_json::to_value(self)?;
+        value["todos"] = serde_json::to_value(todos)?;
+        Ok(serde_yaml::to_string(&canonical_value(value, true))?)
+    }
+
+    /// Dependency graph as an adjacency matrix
+    ///
+    /// Returns the todo IDs sorted ascending and a matrix where `m[i][j]` is
+    /// true when todo `i` depends on todo `j`. Dependencies on unknown IDs are
+    /// omitted.
+    pub fn adjacency_matrix(&self) -> (Vec<String>, Vec<Vec<bool>>) {
+        let mut todos: Vec<&Todo> = self.todos.iter().collect();
+        todos.sort_by(|a, b| stable_todo_key(a).cmp(&stable_todo_key(b)));
+        let mut ids: Vec<String> = todos.iter().map(|t| t.id.clone()).collect();
+        ids.dedup();
+
+        let index: HashMap<&str, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.as_str(), i))
+            .collect();
+
+        let mut matrix = vec![vec![false; ids.len()]; ids.len()];
+        for todo in &self.todos {
+            let i = index[todo.id.as_str()];
+            for dep in &todo.dependencies {
+                if let Some(&j) = index.get(dep.as_str()) {
+                    matrix[i][j] = true;
+                }
+            }
+        }
+
+        (ids, matrix)
+    }
+
+    /// Total hours of work left across estimated todos, for burndown tracking
+    pub fn remaining_hours(&self) -> f32 {
+        self.todos
+            .iter()
+            .filter(|t| t.estimated_hours.is_some() || t.remaining_hours.is_some())
+            .filter_map(Todo::effort_remaining)
+            .sum()
+    }
+
+    /// Total estimated hours inflated by estimate uncertainty
+    ///
+    /// Each estimate is divided by its confidence (clamped to
+    /// [`MIN_ESTIMATE_CONFIDENCE`]..=1.0); estimates without a confidence, or
+    /// with a NaN one, count as fully confident. Non-finite estimates are
+    /// skipped, so the total is always finite.
+    pub fn risk_adjusted_hours(&self) -> f32 {
+        self.todos
+            .iter()
+            .filter_map(|t| {
+                let hours = t.estimated_hours.filter(|hours| hours.is_finite())?;
+                let confidence = t
+                    .estimate_confidence
+                    .filter(|confidence| !confidence.is_nan())
+                    .unwrap_or(1.0)
+                    .clamp(MIN_ESTIMATE_CONFIDENCE, 1.0);
+                Some(hours / confidence)
+            })
+            .sum()
+    }
+
+    /// Raise prerequisite priorities to match their highest-priority dependent
+    ///
+    /// Propagates through the whole dependency chain, so every transitive
+    /// prerequisite of a critical todo becomes critical. Returns the number of
+    /// todos whose priority changed.
+    pub fn resolve_priority_inversions(&mut self) -> usize {
+        let index: HashMap<String, usize> = self
+            .todos
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.id.clone(), i))
+            .collect();
+        let original: Vec<TodoPriority> = self.todos.iter().map(|t| t.priority).collect();
+
+        // Priorities only increase, so this reaches a fixed point even with cycles
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 0..self.todos.len() {
+                let priority = self.todos[i].priority;
+                for dep in self.todos[i].dependencies.clone() {
+                    if let Some(&d) = index.get(&dep) {
+                        if self.todos[d].priority < priority {
+                            self.todos[d].priority = priority;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        let bumped = self
+            .todos
+            .iter()
+            .zip(&original)
+            .filter(|(todo, before)| todo.priority != **before)
+            .count();
+        if bumped > 0 {
+            self.update_metadata_internal(false);
+        }
+        bumped
+    }
+
+    /// Round every time estimate to the nearest multiple of `granule`
+    ///
+    /// Non-zero estimates never round below one granule. Does nothing if
+    /// `granule` is not positive.
+    pub fn round_estimates(&mut self, granule: f32) {
+        if granule <= 0.0 || !granule.is_finite() {
+            return;
+        }
+
+        for hours in self
+            .todos
+            .iter_mut()
+            .filter_map(|t| t.estimated_hours.as_mut())
+        {
+            let rounded = (*hours / granule).round() * granule;
+            *hours = if *hours > 0.0 {
+                rounded.max(granule)
+            } else {
+                rounded
+            };
+        }
+        self.update_metadata_internal(false);
+    }
+
+    /// Tag todos with the project's tech stack entries they mention
+    ///
+    /// Each `tech_stack` entry found as a whole word in a todo's content,
+    /// ignoring case, adds the entry as a lowercase tag with spaces replaced
+    /// by `-` (e.g. `Rust` becomes `rust`). Existing tags are kept and never
+    /// duplicated. Does nothing without a project context.
+    pub fn auto_tag_from_context(&mut self) {
+        let Some(project) = &self.project else {
+            return;
+        };
+        let terms: Vec<(String, String)> = project
+            .tech_stack
+            .iter()
+            .map(|term| term.trim().to_lowercase())
+            .filter(|term| !term.is_empty())
+            .map(|term| {
+                let tag = term.split_whitespace().collect::<Vec<_>>().join("-");
+                (term, tag)
+            })
+            .collect();
+
+        for todo in &mut self.todos {
+            let content = todo.content.to_lowercase();
+            for (term, tag) in &terms {
+                if mentions_term(&content, term) && !todo.tags.contains(tag) {
+                    todo.tags.push(tag.clone());
+                }
+            }
+        }
+    }
+
+    /// Check a todo against a definition-of-ready policy
+    ///
+    /// Returns the readiness requirements the todo fails, or a single error if
+    /// no todo has the given ID.
+    pub fn ready_for_work(&self, id: &str, policy: &ReadinessPolicy) -> Result<(), Vec<String>> {
+        let Some(todo) = self.todos.iter().find(|t| t.id == id) else {
+            return Err(vec![format!("Todo '{}' not found", id)]);
+        };
+
+        let mut failures = Vec::new();
+
+        if policy.require_estimate && todo.estimated_hours.is_none() {
+            failures.push("Missing time estimate".to_string());
+        }
+
+        if policy.require_assignee
+            && todo
+                .assignee
+                .as_deref()
+                .is_none_or(|assignee| assignee.trim().is_empty())
+        {
+            failures.push("No assignee".to_string());
+        }
+
+        if policy.require_acceptance_criteria && todo.acceptance_criteria.is_empty() {
+            failures.push("No acceptance criteria".to_string());
+        }
+
+        if policy.require_no_open_questions && !todo.open_questions.is_empty() {
+            failures.push(format!(
+                "{} unresolved question(s): {}",
+                todo.open_questions.len(),
+                todo.open_questions.join("; ")
+            ));
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    /// Get critical path (longest dependency chain)
+    ///
+    /// Returns todo IDs from the first prerequisite to the final dependent,
+    /// weighting each todo by its estimated hours (1.0 when absent). Ties go
+    /// to the lowest todo ID; a cyclic graph yields an empty path.
+    pub fn critical_path(&self) -> Vec<String> {
+        let todos: Vec<&Todo> = self.todos.iter().collect();
+        longest_weighted_chain(&todos)
+    }
+
+    /// Shortest possible duration with unlimited parallelism
+    ///
+    /// Same as [`TodoList::estimated_wall_clock_hours`], but a cyclic graph
+    /// yields 0 instead of an error.
+    pub fn min_duration_hours(&self) -> f32 {
+        self.estimated_wall_clock_hours().unwrap_or(0.0)
+    }
+
+    /// Duration when every todo is done one after another
+    ///
+    /// Sums all estimated hours; compare with
+    /// [`TodoList::min_duration_hours`] to see what parallel work can save.
+    pub fn sequential_duration_hours(&self) -> f32 {
+        self.todos.iter().filter_map(|t| t.estimated_hours).sum()
+    }
+
+    /// Realistic duration when independent todos run in parallel
+    ///
+    /// Sums the estimates along the longest estimated dependency chain,
+    /// counting unestimated todos as zero hours. Unlike
+    /// [`TodoListMetadata::total_estimated_hours`] this reflects the schedule
+    /// rather than the total effort. Fails with
+    /// [`TodoValidationError::CircularDependency`] when the graph has a cycle.
+    ///
+    /// [`TodoValidationError::CircularDependency`]: crate::error::TodoValidationError::CircularDependency
+    pub fn estimated_wall_clock_hours(&self) -> crate::Result<f32> {
+        self.validate_dependencies().map_err(|cycle| {
+            crate::error::ValidationError::from(
+                crate::error::TodoValidationError::CircularDependency { cycle },
+            )
+        })?;
+
+        let todos: Vec<&Todo> = self.todos.iter().collect();
+        let path: HashSet<String> =
+            longest_chain_by(&todos, |todo| todo.estimated_hours.unwrap_or(0.0))
+                .into_iter()
+                .collect();
+        Ok(self
+            .todos
+            .iter()
+            .filter(|t| path.contains(&t.id))
+            .filter_map(|t| t.estimated_hours)
+            .sum())
+    }
+
+    /// Suggest dependencies between todos that stage the same piece of work
+    ///
+    /// Todos whose content starts with a stage verb (design → implement →
+    /// test → deploy, with common synonyms) and whose remaining words overlap
+    /// by at least half are treated as stages of the same work. Each such todo
+    /// is paired with the todos at the nearest earlier stage. Returns
+    /// `(dependent, dependency)` ID pairs in list order, skipping pairs that
+    /// are already linked in either direction. This is a heuristic; review the
+    /// suggestions before applying them.
+    pub fn suggest_dependencies(&self) -> Vec<(String, String)> {
+        let staged: Vec<(&Todo, usize, HashSet<String>)> = self
+            .todos
+            .iter()
+            .filter_map(|todo| {
+                work_stage(&todo.content).map(|(stage, subject)| (todo, stage, subject))
+            })
+            .collect();
+
+        let mut suggestions = Vec::new();
+        for (dependent, stage, subject) in &staged {
+            let candidates: Vec<(&Todo, usize)> = staged
+                .iter()
+                .filter(|(_, other_stage, other_subject)| {
+                    other_stage < stage
+                        && jaccard(subject, other_subject) >= SUBJECT_SIMILARITY_THRESHOLD
+                })
+                .map(|(todo, other_stage, _)| (*todo, *other_stage))
+                .collect();
+            let Some(nearest) = candidates.iter().map(|(_, s)| *s).max() else {
+                continue;
+            };
+
+            for (dependency, _) in candidates.iter().filter(|(_, s)| *s == nearest) {
+                if dependent.dependencies.contains(&dependency.id)
+                    || dependency.dependencies.contains(&dependent.id)
+                {
+                    continue;
+                }
+                suggestions.push((dependent.id.clone(), dependency.id.clone()));
+            }
+        }
+        suggestions
+    }
+}
+
+/// Share of words two sets have in common
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    count_as_f32(a.intersection(b).count()) / count_as_f32(union)
+}
+
+/// Tie-break key shared by every API that orders todos
+///
+/// When an ordering has no other reason to put one todo first (equal
+/// priority in [`TodoList::topological_order`], equal weight in
+/// [`TodoList::critical_path`], the same phase in
+/// [`TodoList::parallel_groups`], or no order at all in
+/// [`TodoList::adjacency_matrix`] and [`TodoList::to_canonical_yaml`]), the
+/// todo with the smaller key comes first. The key is the todo ID, so results
+/// never depend on insertion order.
+pub fn stable_todo_key(todo: &Todo) -> impl Ord + '_ {
+    todo.id.as_str()
+}
+
+/// Whether `term` appears in `text` without letters or digits on either side
+fn mentions_term(text: &str, term: &str) -> bool {
+    text.match_indices(term).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + term.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Compute the longest dependency chain through a set of todos.
+///
+/// Each todo is weighted by its `estimated_hours` (unit weight when absent).
+/// Dependencies on IDs outside the set are ignored. Returns the chain ordered
+/// from the first prerequisite to the final dependent, or an empty vec when
+/// the graph contains a cycle. Ties are broken by lowest todo ID.
+pub(crate) fn longest_weighted_chain(todos: &[&Todo]) -> Vec<String> {
+    longest_chain_by(todos, |todo| todo.estimated_hours.unwrap_or(1.0))
+}
+
+/// Compute the longest dependency chain using a custom per-todo weight.
+///
+/// See [`longest_weighted_chain`] for ordering, cycle and tie-breaking rules.
+pub(crate) fn longest_chain_by<F>(todos: &[&Todo], weight: F) -> Vec<String>
+where
+    F: Fn(&Todo) -> f32,
+{
+    use std::collections::VecDeque;
+
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (i, todo) in todos.iter().enumerate() {
+        index.entry(todo.id.as_str()).or_insert(i);
+    }
+
+    // Edges run from a dependency to the todos that depend on it
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); todos.len()];
+    let mut in_degree = vec![0usize; todos.len()];
+    for (i, todo) in todos.iter().enumerate() {
+        for dep in &todo.dependencies {
+            if let Some(&d) = index.get(dep.as_str()) {
+                dependents[d].push(i);
+                in_degree[i] += 1;
+            }
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..todos.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut order = Vec::with_capacity(todos.len());
+    while let Some(current) = queue.pop_front() {
+        order.push(current);
+        for &next in &dependents[current] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() != todos.len() {
+        return Vec::new();
+    }
+
+    // Longest weighted path ending at each node, with the predecessor that achieves it
+    let mut best = vec![0.0f32; todos.len()];
+    let mut previous: Vec<Option<usize>> = vec![None; todos.len()];
+    let outranks = |best: &[f32], a: usize, b: usize| match best[a].total_cmp(&best[b]) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => stable_todo_key(todos[a]) < stable_todo_key(todos[b]),
+        std::cmp::Ordering::Less => false,
+    };
+    for &i in &order {
+        let mut chosen: Option<usize> = None;
+        for dep in &todos[i].dependencies {
+            if let Some(&d) = index.get(dep.as_str()) {
+                if chosen.is_none_or(|c| outranks(&best, d, c)) {
+                    chosen = Some(d);
+                }
+            }
+        }
+        best[i] = weight(todos[i]) + chosen.map_or(0.0, |c| best[c]);
+        previous[i] = chosen;
+    }
+
+    let end = (0..todos.len()).reduce(|a, b| if outranks(&best, b, a) { b } else { a });
+
+    let mut path = Vec::new();
+    let mut current = end;
+    while let Some(i) = current {
+        path.push(todos[i].id.clone());
+        current = previous[i];
+    }
+    path.reverse();
+    path
+}
+
+/// Fields holding caller-supplied JSON, whose numbers are kept as written
+const USER_DATA_FIELDS: [&str; 2] = ["custom_fields", "custom_metadata"];
+
+/// Recursively sort object keys and normalize the model's floats for canonical output
+///
+/// Numbers inside [`USER_DATA_FIELDS`] are left as-is; only values that came
+/// from the model's own `f32` fields are rounded to their shortest form.
+fn canonical_value(value: serde_json::Value, normalize_floats: bool) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let normalize =
+                            normalize_floats && !USER_DATA_FIELDS.contains(&key.as_str());
+                        (key, canonical_value(value, normalize))
+                    })
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(
+            items
+                .into_iter()
+                .map(|item| canonical_value(item, normalize_floats))
+                .collect(),
+        ),
+        Value::Number(number) if normalize_floats && !number.is_i64() && !number.is_u64() => {
+            // Models store f32; widening to f64 adds noise digits (0.1 -> 0.10000000149)
+            #[allow(clippy::cast_possible_truncation)]
+            let shortest = number
+                .as_f64()
+                .and_then(|n| (n as f32).to_string().parse::<f64>().ok())
+                .and_then(serde_json::Number::from_f64);
+            shortest.map_or(Value::Number(number), Value::Number)
+        }
+        other => other,
+    }
+}
+
+impl Default for TodoList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for TodoQualityGates {
+    fn default() -> Self {
+        Self {
+            complexity_check: false,
+            completeness_check: false,
+            actionability_check: false,
+            time_estimate_check: false,
+            custom_checks: HashMap::new(),
+        }
+    }
+}
+
+impl Default for TodoListMetadata {
+    fn default() -> Self {
+        Self {
+            total_count: 0,
+            status_counts: HashMap::new(),
+            priority_counts: HashMap::new(),
+            total_estimated_hours: 0.0,
+            avg_estimated_hours: 0.0,
+            completion_percentage: 0.0,
+            dependency_graph_valid: true,
+            #[cfg(feature = "todo-validation")]
+            generated_at: chrono::Utc::now(),
+            template_version: "1.0.0".to_string(),
+            estimate_unit: EstimateUnit::default(),
+            custom_metadata: HashMap::new(),
+        }
+    }
+}
+
+impl Default for TodoQualityConfig {
+    fn default() -> Self {
+        Self {
+            max_todos_per_batch: Some(50),
+            min_task_detail_chars: Some(10),
+            max_task_detail_chars: Some(100),
+            max_complexity_per_task: Some(8),
+            require_time_estimates: true,
+            require_specific_actions: true,
+            require_dependency_graph: true,
+            prevent_circular_dependencies: true,
+            min_estimated_hours: Some(0.5),
+            max_estimated_hours: Some(40.0),
+            max_priority_depth: None,
+            estimate_granule: None,
+            max_flat_list_size: None,
+            min_meaningful_tokens: None,
+            max_critical_path_length: None,
+            min_useful_estimate_hours: None,
+            max_tiny_todo_fraction: None,
+            max_custom_field_depth: None,
+            max_custom_fields_bytes: None,
+        }
+    }
+}
+
+impl Default for ComplexityModel {
+    fn default() -> Self {
+        let words = |list: &[&str]| list.iter().map(|w| (*w).to_string()).collect();
+        Self {
+            keywords: words(&[
+                "integrate",
+                "refactor",
+                "optimize",
+                "migrate",
+                "analyze",
+                "algorithm",
+                "performance",
+                "security",
+                "architecture",
+            ]),
+            keyword_weight: 1,
+            technical_terms: words(&["database", "api", "system"]),
+            technical_term_weight: 1,
+        }
+    }
+}
+
+impl Default for ReadinessPolicy {
+    fn default() -> Self {
+        Self {
+            require_estimate: true,
+            require_assignee: true,
+            require_acceptance_criteria: true,
+            require_no_open_questions: true,
+        }
+    }
+}
+
+impl Default for TodoInput {
+    fn default() -> Self {
+        Self {
+            project_name: "Sample Project".to_string(),
+            requirements: vec!["Create basic functionality".to_string()],
+            granularity: TodoGranularity::Medium,
+            project_context: None,
+            quality_config: None,
+            max_todos: Some(20),
+            max_requirements: Some(DEFAULT_MAX_REQUIREMENTS),
+            include_estimates: true,
+            default_priority: Some(TodoPriority::Medium),
+            id_scheme: TodoIdScheme::default(),
+        }
+    }
+}
+
+impl std::fmt::Display for TodoStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodoStatus::Pending => write!(f, "pending"),
+            TodoStatus::InProgress => write!(f, "in_progress"),
+            TodoStatus::Completed => write!(f, "completed"),
+            TodoStatus::Blocked => write!(f, "blocked"),
+            TodoStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// Aliases used when parsing priorities without an explicit alias map
+static DEFAULT_PRIORITY_ALIASES: LazyLock<HashMap<String, TodoPriority>> =
+    LazyLock::new(TodoPriority::default_aliases);
+
+impl TodoPriority {
+    /// Common priority names used by other trackers
+    ///
+    /// `urgent`, `blocker` and `p0` map to `Critical`; `major` and `p1` to
+    /// `High`; `normal` and `p2` to `Medium`; `minor`, `trivial` and `p3` to
+    /// `Low`.
+    pub fn default_aliases() -> HashMap<String, Self> {
+        [
+            ("urgent", Self::Critical),
+            ("blocker", Self::Critical),
+            ("p0", Self::Critical),
+            ("major", Self::High),
+            ("p1", Self::High),
+            ("normal", Self::Medium),
+            ("p2", Self::Medium),
+            ("minor", Self::Low),
+            ("trivial", Self::Low),
+            ("p3", Self::Low),
+        ]
+        .into_iter()
+        .map(|(alias, priority)| (alias.to_string(), priority))
+        .collect()
+    }
+
+    /// Parse a priority name or one of the given aliases
+    ///
+    /// Canonical names (`low`, `medium`, `high`, `critical`) are always
+    /// accepted. Matching ignores case and surrounding whitespace; alias
+    /// keys are expected in lowercase.
+    pub fn from_alias(value: &str, aliases: &HashMap<String, Self>) -> crate::Result<Self> {
+        let key = value.trim().to_lowercase();
+        match key.as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "critical" => Ok(Self::Critical),
+            _ => aliases
+                .get(&key)
+                .copied()
+                .ok_or_else(|| crate::Error::invalid_input(format!("Unknown priority: {}", value))),
+        }
+    }
+}
+
+impl std::str::FromStr for TodoPriority {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_alias(s, &DEFAULT_PRIORITY_ALIASES)
+    }
+}
+
+impl TryFrom<String> for TodoPriority {
+    type Error = crate::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::fmt::Display for TodoPriority {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TodoPriority::Low => write!(f, "low"),
@@ -634,6 +2008,14 @@ impl std::fmt::Display for TodoPriority {
     }
 }
 
+/// Test fixture: an "Implement <id>" todo with the given ID and dependencies
+#[cfg(test)]
+pub(crate) fn test_todo(id: &str, dependencies: &[&str]) -> TodoBuilder {
+    Todo::builder(format!("Implement {}", id))
+        .id(id)
+        .dependencies(dependencies.iter().copied())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -647,6 +2029,555 @@ mod tests {
         assert_eq!(todo.priority, TodoPriority::Medium);
     }
 
+    #[test]
+    fn test_new_deterministic_ids() {
+        let build = || {
+            let mut list = TodoList::new();
+            for (idx, content) in ["Implement login", "Implement login", "Write docs"]
+                .iter()
+                .enumerate()
+            {
+                list.add_todo(Todo::new_deterministic(*content, 7 + idx as u64));
+            }
+            list
+        };
+
+        let ids = |list: &TodoList| list.todos.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        let first = build();
+        assert_eq!(ids(&first), ids(&build()));
+        assert_ne!(first.todos[0].id, first.todos[1].id);
+        assert_ne!(
+            Todo::new_deterministic("Write docs", 1).id,
+            Todo::new_deterministic("Write docs", 2).id
+        );
+    }
+
+    #[test]
+    fn test_priority_aliases() {
+        let mut todo = serde_json::to_value(Todo::new("Fix checkout crash")).unwrap();
+        todo["priority"] = serde_json::json!("urgent");
+        let todo: Todo = serde_json::from_value(todo).unwrap();
+        assert_eq!(todo.priority, TodoPriority::Critical);
+
+        assert_eq!(
+            "P0".parse::<TodoPriority>().unwrap(),
+            TodoPriority::Critical
+        );
+        assert_eq!(
+            " Normal ".parse::<TodoPriority>().unwrap(),
+            TodoPriority::Medium
+        );
+        assert_eq!("high".parse::<TodoPriority>().unwrap(), TodoPriority::High);
+
+        let err = serde_yaml::from_str::<TodoPriority>("someday").unwrap_err();
+        assert!(err.to_string().contains("someday"));
+
+        let aliases = HashMap::from([("sev1".to_string(), TodoPriority::Critical)]);
+        assert_eq!(
+            TodoPriority::from_alias("SEV1", &aliases).unwrap(),
+            TodoPriority::Critical
+        );
+        assert!(TodoPriority::from_alias("urgent", &aliases).is_err());
+    }
+
+    #[test]
+    fn test_builder_defaults_match_new() {
+        let built = Todo::builder("Implement user authentication").build();
+        let plain = Todo::new("Implement user authentication");
+
+        assert!(uuid::Uuid::parse_str(&built.id).is_ok());
+        assert_ne!(built.id, plain.id);
+        assert_eq!(built.content, plain.content);
+        assert_eq!(built.status, plain.status);
+        assert_eq!(built.priority, plain.priority);
+        assert_eq!(built.estimated_hours, plain.estimated_hours);
+        assert_eq!(built.dependencies, plain.dependencies);
+        assert_eq!(built.tags, plain.tags);
+        assert_eq!(built.assignee, plain.assignee);
+        assert!(built.custom_fields.is_empty());
+    }
+
+    #[test]
+    fn test_builder_setters() {
+        let todo = Todo::builder("Implement login endpoint")
+            .id("login")
+            .status(TodoStatus::InProgress)
+            .priority(TodoPriority::High)
+            .estimated_hours(4.0)
+            .dependencies(["schema"])
+            .tags(vec!["backend".to_string()])
+            .assignee("sam")
+            .build();
+
+        assert_eq!(todo.id, "login");
+        assert_eq!(todo.status, TodoStatus::InProgress);
+        assert_eq!(todo.priority, TodoPriority::High);
+        assert_eq!(todo.estimated_hours, Some(4.0));
+        assert_eq!(todo.dependencies, vec!["schema"]);
+        assert_eq!(todo.tags, vec!["backend"]);
+        assert_eq!(todo.assignee.as_deref(), Some("sam"));
+        assert!(todo.history.is_empty());
+    }
+
+    #[test]
+    fn test_status_history() {
+        let mut todo = Todo::new("Implement audit logging");
+        assert!(!serde_json::to_string(&todo).unwrap().contains("history"));
+
+        todo.set_status(TodoStatus::InProgress);
+        todo.set_status(TodoStatus::Completed);
+
+        assert_eq!(todo.history.len(), 2);
+        assert_eq!(todo.history[0].from, TodoStatus::Pending);
+        assert_eq!(todo.history[0].to, TodoStatus::InProgress);
+        assert_eq!(todo.history[1].from, TodoStatus::InProgress);
+        assert_eq!(todo.history[1].to, TodoStatus::Completed);
+        assert!(todo.history[0].at <= todo.history[1].at);
+
+        let json = serde_json::to_string(&todo).unwrap();
+        let restored: Todo = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.history, todo.history);
+
+        let mut blocked = Todo::new("Integrate payment provider");
+        blocked.block("Waiting on sandbox credentials");
+        assert_eq!(blocked.history.len(), 1);
+        assert_eq!(blocked.history[0].from, TodoStatus::Pending);
+        assert_eq!(blocked.history[0].to, TodoStatus::Blocked);
+    }
+
+    #[test]
+    fn test_logically_eq_ignores_volatile_fields() {
+        let build = || {
+            let mut list = TodoList::new();
+            let schema = Todo::new("Design database schema");
+            let mut api = Todo::new("Implement API endpoints");
+            api.dependencies = vec![schema.id.clone()];
+            api.tags = vec!["backend".to_string(), "api".to_string()];
+            api.estimated_hours = Some(6.0);
+            list.add_todo(api);
+            list.add_todo(schema);
+            list
+        };
+
+        let first = build();
+        let mut second = build();
+        second.todos.reverse();
+        second.todos[0].created_at += chrono::Duration::hours(1);
+        second.todos[1].tags.reverse();
+        second.update_metadata();
+
+        assert_ne!(first.todos[0].id, second.todos[1].id);
+        assert!(first.logically_eq(&second));
+
+        second.todos[1].estimated_hours = Some(7.0);
+        assert!(!first.logically_eq(&second));
+    }
+
+    #[test]
+    fn test_phase_count() {
+        let mut chain = TodoList::new();
+        chain.add_todo(test_todo("a", &[]).build());
+        chain.add_todo(test_todo("b", &["a"]).build());
+        chain.add_todo(test_todo("c", &["b"]).build());
+        chain.add_todo(test_todo("d", &["c"]).build());
+        assert_eq!(chain.phase_count().unwrap(), 4);
+
+        let mut independent = TodoList::new();
+        for id in ["a", "b", "c", "d"] {
+            independent.add_todo(test_todo(id, &[]).build());
+        }
+        assert_eq!(independent.phase_count().unwrap(), 1);
+        assert_eq!(TodoList::new().phase_count().unwrap(), 0);
+
+        let mut diamond = TodoList::new();
+        diamond.add_todo(test_todo("d", &["b", "c"]).build());
+        diamond.add_todo(test_todo("c", &["a"]).build());
+        diamond.add_todo(test_todo("b", &["a", "external"]).build());
+        diamond.add_todo(test_todo("a", &[]).build());
+        assert_eq!(
+            diamond.parallel_groups().unwrap(),
+            vec![vec!["a"], vec!["b", "c"], vec!["d"]]
+        );
+
+        chain.todos[0].dependencies.push("d".to_string());
+        assert!(matches!(
+            chain.phase_count(),
+            Err(crate::Error::Validation(
+                crate::error::ValidationError::Todo(
+                    crate::error::TodoValidationError::CircularDependency { .. }
+                )
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_min_and_sequential_duration() {
+        let mut list = TodoList::new();
+        for (id, hours, deps) in [
+            ("schema", 4.0, vec![]),
+            ("api", 6.0, vec!["schema"]),
+            ("ui", 3.0, vec!["schema"]),
+            ("docs", 2.0, vec![]),
+            ("release", 1.0, vec!["api", "ui"]),
+        ] {
+            let todo = Todo::builder(format!("Implement {}", id))
+                .id(id)
+                .estimated_hours(hours)
+                .dependencies(deps)
+                .build();
+            list.add_todo(todo);
+        }
+
+        // schema -> api -> release is the heaviest chain
+        assert!((list.min_duration_hours() - 11.0).abs() < f32::EPSILON);
+        assert!((list.sequential_duration_hours() - 16.0).abs() < f32::EPSILON);
+        assert!(TodoList::new().min_duration_hours().abs() < f32::EPSILON);
+
+        // A long unestimated chain must not hide real estimates
+        let mut list = TodoList::new();
+        list.add_todo(Todo::builder("Implement a").id("a").build());
+        list.add_todo(
+            Todo::builder("Implement b")
+                .id("b")
+                .dependencies(["a"])
+                .build(),
+        );
+        list.add_todo(
+            Todo::builder("Implement c")
+                .id("c")
+                .dependencies(["b"])
+                .build(),
+        );
+        list.add_todo(
+            Todo::builder("Implement d")
+                .id("d")
+                .estimated_hours(3.0)
+                .build(),
+        );
+        assert!((list.min_duration_hours() - 3.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_critical_path() {
+        let list_of = |todos: Vec<Todo>| {
+            let mut list = TodoList::new();
+            for todo in todos {
+                list.add_todo(todo);
+            }
+            list
+        };
+
+        let linear = list_of(vec![
+            test_todo("c", &["b"]).estimated_hours(1.0).build(),
+            test_todo("a", &[]).estimated_hours(2.0).build(),
+            test_todo("b", &["a"]).build(),
+        ]);
+        assert_eq!(linear.critical_path(), vec!["a", "b", "c"]);
+
+        // The heavier branch wins; equal branches fall back to the lowest ID
+        let diamond = list_of(vec![
+            test_todo("a", &[]).estimated_hours(1.0).build(),
+            test_todo("b", &["a"]).estimated_hours(2.0).build(),
+            test_todo("c", &["a"]).estimated_hours(5.0).build(),
+            test_todo("d", &["b", "c"]).estimated_hours(1.0).build(),
+        ]);
+        assert_eq!(diamond.critical_path(), vec!["a", "c", "d"]);
+        let even = list_of(vec![
+            test_todo("a", &[]).estimated_hours(1.0).build(),
+            test_todo("c", &["a"]).estimated_hours(2.0).build(),
+            test_todo("b", &["a"]).estimated_hours(2.0).build(),
+            test_todo("d", &["c", "b"]).estimated_hours(1.0).build(),
+        ]);
+        assert_eq!(even.critical_path(), vec!["a", "b", "d"]);
+
+        let disconnected = list_of(vec![
+            test_todo("x", &[]).estimated_hours(3.0).build(),
+            test_todo("y", &["x"]).estimated_hours(3.0).build(),
+            test_todo("p", &[]).estimated_hours(1.0).build(),
+            test_todo("q", &["p"]).estimated_hours(1.0).build(),
+            test_todo("r", &["q"]).estimated_hours(1.0).build(),
+            test_todo("solo", &[]).estimated_hours(5.0).build(),
+        ]);
+        assert_eq!(disconnected.critical_path(), vec!["x", "y"]);
+
+        let cyclic = list_of(vec![
+            test_todo("a", &["b"]).build(),
+            test_todo("b", &["a"]).build(),
+        ]);
+        assert!(cyclic.critical_path().is_empty());
+        assert!(TodoList::new().critical_path().is_empty());
+    }
+
+    #[cfg(feature = "todo-validation")]
+    #[test]
+    fn test_missing_created_at_parses_deterministically() {
+        let yaml = "id: a\ncontent: Write release notes\nstatus: pending\npriority: low\n";
+        let first: Todo = serde_yaml::from_str(yaml).unwrap();
+        let second: Todo = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(first.created_at, chrono::DateTime::UNIX_EPOCH);
+        assert_eq!(first.created_at, second.created_at);
+    }
+
+    #[test]
+    fn test_canonical_yaml_is_order_independent() {
+        let mut first = Todo::new("Design database schema");
+        first.id = "a".to_string();
+        first.estimated_hours = Some(0.1);
+        first
+            .custom_fields
+            .insert("team".to_string(), serde_json::json!("core"));
+        first
+            .custom_fields
+            .insert("area".to_string(), serde_json::json!({"z": 1, "b": 2}));
+        first
+            .custom_fields
+            .insert("weight".to_string(), serde_json::json!(0.123_456_789));
+        let mut second = Todo::new("Implement REST endpoints");
+        second.id = "b".to_string();
+        second.dependencies = vec!["a".to_string()];
+
+        let mut list = TodoList::new();
+        list.add_todo(first.clone());
+        list.add_todo(second.clone());
+
+        let mut reordered_first = first;
+        reordered_first.custom_fields = [
+            ("area".to_string(), serde_json::json!({"b": 2, "z": 1})),
+            ("team".to_string(), serde_json::json!("core")),
+            ("weight".to_string(), serde_json::json!(0.123_456_789)),
+        ]
+        .into_iter()
+        .collect();
+        let mut reordered = TodoList::new();
+        reordered.add_todo(second);
+        reordered.add_todo(reordered_first);
+        reordered.metadata.generated_at = list.metadata.generated_at;
+
+        assert!(list.logically_eq(&reordered));
+        let yaml = list.to_canonical_yaml().unwrap();
+        assert_eq!(yaml, reordered.to_canonical_yaml().unwrap());
+        assert!(yaml.contains("estimated_hours: 0.1\n"));
+        assert!(yaml.contains("weight: 0.123456789\n"));
+        assert!(yaml.find("id: a").unwrap() < yaml.find("id: b").unwrap());
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let mut fan_out = TodoList::new();
+        fan_out.add_todo(test_todo("d", &["a"]).priority(TodoPriority::High).build());
+        fan_out.add_todo(test_todo("b", &["a"]).priority(TodoPriority::High).build());
+        fan_out.add_todo(
+            test_todo("c", &["a", "external"])
+                .priority(TodoPriority::Critical)
+                .build(),
+        );
+        fan_out.add_todo(test_todo("a", &[]).priority(TodoPriority::Low).build());
+        fan_out.add_todo(test_todo("e", &[]).priority(TodoPriority::Medium).build());
+
+        let order: Vec<&str> = fan_out
+            .topological_order()
+            .unwrap()
+            .iter()
+            .map(|t| t.id.as_str())
+            .collect();
+        assert_eq!(order, vec!["e", "a", "c", "b", "d"]);
+
+        let mut cyclic = TodoList::new();
+        cyclic.add_todo(test_todo("x", &["y"]).build());
+        cyclic.add_todo(test_todo("y", &["x"]).build());
+        cyclic.add_todo(test_todo("z", &[]).build());
+
+        let mut cycle = cyclic.topological_order().unwrap_err();
+        cycle.sort();
+        assert_eq!(cycle, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn test_merge_prefixes_ids_and_dependencies() {
+        let list_of = |todos: &[(&str, f32, &[&str])]| {
+            let mut list = TodoList::new();
+            for (id, hours, deps) in todos {
+                list.add_todo(
+                    Todo::builder(format!("Implement {}", id))
+                        .id(*id)
+                        .estimated_hours(*hours)
+                        .dependencies(deps.iter().copied())
+                        .build(),
+                );
+            }
+            list
+        };
+
+        let mut backend = list_of(&[("todo_0", 4.0, &[]), ("todo_1", 2.0, &["todo_0"])]);
+        let frontend = list_of(&[
+            ("todo_0", 3.0, &[]),
+            ("todo_1", 1.0, &["todo_0", "shared_schema"]),
+        ]);
+
+        let err = backend.merge(frontend.clone(), None).unwrap_err();
+        assert!(err.to_string().contains("duplicate todo ID 'todo_0'"));
+        assert_eq!(backend.todos.len(), 2);
+
+        backend.merge(frontend, Some("ui_")).unwrap();
+        let ids: Vec<&str> = backend.todos.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["todo_0", "todo_1", "ui_todo_0", "ui_todo_1"]);
+        assert_eq!(backend.todos[1].dependencies, vec!["todo_0"]);
+        assert_eq!(
+            backend.todos[3].dependencies,
+            vec!["ui_todo_0", "shared_schema"]
+        );
+        assert_eq!(backend.metadata.total_count, 4);
+        assert!((backend.metadata.total_estimated_hours - 10.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_subgraph_from() {
+        let mut list = TodoList::new();
+        for (id, deps) in [
+            ("schema", vec![]),
+            ("auth_api", vec!["schema", "missing"]),
+            ("login_ui", vec!["auth_api"]),
+            ("billing", vec!["schema"]),
+            ("docs", vec![]),
+        ] {
+            let mut todo = Todo::new(format!("Implement {}", id));
+            todo.id = id.to_string();
+            todo.estimated_hours = Some(2.0);
+            todo.dependencies = deps.into_iter().map(String::from).collect();
+            list.add_todo(todo);
+        }
+
+        let subgraph = list.subgraph_from(&["login_ui".to_string(), "unknown".to_string()]);
+
+        let ids: Vec<&str> = subgraph.todos.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["schema", "auth_api", "login_ui"]);
+        assert_eq!(subgraph.todos[1].dependencies, vec!["schema"]);
+        assert_eq!(subgraph.metadata.total_count, 3);
+        assert!((subgraph.metadata.total_estimated_hours - 6.0).abs() < f32::EPSILON);
+        assert!(subgraph.validate_dependencies().is_ok());
+        assert!(list.subgraph_from(&[]).todos.is_empty());
+    }
+
+    #[test]
+    fn test_split_into_batches() {
+        let mut list = TodoList::new();
+        for i in 0..11 {
+            let mut todo = Todo::new(format!("Implement step {}", i));
+            todo.id = format!("step_{:02}", i);
+            // Later steps depend on earlier ones, but are added first
+            if i > 0 {
+                todo.dependencies = vec![format!("step_{:02}", i - 1)];
+            }
+            list.todos.insert(0, todo);
+        }
+        list.update_metadata();
+
+        let batches = list.split_into_batches(4);
+
+        assert_eq!(batches.len(), 3);
+        assert!(batches.iter().all(|b| b.todos.len() <= 4));
+        assert_eq!(batches.iter().map(|b| b.todos.len()).sum::<usize>(), 11);
+        assert_eq!(batches[2].metadata.total_count, 3);
+
+        let batch_of: HashMap<&str, usize> = batches
+            .iter()
+            .enumerate()
+            .flat_map(|(n, b)| b.todos.iter().map(move |t| (t.id.as_str(), n)))
+            .collect();
+        for (n, batch) in batches.iter().enumerate() {
+            for todo in &batch.todos {
+                for dep in &todo.dependencies {
+                    assert!(
+                        batch_of[dep.as_str()] <= n,
+                        "{} forward-references {}",
+                        todo.id,
+                        dep
+                    );
+                }
+            }
+        }
+
+        assert_eq!(list.split_into_batches(0).len(), 11);
+        assert!(TodoList::new().split_into_batches(4).is_empty());
+    }
+
+    #[test]
+    fn test_ordering_apis_share_tie_break() {
+        // Five interchangeable todos feeding one final step, inserted out of order
+        let build = |ids: &[&str]| {
+            let mut list = TodoList::new();
+            for id in ids {
+                let mut todo = Todo::new(format!("Implement step {}", id));
+                todo.id = (*id).to_string();
+                todo.estimated_hours = Some(1.0);
+                if *id == "z" {
+                    todo.dependencies = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+                }
+                list.add_todo(todo);
+            }
+            list
+        };
+        let expected = ["a", "b", "c", "d", "e", "z"];
+
+        for list in [
+            build(&["d", "z", "b", "e", "a", "c"]),
+            build(&["z", "e", "d", "c", "b", "a"]),
+        ] {
+            let mut by_key: Vec<&Todo> = list.todos.iter().collect();
+            by_key.sort_by(|a, b| stable_todo_key(a).cmp(&stable_todo_key(b)));
+            let by_key: Vec<&str> = by_key.iter().map(|t| t.id.as_str()).collect();
+            assert_eq!(by_key, expected);
+
+            let topological: Vec<&str> = list
+                .topological_order()
+                .unwrap()
+                .iter()
+                .map(|t| t.id.as_str())
+                .collect();
+            assert_eq!(topological, expected);
+
+            let groups = list.parallel_groups().unwrap();
+            assert_eq!(groups, vec![expected[..5].to_vec(), vec!["z"]]);
+
+            assert_eq!(list.adjacency_matrix().0, expected);
+
+            let canonical: serde_yaml::Value =
+                serde_yaml::from_str(&list.to_canonical_yaml().unwrap()).unwrap();
+            let canonical: Vec<&str> = canonical["todos"]
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .map(|t| t["id"].as_str().unwrap())
+                .collect();
+            assert_eq!(canonical, expected);
+
+            assert_eq!(list.critical_path(), vec!["a", "z"]);
+        }
+    }
+
+    #[test]
+    fn test_adjacency_matrix() {
+        let mut list = TodoList::new();
+        for (id, deps) in [("c", vec!["a", "missing"]), ("a", vec![]), ("b", vec!["a"])] {
+            let mut todo = Todo::new(format!("Implement step {}", id));
+            todo.id = id.to_string();
+            todo.dependencies = deps.into_iter().map(String::from).collect();
+            list.add_todo(todo);
+        }
+
+        let (ids, matrix) = list.adjacency_matrix();
+
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(
+            matrix,
+            vec![
+                vec![false, false, false],
+                vec![true, false, false],
+                vec![true, false, false],
+            ]
+        );
+    }
+
     #[test]
     fn test_todo_actionability() {
         let actionable = Todo::new("Implement user login system");
@@ -666,6 +2597,34 @@ mod tests {
         assert!(score >= 3, "Expected complexity score >= 3, got {}", score);
     }
 
+    #[test]
+    fn test_custom_complexity_model() {
+        let todo = Todo::new("Renovate kitchen plumbing and wiring, tiles, paint");
+        assert_eq!(todo.complexity_score(), 2);
+
+        let model = ComplexityModel {
+            keywords: vec!["plumbing".to_string(), "wiring".to_string()],
+            keyword_weight: 3,
+            technical_terms: vec!["kitchen".to_string()],
+            technical_term_weight: 2,
+        };
+        assert_eq!(todo.complexity_score_with(&model), 10);
+
+        // Model terms match regardless of their case
+        let shouting = ComplexityModel {
+            keywords: vec!["Plumbing".to_string(), "WIRING".to_string()],
+            technical_terms: vec!["Kitchen".to_string()],
+            ..model
+        };
+        assert_eq!(todo.complexity_score_with(&shouting), 10);
+
+        let neutral = Todo::new("Implement database migration");
+        assert_eq!(
+            neutral.complexity_score_with(&ComplexityModel::default()),
+            neutral.complexity_score()
+        );
+    }
+
     #[test]
     fn test_todo_list_metadata() {
         let mut list = TodoList::new();
@@ -719,6 +2678,280 @@ mod tests {
         assert!(list.validate_dependencies().is_err());
     }
 
+    #[test]
+    fn test_ready_todos_exclude_blocked() {
+        let mut list = TodoList::new();
+
+        let mut done = Todo::new("Task 1");
+        done.id = "task1".to_string();
+        done.status = TodoStatus::Completed;
+
+        let mut ready = Todo::new("Task 2");
+        ready.id = "task2".to_string();
+        ready.dependencies = vec!["task1".to_string()];
+
+        let mut blocked = Todo::new("Task 3");
+        blocked.id = "task3".to_string();
+        blocked.dependencies = vec!["task1".to_string()];
+        blocked.block("Waiting on vendor API access");
+
+        let mut waiting = Todo::new("Task 4");
+        waiting.id = "task4".to_string();
+        waiting.dependencies = vec!["task2".to_string()];
+
+        list.add_todo(done);
+        list.add_todo(ready);
+        list.add_todo(blocked);
+        list.add_todo(waiting);
+
+        let ready_ids: Vec<&str> = list.ready_todos().iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ready_ids, vec!["task2"]);
+        assert_eq!(
+            list.todos[2].blocked_reason.as_deref(),
+            Some("Waiting on vendor API access")
+        );
+    }
+
+    #[test]
+    fn test_blocking_path() {
+        let mut list = TodoList::new();
+        for (id, deps) in [
+            ("schema", vec![]),
+            ("migrations", vec!["schema"]),
+            ("api", vec!["migrations"]),
+            ("ui", vec!["api"]),
+        ] {
+            let mut todo = Todo::new(format!("Implement {}", id));
+            todo.id = id.to_string();
+            todo.dependencies = deps.into_iter().map(String::from).collect();
+            list.add_todo(todo);
+        }
+
+        assert_eq!(
+            list.blocking_path("ui"),
+            vec!["api", "migrations", "schema"]
+        );
+        assert!(list.blocking_path("schema").is_empty());
+
+        // Completed ancestors no longer block, nor does anything behind them
+        list.todos[1].status = TodoStatus::Completed;
+        assert_eq!(list.blocking_path("ui"), vec!["api"]);
+    }
+
+    #[test]
+    fn test_ready_for_work() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Implement password reset flow");
+        todo.id = "reset".to_string();
+        todo.assignee = Some("sam".to_string());
+        todo.acceptance_criteria = vec!["Reset email is sent".to_string()];
+        list.add_todo(todo);
+
+        let policy = ReadinessPolicy::default();
+        assert_eq!(
+            list.ready_for_work("reset", &policy),
+            Err(vec!["Missing time estimate".to_string()])
+        );
+
+        let relaxed = ReadinessPolicy {
+            require_estimate: false,
+            ..ReadinessPolicy::default()
+        };
+        assert!(list.ready_for_work("reset", &relaxed).is_ok());
+
+        list.todos[0].estimated_hours = Some(3.0);
+        list.todos[0].open_questions = vec!["Which mail provider?".to_string()];
+        let failures = list.ready_for_work("reset", &policy).unwrap_err();
+        assert_eq!(
+            failures,
+            vec!["1 unresolved question(s): Which mail provider?"]
+        );
+
+        assert!(list.ready_for_work("missing", &policy).is_err());
+    }
+
+    #[test]
+    fn test_risk_adjusted_hours() {
+        let mut list = TodoList::new();
+
+        let mut uncertain = Todo::new("Integrate payment provider");
+        uncertain.estimated_hours = Some(4.0);
+        uncertain.estimate_confidence = Some(0.5);
+        list.add_todo(uncertain);
+
+        let mut certain = Todo::new("Add login button");
+        certain.estimated_hours = Some(2.0);
+        list.add_todo(certain);
+
+        assert!((list.risk_adjusted_hours() - 10.0).abs() < 1e-4);
+
+        // Non-finite input never leaks NaN or infinity into the total
+        let mut garbage = Todo::new("Migrate legacy billing data");
+        garbage.estimated_hours = Some(3.0);
+        garbage.estimate_confidence = Some(f32::NAN);
+        list.add_todo(garbage);
+        let mut unbounded = Todo::new("Refactor reporting module");
+        unbounded.estimated_hours = Some(f32::INFINITY);
+        unbounded.estimate_confidence = Some(f32::INFINITY);
+        list.add_todo(unbounded);
+        assert!((list.risk_adjusted_hours() - 13.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_resolve_priority_inversions() {
+        let mut list = TodoList::new();
+        for (id, priority, deps) in [
+            ("db", TodoPriority::Low, vec![]),
+            ("api", TodoPriority::Low, vec!["db"]),
+            ("release", TodoPriority::High, vec!["api"]),
+            ("docs", TodoPriority::Medium, vec![]),
+        ] {
+            let mut todo = Todo::new(format!("Implement {}", id));
+            todo.id = id.to_string();
+            todo.priority = priority;
+            todo.dependencies = deps.into_iter().map(String::from).collect();
+            list.add_todo(todo);
+        }
+
+        assert_eq!(list.resolve_priority_inversions(), 2);
+        let priorities: Vec<TodoPriority> = list.todos.iter().map(|t| t.priority).collect();
+        assert_eq!(
+            priorities,
+            vec![
+                TodoPriority::High,
+                TodoPriority::High,
+                TodoPriority::High,
+                TodoPriority::Medium
+            ]
+        );
+        assert_eq!(list.resolve_priority_inversions(), 0);
+    }
+
+    #[test]
+    fn test_auto_tag_from_context() {
+        let mut list = TodoList::new();
+        list.project = Some(ProjectContext {
+            name: "Service".to_string(),
+            description: None,
+            project_type: None,
+            target_date: None,
+            stakeholders: Vec::new(),
+            tech_stack: vec![
+                "Rust".to_string(),
+                "Go".to_string(),
+                "Google Cloud".to_string(),
+            ],
+            budget_hours: None,
+        });
+        list.add_todo(Todo::new("Implement the parser in RUST"));
+        let mut tagged = Todo::new("Deploy rust service to google cloud");
+        tagged.tags = vec!["rust".to_string()];
+        list.add_todo(tagged);
+        list.add_todo(Todo::new("Write a good README for trusted users"));
+
+        list.auto_tag_from_context();
+
+        assert_eq!(list.todos[0].tags, vec!["rust"]);
+        assert_eq!(list.todos[1].tags, vec!["rust", "google-cloud"]);
+        assert!(list.todos[2].tags.is_empty());
+    }
+
+    #[test]
+    fn test_estimated_wall_clock_hours() {
+        // Serial chain: design -> implement -> verify
+        let mut serial = TodoList::new();
+        let design = Todo::builder("Design schema").estimated_hours(2.0).build();
+        let implement = Todo::builder("Implement schema")
+            .estimated_hours(3.0)
+            .dependencies([design.id.clone()])
+            .build();
+        let verify = Todo::builder("Test schema")
+            .estimated_hours(1.0)
+            .dependencies([implement.id.clone()])
+            .build();
+        serial.add_todo(design);
+        serial.add_todo(implement);
+        serial.add_todo(verify);
+        assert!((serial.estimated_wall_clock_hours().unwrap() - 6.0).abs() < f32::EPSILON);
+        assert!((serial.metadata.total_estimated_hours - 6.0).abs() < f32::EPSILON);
+
+        // Parallel: three independent todos and one unestimated dependent
+        let mut parallel = TodoList::new();
+        let ids: Vec<String> = [2.0, 4.0, 3.0]
+            .into_iter()
+            .map(|hours| {
+                let todo = Todo::builder("Write endpoint")
+                    .estimated_hours(hours)
+                    .build();
+                let id = todo.id.clone();
+                parallel.add_todo(todo);
+                id
+            })
+            .collect();
+        parallel.add_todo(Todo::builder("Review endpoints").dependencies(ids).build());
+        assert!((parallel.estimated_wall_clock_hours().unwrap() - 4.0).abs() < f32::EPSILON);
+        assert!((parallel.metadata.total_estimated_hours - 9.0).abs() < f32::EPSILON);
+
+        // Cycles are rejected
+        let mut cyclic = TodoList::new();
+        let mut first = Todo::builder("Build x")
+            .id("x")
+            .estimated_hours(1.0)
+            .build();
+        let mut second = Todo::builder("Build y")
+            .id("y")
+            .estimated_hours(1.0)
+            .build();
+        first.dependencies.push("y".to_string());
+        second.dependencies.push("x".to_string());
+        cyclic.todos = vec![first, second];
+        assert!(cyclic.estimated_wall_clock_hours().is_err());
+    }
+
+    #[test]
+    fn test_suggest_dependencies() {
+        let mut list = TodoList::new();
+        let design = Todo::new("Design auth");
+        let implement = Todo::new("Implement auth");
+        let test = Todo::new("Test auth flow");
+        let unrelated = Todo::new("Implement billing export");
+        let (design_id, implement_id, test_id) =
+            (design.id.clone(), implement.id.clone(), test.id.clone());
+        list.add_todo(test);
+        list.add_todo(implement);
+        list.add_todo(design);
+        list.add_todo(unrelated);
+
+        assert_eq!(
+            list.suggest_dependencies(),
+            vec![
+                (test_id, implement_id.clone()),
+                (implement_id.clone(), design_id.clone()),
+            ]
+        );
+
+        // Existing links are not suggested again
+        list.todos[0].dependencies.push(implement_id.clone());
+        assert_eq!(list.suggest_dependencies(), vec![(implement_id, design_id)]);
+    }
+
+    #[test]
+    fn test_round_estimates() {
+        let mut list = TodoList::new();
+        for hours in [2.3, 0.1, 4.0] {
+            let mut todo = Todo::new("Implement estimate rounding");
+            todo.estimated_hours = Some(hours);
+            list.add_todo(todo);
+        }
+        list.add_todo(Todo::new("Review estimate rounding"));
+
+        list.round_estimates(0.5);
+
+        let estimates: Vec<Option<f32>> = list.todos.iter().map(|t| t.estimated_hours).collect();
+        assert_eq!(estimates, vec![Some(2.5), Some(0.5), Some(4.0), None]);
+        assert!((list.metadata.total_estimated_hours - 7.0).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_todo_progress() {
         let pending = Todo::new("Pending task");
@@ -73,6 +73,21 @@ pub struct GateResult {
     pub suggestions: Vec<String>,
 }
 
+/// Metrics-independent summary of a gate, for previewing what will be checked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateDescription {
+    /// Gate identifier
+    pub id: String,
+    /// Gate type
+    pub gate_type: GateType,
+    /// Required threshold (if applicable)
+    pub threshold: Option<f64>,
+    /// Whether this gate is mandatory
+    pub mandatory: bool,
+    /// Gate description
+    pub description: String,
+}
+
 /// Quality gate pipeline for comprehensive validation
 #[derive(Debug)]
 pub struct QualityGatePipeline {
@@ -191,6 +206,20 @@ impl QualityGatePipeline {
         self.gates.retain(|g| g.id != gate_id);
     }
     
+    /// Describe the configured gates without evaluating any metrics
+    pub fn describe(&self) -> Vec<GateDescription> {
+        self.gates
+            .iter()
+            .map(|gate| GateDescription {
+                id: gate.id.clone(),
+                gate_type: gate.gate_type,
+                threshold: gate.threshold,
+                mandatory: gate.mandatory,
+                description: gate.description.clone(),
+            })
+            .collect()
+    }
+    
     /// Validate metrics against all gates
     pub fn validate(&self, metrics: &QualityMetrics) -> Vec<GateResult> {
         let mut results = Vec::new();
@@ -360,10 +389,92 @@ impl QualityGatePipeline {
             .filter(|r| !r.passed)
             .collect()
     }
+    
+    /// Build a compact report of failed gates for CI logs
+    ///
+    /// Lists each failed gate with its message and top suggestion; passing
+    /// gates are omitted.
+    pub fn failure_report(&self, metrics: &QualityMetrics) -> String {
+        let failed = self.get_failed_gates(metrics);
+        if failed.is_empty() {
+            return "All quality gates passed".to_string();
+        }
+        
+        let mut lines = vec![format!("{} quality gate(s) failed:", failed.len())];
+        for result in &failed {
+            let optional = if result.gate.mandatory { "" } else { " (optional)" };
+            lines.push(format!("- [{}] {}{}: {}",
+                result.gate.gate_type, result.gate.id, optional, result.message));
+            if let Some(suggestion) = result.suggestions.first() {
+                lines.push(format!("  suggestion: {}", suggestion));
+            }
+        }
+        
+        lines.join("\n")
+    }
 }
 
 impl Default for QualityGatePipeline {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn passing_metrics() -> QualityMetrics {
+        QualityMetrics {
+            coverage: 92.0,
+            complexity: 4,
+            doctest_count: 3,
+            property_test_count: 2,
+            example_count: 1,
+            satd_count: 0,
+        }
+    }
+    
+    #[test]
+    fn test_failure_report_lists_only_failures() {
+        let pipeline = QualityGatePipeline::new();
+        let metrics = QualityMetrics { coverage: 55.0, ..passing_metrics() };
+        
+        let report = pipeline.failure_report(&metrics);
+        
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines, vec![
+            "1 quality gate(s) failed:",
+            "- [Coverage] coverage_80_percent: Coverage 55% below required 80%",
+            "  suggestion: Add more unit tests to increase coverage",
+        ]);
+        assert!(!report.contains("Doctests"));
+        assert!(!report.contains("Complexity"));
+        
+        assert_eq!(pipeline.failure_report(&passing_metrics()), "All quality gates passed");
+    }
+    
+    #[test]
+    fn test_describe_default_gates() {
+        let descriptions = QualityGatePipeline::new().describe();
+        
+        let coverage = descriptions.iter()
+            .find(|d| d.gate_type == GateType::Coverage)
+            .unwrap();
+        assert_eq!(coverage.id, "coverage_80_percent");
+        assert_eq!(coverage.threshold, Some(80.0));
+        assert!(coverage.mandatory);
+        
+        let complexity = descriptions.iter()
+            .find(|d| d.gate_type == GateType::Complexity)
+            .unwrap();
+        assert_eq!(complexity.id, "complexity_limit");
+        assert_eq!(complexity.threshold, Some(8.0));
+        assert_eq!(complexity.description, "Cyclomatic complexity must be under 8");
+        
+        let formatting = descriptions.iter()
+            .find(|d| d.gate_type == GateType::Formatting)
+            .unwrap();
+        assert!(!formatting.mandatory);
+    }
+}
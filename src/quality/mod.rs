@@ -20,4 +20,4 @@ pub use proxy::{QualityProxy, ProxyMode, ProxyConfig, ProxyRequest, ProxyRespons
 pub use enforcement::{QualityEnforcer, EnforcementResult, EnforcementConfig};
 
 #[cfg(feature = "quality-proxy")]
-pub use gates::{QualityGate, GateResult, QualityGatePipeline};
+pub use gates::{QualityGate, GateDescription, GateResult, QualityGatePipeline};
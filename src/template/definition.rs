@@ -2,8 +2,71 @@
 //!
 //! Data structures for defining YAML templates with metadata and validation rules.
 
+use crate::template::schema::check_schema;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Handlebars helpers whose output differs between runs
+pub const NONDETERMINISTIC_HELPERS: &[&str] = &["now", "timestamp", "random", "rand", "uuid"];
+
+/// Matches a mustache expression and captures its contents
+static MUSTACHE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?s)\{\{(.*?)\}\}").unwrap());
+
+/// Matches a name in call position: the start of an expression or a subexpression
+static HELPER_CALL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^[{~#\s]*|\(\s*)([A-Za-z_][A-Za-z0-9_]*)").unwrap());
+
+/// Matches a block comment (`{{!-- --}}`) or a short comment (`{{! }}`)
+static COMMENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\{\{~?!--.*?--~?\}\}|\{\{~?!.*?~?\}\}").unwrap());
+
+/// Find non-deterministic helpers invoked in a Handlebars template body
+///
+/// Returns the helper names in order of first appearance, without duplicates.
+pub fn find_nondeterministic_helpers(template: &str) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    for expression in MUSTACHE.captures_iter(template) {
+        for call in HELPER_CALL.captures_iter(&expression[1]) {
+            let name = &call[1];
+            if NONDETERMINISTIC_HELPERS.contains(&name) && !found.iter().any(|f| f == name) {
+                found.push(name.to_string());
+            }
+        }
+    }
+    found
+}
+
+/// Remove Handlebars comments from a template body
+///
+/// When `trim_blank_lines` is set, lines left empty or whitespace-only by the
+/// removal are dropped entirely so comments don't leave blank lines in the
+/// rendered output. Blank lines that never contained a comment are kept.
+pub fn strip_handlebars_comments(template: &str, trim_blank_lines: bool) -> String {
+    let mut stripped = String::with_capacity(template.len());
+    let mut touched_lines = Vec::new();
+    let mut last_end = 0;
+
+    for comment in COMMENT.find_iter(template) {
+        stripped.push_str(&template[last_end..comment.start()]);
+        touched_lines.push(stripped.matches('\n').count());
+        last_end = comment.end();
+    }
+    stripped.push_str(&template[last_end..]);
+
+    if !trim_blank_lines || touched_lines.is_empty() {
+        return stripped;
+    }
+
+    stripped
+        .split('\n')
+        .enumerate()
+        .filter(|(idx, line)| !(touched_lines.contains(idx) && line.trim().is_empty()))
+        .map(|(_, line)| line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
 /// Complete template definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +92,10 @@ pub struct TemplateDefinition {
     /// Validation rules
     pub validation: ValidationRules,
 
+    /// Provider parameters that must be set in `metadata.parameters`
+    #[serde(default)]
+    pub required_parameters: Vec<String>,
+
     /// Handlebars template string
     pub prompt_template: String,
 
@@ -68,6 +135,9 @@ pub struct TemplateMetadata {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputSchema {
     /// Output format (yaml, json, text, markdown)
+    ///
+    /// Output is only parsed and checked when this is `yaml` or `json`; the
+    /// default, `text`, is never parsed.
     pub format: String,
 
     /// Expected output structure description
@@ -98,7 +168,10 @@ pub struct ValidationRules {
     /// Structure validation rules
     pub structure_rules: Option<StructureRules>,
 
-    /// Custom validation functions
+    /// Names of engine-registered validators to run on each generation
+    ///
+    /// Every name must be registered with the engine; unknown names fail the
+    /// generation.
     pub custom_validators: Vec<String>,
 
     /// Minimum output length
@@ -128,6 +201,10 @@ pub struct QualityGateRules {
 
     /// Custom quality rules
     pub custom_rules: HashMap<String, serde_json::Value>,
+
+    /// Whether output containing TODO/FIXME/HACK/XXX markers is rejected
+    #[serde(default)]
+    pub zero_satd: bool,
 }
 
 /// Structure validation rules
@@ -207,6 +284,31 @@ pub enum QualityMode {
     Disabled,
 }
 
+/// Audit of the factors that make a template (non-)deterministic
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeterminismReport {
+    /// Template identifier
+    pub template_id: String,
+
+    /// Configured provider
+    pub provider: String,
+
+    /// Configured temperature parameter, if any
+    pub temperature: Option<f64>,
+
+    /// Whether provider/temperature settings declare determinism
+    pub configured_deterministic: bool,
+
+    /// Non-deterministic helpers found in the template body
+    pub nondeterministic_helpers: Vec<String>,
+
+    /// Whether the template will reproduce identical output for identical input
+    pub is_reproducible: bool,
+
+    /// Human-readable explanation of each factor
+    pub reasons: Vec<String>,
+}
+
 impl TemplateDefinition {
     /// Create a new template definition
     pub fn new<S: Into<String>>(id: S, version: S, prompt_template: S) -> Self {
@@ -218,12 +320,69 @@ impl TemplateDefinition {
             input_schema: serde_json::json!({"type": "object", "properties": {}}),
             output_schema: OutputSchema::default(),
             validation: ValidationRules::default(),
+            required_parameters: Vec::new(),
             prompt_template: prompt_template.into(),
             #[cfg(feature = "quality-proxy")]
             quality_enforcement: None,
         }
     }
 
+    /// Parse a single-file template: YAML front-matter followed by the body
+    ///
+    /// The file starts with a `---` line and the front-matter ends at the
+    /// next `---` line; everything after it is the `prompt_template`. The
+    /// front-matter must set `id` and `version`; any other definition field
+    /// it sets overrides the defaults of [`TemplateDefinition::new`], with
+    /// nested mappings merged key by key.
+    pub fn from_str_with_frontmatter(s: &str) -> crate::Result<Self> {
+        let invalid = |reason: String| -> crate::Error {
+            crate::error::TemplateError::invalid_definition(reason).into()
+        };
+
+        let mut lines = crate::utils::strip_bom(s).split_inclusive('\n');
+        if lines.next().map(str::trim_end) != Some("---") {
+            return Err(invalid(
+                "Template must start with a '---' front-matter fence".to_string(),
+            ));
+        }
+        let mut front_matter = String::new();
+        let mut closed = false;
+        for line in lines.by_ref() {
+            if line.trim_end() == "---" {
+                closed = true;
+                break;
+            }
+            front_matter.push_str(line);
+        }
+        if !closed {
+            return Err(invalid(
+                "Front-matter has no closing '---' fence".to_string(),
+            ));
+        }
+        let body: String = lines.collect();
+
+        let overrides: serde_json::Value = serde_yaml::from_str(&front_matter)
+            .map_err(|err| invalid(format!("Invalid front-matter YAML: {}", err)))?;
+        let serde_json::Value::Object(overrides) = overrides else {
+            return Err(invalid("Front-matter must be a YAML mapping".to_string()));
+        };
+        if overrides.contains_key("prompt_template") {
+            return Err(invalid(
+                "Front-matter must not set 'prompt_template'; the body is the template".to_string(),
+            ));
+        }
+        for key in ["id", "version"] {
+            if !overrides.get(key).is_some_and(serde_json::Value::is_string) {
+                return Err(invalid(format!("Front-matter must set '{}'", key)));
+            }
+        }
+
+        let mut definition = serde_json::to_value(Self::new("", "", &body))?;
+        merge_value(&mut definition, serde_json::Value::Object(overrides));
+        serde_json::from_value(definition)
+            .map_err(|err| invalid(format!("Invalid front-matter: {}", err)))
+    }
+
     /// Check if template is deterministic
     pub fn is_deterministic(&self) -> bool {
         // Provider is deterministic
@@ -240,6 +399,64 @@ impl TemplateDefinition {
             .unwrap_or(false)
     }
 
+    /// Build a detailed report of why the template is or isn't deterministic
+    ///
+    /// Unlike [`is_deterministic`](Self::is_deterministic), this also scans the
+    /// template body for helpers whose output changes between runs.
+    pub fn determinism_report(&self) -> DeterminismReport {
+        let temperature = self
+            .metadata
+            .parameters
+            .get("temperature")
+            .and_then(serde_json::Value::as_f64);
+
+        let nondeterministic_helpers = find_nondeterministic_helpers(&self.prompt_template);
+        let configured_deterministic = self.is_deterministic();
+
+        let mut reasons = Vec::new();
+        if self.metadata.provider == "deterministic" {
+            reasons.push("Provider is 'deterministic'".to_string());
+        } else {
+            reasons.push(format!(
+                "Provider '{}' is not deterministic",
+                self.metadata.provider
+            ));
+        }
+        match temperature {
+            Some(0.0) => reasons.push("Temperature is 0.0".to_string()),
+            Some(t) => reasons.push(format!("Temperature {} is above 0.0", t)),
+            None => reasons.push("No temperature parameter set".to_string()),
+        }
+        for helper in &nondeterministic_helpers {
+            reasons.push(format!(
+                "Template body uses non-deterministic helper '{}'",
+                helper
+            ));
+        }
+
+        DeterminismReport {
+            template_id: self.id.clone(),
+            provider: self.metadata.provider.clone(),
+            temperature,
+            configured_deterministic,
+            is_reproducible: configured_deterministic && nondeterministic_helpers.is_empty(),
+            nondeterministic_helpers,
+            reasons,
+        }
+    }
+
+    /// Check whether the template body contains Handlebars comments
+    pub fn has_comments(&self) -> bool {
+        COMMENT.is_match(&self.prompt_template)
+    }
+
+    /// Remove Handlebars comments from the template body
+    ///
+    /// See [`strip_handlebars_comments`] for how `trim_blank_lines` behaves.
+    pub fn strip_comments(&mut self, trim_blank_lines: bool) {
+        self.prompt_template = strip_handlebars_comments(&self.prompt_template, trim_blank_lines);
+    }
+
     /// Get template parameter value
     pub fn get_parameter<T>(&self, key: &str) -> Option<T>
     where
@@ -293,6 +510,36 @@ impl TemplateDefinition {
             .into());
         }
 
+        // Reject malformed keywords the schema validator would silently skip
+        let schemas = [
+            ("Input", Some(&self.input_schema)),
+            ("Output", self.output_schema.schema.as_ref()),
+        ];
+        for (kind, schema) in schemas {
+            let violations = schema.map(check_schema).unwrap_or_default();
+            if !violations.is_empty() {
+                let violations: Vec<String> = violations.iter().map(ToString::to_string).collect();
+                return Err(crate::error::TemplateError::InvalidDefinition {
+                    reason: format!("{} schema is malformed: {}", kind, violations.join("; ")),
+                }
+                .into());
+            }
+        }
+
+        // Check required provider parameters are configured
+        let missing: Vec<&str> = self
+            .required_parameters
+            .iter()
+            .filter(|name| !self.metadata.parameters.contains_key(*name))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            return Err(crate::error::TemplateError::InvalidDefinition {
+                reason: format!("Missing required parameters: {}", missing.join(", ")),
+            }
+            .into());
+        }
+
         // Validate deterministic settings
         if self.validation.deterministic_only && !self.is_deterministic() {
             return Err(crate::error::TemplateError::InvalidDefinition {
@@ -352,7 +599,7 @@ impl Default for TemplateMetadata {
 impl Default for OutputSchema {
     fn default() -> Self {
         Self {
-            format: "yaml".to_string(),
+            format: "text".to_string(),
             structure: "Generated content structure".to_string(),
             schema: None,
             example: None,
@@ -384,6 +631,7 @@ impl Default for QualityGateRules {
             min_task_detail_chars: Some(10),
             max_task_detail_chars: Some(100),
             custom_rules: HashMap::new(),
+            zero_satd: false,
         }
     }
 }
@@ -427,10 +675,59 @@ impl Default for PmatConfig {
     }
 }
 
+/// Overlay `overlay` onto `base`, merging mappings recursively
+fn merge_value(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_value(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_str_with_frontmatter() {
+        let source = "---\nid: greeting\nversion: 1.2.0\nmetadata:\n  description: Greets a user\nvalidation:\n  max_length: 200\n---\nHello {{name}}!\n---\n";
+        let template = TemplateDefinition::from_str_with_frontmatter(source).unwrap();
+
+        assert_eq!(template.id, "greeting");
+        assert_eq!(template.version, "1.2.0");
+        assert_eq!(template.prompt_template, "Hello {{name}}!\n---\n");
+        assert_eq!(template.metadata.description, "Greets a user");
+        assert_eq!(template.metadata.provider, "deterministic");
+        assert_eq!(template.validation.max_length, Some(200));
+        assert!(template.validate().is_ok());
+    }
+
+    #[test]
+    fn test_frontmatter_errors() {
+        let err =
+            TemplateDefinition::from_str_with_frontmatter("---\nid: greeting\nHello").unwrap_err();
+        assert!(err.to_string().contains("no closing '---' fence"));
+
+        let err = TemplateDefinition::from_str_with_frontmatter("Hello {{name}}").unwrap_err();
+        assert!(err.to_string().contains("must start with a '---'"));
+
+        let err =
+            TemplateDefinition::from_str_with_frontmatter("---\nid: [oops\n---\nHi").unwrap_err();
+        assert!(err.to_string().contains("Invalid front-matter YAML"));
+
+        let err = TemplateDefinition::from_str_with_frontmatter("---\nid: greeting\n---\nHi")
+            .unwrap_err();
+        assert!(err.to_string().contains("must set 'version'"));
+    }
+
     #[test]
     fn test_template_definition_creation() {
         let template =
@@ -451,6 +748,24 @@ mod tests {
         assert!(template.validate().is_err());
     }
 
+    #[test]
+    fn test_malformed_schemas_rejected() {
+        let mut template = TemplateDefinition::new("test", "1.0", "{{input}}");
+        template.input_schema = serde_json::json!({
+            "type": "object",
+            "properties": {"input": {"type": "text"}}
+        });
+        let err = template.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Input schema is malformed: $.properties.input.type: unknown type 'text'"));
+
+        template.input_schema = serde_json::json!({"type": "object"});
+        template.output_schema.schema = Some(serde_json::json!({"required": "todos"}));
+        let err = template.validate().unwrap_err();
+        assert!(err.to_string().contains("Output schema is malformed"));
+    }
+
     #[test]
     fn test_deterministic_detection() {
         let mut template = TemplateDefinition::new("test", "1.0", "{{input}}");
@@ -501,4 +816,70 @@ mod tests {
         assert!(all_tags.contains(&"deterministic".to_string()));
         assert!(all_tags.contains(&"strict".to_string()));
     }
+
+    #[test]
+    fn test_determinism_report() {
+        let template = TemplateDefinition::new("test", "1.0", "Hello {{name}}!");
+        let report = template.determinism_report();
+        assert!(report.configured_deterministic);
+        assert!(report.is_reproducible);
+        assert_eq!(report.temperature, Some(0.0));
+        assert!(report.nondeterministic_helpers.is_empty());
+
+        let template = TemplateDefinition::new(
+            "test",
+            "1.0",
+            "Generated at {{now}} for {{name}} ({{upper (uuid)}})",
+        );
+        assert!(template.is_deterministic());
+
+        let report = template.determinism_report();
+        assert!(report.configured_deterministic);
+        assert!(!report.is_reproducible);
+        assert_eq!(report.nondeterministic_helpers, vec!["now", "uuid"]);
+        assert!(report.reasons.iter().any(|r| r.contains("'now'")));
+
+        // Plain text mentioning a helper name is not an invocation
+        let template = TemplateDefinition::new("test", "1.0", "Do it now: {{task}} (random order)");
+        assert!(template.determinism_report().is_reproducible);
+    }
+
+    #[test]
+    fn test_required_parameters() {
+        let mut template =
+            TemplateDefinition::new("test", "1.0", "{{#each items}}{{this}}{{/each}}");
+        template.required_parameters = vec!["max_items".to_string(), "style".to_string()];
+        template
+            .set_parameter("style".to_string(), "compact")
+            .unwrap();
+
+        let err = template.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Missing required parameters: max_items"));
+
+        template.set_parameter("max_items".to_string(), 10).unwrap();
+        assert!(template.validate().is_ok());
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        let body = "{{!-- Greeting template\n  maintained by docs team --}}\n\
+                    Hello {{name}}! {{! inline note }}\n\
+                    {{~! whitespace-controlled ~}}\n\
+                    \n\
+                    Bye";
+
+        let mut template = TemplateDefinition::new("test", "1.0", body);
+        assert!(template.has_comments());
+
+        let mut untrimmed = template.clone();
+        untrimmed.strip_comments(false);
+        assert_eq!(untrimmed.prompt_template, "\nHello {{name}}! \n\n\nBye");
+
+        template.strip_comments(true);
+        assert!(!template.has_comments());
+        // The intentional blank line is kept, comment-only lines are dropped
+        assert_eq!(template.prompt_template, "Hello {{name}}! \n\nBye");
+    }
 }
@@ -2,15 +2,86 @@
 //!
 //! Core engine for processing templates and generating content.
 
-use crate::error::{Result, TemplateError};
+use crate::error::{Error, Result, TemplateError, ValidationError};
 use crate::models::content::GeneratedContent;
-use crate::template::definition::TemplateDefinition;
+use crate::template::definition::{OutputSchema, StructureRules, TemplateDefinition};
+use crate::template::inheritance::merge_definitions;
+use crate::template::postprocess::PostProcessor;
+use crate::template::schema::{self, CompiledSchema, ValidationMode};
+use crate::template::validator::TemplateValidator;
+use crate::validators::structure::{iter_satd, StructureValidator};
 use handlebars::Handlebars;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tracing::{debug, info};
 
+/// Key under which the global context is exposed to templates (`{{_ctx.org}}`)
+pub const GLOBAL_CONTEXT_KEY: &str = "_ctx";
+
+/// Helpers available in every [`TemplateEngine::new`]: the engine's own
+/// string helpers followed by those Handlebars registers itself
+pub const BUILTIN_HELPERS: &[&str] = &[
+    "upper",
+    "lower",
+    "capitalize",
+    "if",
+    "unless",
+    "each",
+    "with",
+    "lookup",
+    "raw",
+    "log",
+    "eq",
+    "ne",
+    "gt",
+    "gte",
+    "lt",
+    "lte",
+    "and",
+    "or",
+    "not",
+    "len",
+];
+
+/// Hit and miss counts for the compiled template cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct TemplateCacheStats {
+    /// Registrations that reused the already compiled template
+    pub hits: usize,
+
+    /// Registrations that compiled the template
+    pub misses: usize,
+}
+
+/// Stage reached by a generation, as reported to progress callbacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GenerationPhase {
+    /// The template was found and its inheritance chain flattened
+    TemplateResolved,
+    /// The input was validated and rendered, and post-processors applied
+    Rendered,
+    /// The output passed schema and structure validation
+    Validated,
+    /// The generated content is ready
+    Complete,
+}
+
+/// Progress update from [`TemplateEngine::generate_with_progress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct GenerationProgress {
+    /// Stage just reached
+    pub phase: GenerationPhase,
+
+    /// Time since generation started
+    pub elapsed: std::time::Duration,
+}
+
 /// Main template engine
 #[derive(Debug)]
 pub struct TemplateEngine {
@@ -23,6 +94,47 @@ pub struct TemplateEngine {
     /// Quality proxy integration
     #[cfg(feature = "quality-proxy")]
     quality_proxy: Option<Arc<crate::quality::QualityProxy>>,
+
+    /// Whether to reject templates that use non-deterministic helpers
+    reject_nonreproducible: bool,
+
+    /// Maximum number of generated items allowed in this session
+    generation_quota: Option<usize>,
+
+    /// Number of items generated so far in this session
+    generated_items: AtomicUsize,
+
+    /// Transforms applied to rendered output, in order
+    post_processors: Vec<Box<dyn PostProcessor>>,
+
+    /// Validators templates can name in `custom_validators`
+    validators: HashMap<String, Box<dyn TemplateValidator>>,
+
+    /// Ambient values merged into every render under [`GLOBAL_CONTEXT_KEY`]
+    global_context: Option<serde_json::Value>,
+
+    /// Hard cap on rendered output size in bytes
+    max_output_bytes: Option<usize>,
+
+    /// Whether validation reports every problem or only the first
+    validation_mode: ValidationMode,
+
+    /// Whether generated content IDs are derived from the output
+    #[cfg(feature = "todo-validation")]
+    deterministic_ids: bool,
+
+    /// Whether generated todo lists are validated as part of `generate`
+    #[cfg(feature = "todo-validation")]
+    validate_todos: bool,
+
+    /// Prompt template body compiled for each template ID
+    compiled_bodies: HashMap<String, String>,
+
+    /// Input schema compiled for each template ID
+    input_schemas: HashMap<String, CompiledSchema>,
+
+    /// Compiled template cache counters
+    cache_stats: TemplateCacheStats,
 }
 
 impl TemplateEngine {
@@ -40,7 +152,127 @@ impl TemplateEngine {
             handlebars,
             #[cfg(feature = "quality-proxy")]
             quality_proxy: None,
+            reject_nonreproducible: false,
+            generation_quota: None,
+            generated_items: AtomicUsize::new(0),
+            post_processors: Vec::new(),
+            validators: HashMap::new(),
+            global_context: None,
+            max_output_bytes: None,
+            validation_mode: ValidationMode::default(),
+            #[cfg(feature = "todo-validation")]
+            deterministic_ids: false,
+            #[cfg(feature = "todo-validation")]
+            validate_todos: false,
+            compiled_bodies: HashMap::new(),
+            input_schemas: HashMap::new(),
+            cache_stats: TemplateCacheStats::default(),
+        }
+    }
+
+    /// Limit the total number of items this engine may generate
+    ///
+    /// Each generation counts the items in its output (the entries of a
+    /// top-level `todos` list or sequence, otherwise one). Once a generation
+    /// would exceed the quota, `generate` returns [`TemplateError::QuotaExceeded`].
+    #[must_use]
+    pub const fn with_generation_quota(mut self, quota: usize) -> Self {
+        self.generation_quota = Some(quota);
+        self
+    }
+
+    /// Abort rendering once output exceeds `bytes`
+    ///
+    /// A safety net against runaway templates, independent of each template's
+    /// declared `max_length`: rendering stops as soon as the cap is crossed
+    /// and `generate` returns [`TemplateError::SizeLimit`].
+    #[must_use]
+    pub const fn with_max_output_bytes(mut self, bytes: usize) -> Self {
+        self.max_output_bytes = Some(bytes);
+        self
+    }
+
+    /// Number of items generated so far in this session
+    pub fn generated_items(&self) -> usize {
+        self.generated_items.load(Ordering::SeqCst)
+    }
+
+    /// Reject templates at registration if their body defeats reproducibility
+    ///
+    /// When enabled, templates invoking non-deterministic helpers such as
+    /// `now` or `random` are refused even if their provider and temperature
+    /// settings look deterministic.
+    pub fn set_reject_nonreproducible(&mut self, reject: bool) {
+        self.reject_nonreproducible = reject;
+    }
+
+    /// Register a custom Handlebars helper
+    ///
+    /// Replaces any helper already registered under `name`, including the
+    /// built-ins. Helpers must be deterministic: output that depends on time,
+    /// randomness or external state defeats the crate's reproducibility
+    /// guarantees.
+    pub fn register_helper(
+        &mut self,
+        name: &str,
+        helper: Box<dyn handlebars::HelperDef + Send + Sync + 'static>,
+    ) {
+        self.handlebars.register_helper(name, helper);
+    }
+
+    /// Register a custom helper, refusing to shadow a built-in when rejecting
+    /// non-reproducible templates
+    ///
+    /// With [`set_reject_nonreproducible`](Self::set_reject_nonreproducible)
+    /// enabled, names in [`BUILTIN_HELPERS`] cannot be replaced, so templates
+    /// relying on them keep their known behavior.
+    pub fn register_helper_checked(
+        &mut self,
+        name: &str,
+        helper: Box<dyn handlebars::HelperDef + Send + Sync + 'static>,
+    ) -> Result<()> {
+        if self.reject_nonreproducible && BUILTIN_HELPERS.contains(&name) {
+            return Err(TemplateError::invalid_definition(format!(
+                "Helper '{}' would replace a built-in helper",
+                name
+            ))
+            .into());
         }
+        self.register_helper(name, helper);
+        Ok(())
+    }
+
+    /// Choose whether `generate` reports every validation problem or the first
+    ///
+    /// Applies to input schema, output schema and structure validation. The
+    /// default, [`ValidationMode::CollectAll`], aggregates all problems of a
+    /// check into a single error; [`ValidationMode::FailFast`] stops at the
+    /// first one.
+    pub fn set_validation_mode(&mut self, mode: ValidationMode) {
+        self.validation_mode = mode;
+    }
+
+    /// Derive generated content IDs from the template and output
+    ///
+    /// When enabled, generating the same output from the same template always
+    /// yields the same [`GeneratedContent::id`] instead of a random UUID, so
+    /// repeated runs can be compared byte for byte. Timestamps are unaffected.
+    #[cfg(feature = "todo-validation")]
+    pub fn set_deterministic_ids(&mut self, deterministic: bool) {
+        self.deterministic_ids = deterministic;
+    }
+
+    /// Validate generated todo lists as part of `generate`
+    ///
+    /// When enabled, output with a top-level `todos` list is checked with a
+    /// default [`TodoValidator`](crate::validators::todo::TodoValidator) and
+    /// the result stored in [`GeneratedContent::validation`]. Todo fields the
+    /// output leaves out take the defaults of
+    /// [`Todo::new`](crate::models::todo::Todo::new). Other output is left
+    /// unvalidated.
+    #[cfg(feature = "todo-validation")]
+    pub fn set_validate_todos(&mut self, validate: bool) {
+        self.validate_todos = validate;
     }
 
     /// Load builtin templates
@@ -53,51 +285,346 @@ impl TemplateEngine {
         let base_template = create_base_template();
         self.register_template(base_template)?;
 
+        // Load the validation report template
+        self.register_template(create_validation_report_template())?;
+
         info!("Loaded {} builtin templates", self.templates.len());
         Ok(())
     }
 
+    /// Load and register every `.yaml`/`.yml` template in a directory
+    ///
+    /// Subdirectories are not visited. Files are loaded in path order and
+    /// each is validated before registration. Returns the number of templates
+    /// loaded; the first unreadable, oversized or malformed file aborts the
+    /// load with an error naming its path.
+    pub async fn load_templates_from_dir<P: AsRef<Path>>(&mut self, dir: P) -> Result<usize> {
+        let dir = dir.as_ref();
+        let mut paths = Vec::new();
+        let mut entries = tokio::fs::read_dir(dir)
+            .await
+            .map_err(|err| io_error_at(dir, &err))?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|err| io_error_at(dir, &err))?
+        {
+            let path = entry.path();
+            let is_yaml = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext == "yaml" || ext == "yml");
+            let is_file = entry
+                .file_type()
+                .await
+                .map_err(|err| io_error_at(&path, &err))?
+                .is_file();
+            if is_yaml && is_file {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        for path in &paths {
+            let metadata = tokio::fs::metadata(path)
+                .await
+                .map_err(|err| io_error_at(path, &err))?;
+            let size = usize::try_from(metadata.len()).unwrap_or(usize::MAX);
+            if size > crate::MAX_TEMPLATE_SIZE {
+                return Err(TemplateError::invalid_definition(format!(
+                    "{}: {}",
+                    path.display(),
+                    TemplateError::size_limit(size, crate::MAX_TEMPLATE_SIZE)
+                ))
+                .into());
+            }
+
+            let source = tokio::fs::read_to_string(path)
+                .await
+                .map_err(|err| io_error_at(path, &err))?;
+            let source = crate::utils::strip_bom(&source);
+            let template: TemplateDefinition = serde_yaml::from_str(source).map_err(|err| {
+                TemplateError::invalid_definition(format!(
+                    "Failed to parse {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+            template.validate().map_err(|err| {
+                TemplateError::invalid_definition(format!("{}: {}", path.display(), err))
+            })?;
+            self.register_template(template)?;
+        }
+
+        info!("Loaded {} templates from {}", paths.len(), dir.display());
+        Ok(paths.len())
+    }
+
+    /// Set ambient values available to every template as `{{_ctx.<key>}}`
+    ///
+    /// Useful for values such as the current user or organization that are
+    /// not part of each call's input. When the input itself carries a `_ctx`
+    /// object, its keys take precedence over the global ones. The global
+    /// context is not stored in the generated content's `input_data`.
+    pub fn set_global_context(&mut self, context: serde_json::Value) {
+        self.global_context = Some(context);
+    }
+
+    /// Add a post-processor to run on rendered output
+    ///
+    /// Post-processors run in the order they were added.
+    pub fn add_post_processor(&mut self, processor: Box<dyn PostProcessor>) {
+        self.post_processors.push(processor);
+    }
+
+    /// Register a validator that templates can name in `custom_validators`
+    ///
+    /// Registering a name again replaces the previous validator. Generating
+    /// from a template that names a validator the engine does not have fails
+    /// with a [`ValidationError::Constraint`].
+    pub fn register_validator(&mut self, name: &str, validator: Box<dyn TemplateValidator>) {
+        self.validators.insert(name.to_string(), validator);
+    }
+
     /// Register a template definition
+    ///
+    /// The template can also be included in other templates as a partial
+    /// under its ID (`{{> id}}`); a partial registered with
+    /// [`register_partial`](Self::register_partial) under the same name takes
+    /// precedence. The input schema is compiled here, so a schema the
+    /// validator cannot use is rejected at registration.
     pub fn register_template(&mut self, template: TemplateDefinition) -> Result<()> {
         template.validate()?;
 
-        // Register with handlebars
-        self.handlebars
-            .register_template_string(&template.id, &template.prompt_template)
-            .map_err(TemplateError::from)?;
+        if self.reject_nonreproducible {
+            let report = template.determinism_report();
+            if !report.nondeterministic_helpers.is_empty() {
+                return Err(TemplateError::invalid_definition(format!(
+                    "Template '{}' is not reproducible: uses non-deterministic helpers {}",
+                    template.id,
+                    report.nondeterministic_helpers.join(", ")
+                ))
+                .into());
+            }
+        }
+
+        let input_schema = CompiledSchema::compile(&template.input_schema)?;
+
+        // Compile with handlebars unless this exact body is already compiled
+        if self.compiled_bodies.get(&template.id) == Some(&template.prompt_template) {
+            self.cache_stats.hits += 1;
+        } else {
+            self.handlebars
+                .register_template_string(&template.id, &template.prompt_template)
+                .map_err(TemplateError::from)?;
+            self.compiled_bodies
+                .insert(template.id.clone(), template.prompt_template.clone());
+            self.cache_stats.misses += 1;
+        }
 
         info!(
             "Registered template: {} (v{})",
             template.id, template.version
         );
+        self.input_schemas.insert(template.id.clone(), input_schema);
         self.templates.insert(template.id.clone(), template);
 
         Ok(())
     }
 
+    /// Hit and miss counts for compiled templates
+    ///
+    /// Re-registering a template whose prompt body is unchanged reuses the
+    /// compiled form and counts as a hit, which keeps repeated directory
+    /// reloads cheap.
+    pub const fn template_cache_stats(&self) -> TemplateCacheStats {
+        self.cache_stats
+    }
+
+    /// Register a partial that templates can include with `{{> name}}`
+    ///
+    /// Registered templates are already available as partials under their
+    /// IDs; use this for fragments that are not templates in their own right,
+    /// such as the `content` block the built-in `base` template renders.
+    pub fn register_partial(&mut self, name: &str, body: &str) -> Result<()> {
+        self.handlebars
+            .register_partial(name, body)
+            .map_err(TemplateError::from)?;
+        Ok(())
+    }
+
     /// Generate content using a template
+    ///
+    /// Input properties the template's input schema declares a `default` for
+    /// are filled in before validation and rendering when absent; the stored
+    /// [`GeneratedContent::input_data`] includes them.
     pub async fn generate<T>(&self, template_id: &str, input: T) -> Result<GeneratedContent>
     where
         T: Serialize,
+    {
+        debug!("Generating content with template: {}", template_id);
+
+        let template = self.template_for_generation(template_id)?;
+        // Serialize input to JSON value for storage
+        let input_json = serde_json::to_value(&input)?;
+        self.generate_from(&template, template_id, input_json, &mut |_| {})
+    }
+
+    /// Generate content and parse the output into `T`
+    ///
+    /// The output is parsed as YAML, which also covers JSON output. Output
+    /// that does not fit `T` fails with [`Error::Serialization`].
+    pub async fn generate_as<T, I>(&self, template_id: &str, input: I) -> Result<T>
+    where
+        T: DeserializeOwned,
+        I: Serialize,
+    {
+        let generated = self.generate(template_id, input).await?;
+        Ok(serde_yaml::from_str(&generated.content)?)
+    }
+
+    /// Generate content, reporting each phase to `on_progress`
+    ///
+    /// Phases are reported in order as they complete; a failing generation
+    /// stops reporting at the phase that failed.
+    // Async like `generate`, so callers can switch between the two freely
+    #[allow(clippy::unused_async)]
+    pub async fn generate_with_progress<T, F>(
+        &self,
+        template_id: &str,
+        input: T,
+        mut on_progress: F,
+    ) -> Result<GeneratedContent>
+    where
+        T: Serialize,
+        F: FnMut(GenerationProgress),
     {
         let start_time = std::time::Instant::now();
+        let mut report = |phase| {
+            on_progress(GenerationProgress {
+                phase,
+                elapsed: start_time.elapsed(),
+            });
+        };
 
-        debug!("Generating content with template: {}", template_id);
+        let template = self.template_for_generation(template_id)?;
+        report(GenerationPhase::TemplateResolved);
+        let input_json = serde_json::to_value(&input)?;
+        self.generate_from(&template, template_id, input_json, &mut report)
+    }
+
+    /// Generate content for many inputs with the same template
+    ///
+    /// The template is looked up and resolved once, so a missing template
+    /// fails with [`TemplateError::NotFound`] before anything is rendered.
+    /// Results are in input order; the first failing input aborts the batch.
+    // Async like `generate`, so callers can switch between the two freely
+    #[allow(clippy::unused_async)]
+    pub async fn generate_batch<T>(
+        &self,
+        template_id: &str,
+        inputs: Vec<T>,
+    ) -> Result<Vec<GeneratedContent>>
+    where
+        T: Serialize,
+    {
+        debug!(
+            "Generating {} documents with template: {}",
+            inputs.len(),
+            template_id
+        );
+
+        let template = self.template_for_generation(template_id)?;
+        inputs
+            .into_iter()
+            .map(|input| {
+                let input_json = serde_json::to_value(&input)?;
+                self.generate_from(&template, template_id, input_json, &mut |_| {})
+            })
+            .collect()
+    }
 
-        // Get template definition
+    /// Look up a template for generation, flattened when it extends a parent
+    fn template_for_generation(&self, template_id: &str) -> Result<Cow<'_, TemplateDefinition>> {
         let template = self
             .templates
             .get(template_id)
             .ok_or_else(|| TemplateError::not_found(template_id))?;
+        if template.extends.is_some() {
+            Ok(Cow::Owned(self.resolve_inheritance(template_id)?))
+        } else {
+            Ok(Cow::Borrowed(template))
+        }
+    }
 
-        // Serialize input to JSON value for storage
-        let input_json = serde_json::to_value(&input)?;
+    /// Validate input, render it with a resolved template and check the output
+    fn generate_from(
+        &self,
+        template: &TemplateDefinition,
+        template_id: &str,
+        mut input_json: serde_json::Value,
+        report: &mut dyn FnMut(GenerationPhase),
+    ) -> Result<GeneratedContent> {
+        let start_time = std::time::Instant::now();
+
+        self.prepare_input(template, &mut input_json)?;
 
         // Render template
-        let rendered_content = self
-            .handlebars
-            .render(&template.id, &input)
-            .map_err(TemplateError::from)?;
+        let render_data = self.render_data(&input_json);
+        let mut rendered_content = match self.max_output_bytes {
+            Some(limit) => self.render_capped(&template.id, &render_data, limit)?,
+            None => self
+                .handlebars
+                .render(&template.id, &render_data)
+                .map_err(TemplateError::from)?,
+        };
+
+        // Apply post-processors
+        let mut applied = Vec::with_capacity(self.post_processors.len());
+        for processor in &self.post_processors {
+            processor.process(&mut rendered_content)?;
+            applied.push(processor.name().to_string());
+        }
+        report(GenerationPhase::Rendered);
+
+        validate_output(
+            &template.output_schema,
+            &rendered_content,
+            self.validation_mode,
+        )?;
+        if let Some(rules) = &template.validation.structure_rules {
+            validate_structure(rules, &rendered_content, self.validation_mode)?;
+        }
+        if let Some(gates) = &template.validation.quality_gates {
+            if gates.zero_satd {
+                validate_no_satd(&rendered_content, self.validation_mode)?;
+            }
+        }
+        for name in &template.validation.custom_validators {
+            let validator =
+                self.validators
+                    .get(name)
+                    .ok_or_else(|| ValidationError::Constraint {
+                        constraint: "custom_validators".to_string(),
+                        details: format!("no validator registered as '{}'", name),
+                    })?;
+            validator.validate(&input_json, &rendered_content)?;
+        }
+        report(GenerationPhase::Validated);
+
+        // Enforce the session quota
+        if let Some(limit) = self.generation_quota {
+            let requested = count_generated_items(&rendered_content);
+            self.generated_items
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                    used.checked_add(requested).filter(|total| *total <= limit)
+                })
+                .map_err(|used| TemplateError::QuotaExceeded {
+                    used,
+                    requested,
+                    limit,
+                })?;
+        }
 
         // Create generated content
         let mut generated =
@@ -109,18 +636,138 @@ impl TemplateEngine {
             .template_version
             .clone_from(&template.version);
         generated.metadata.is_deterministic = template.is_deterministic();
+        generated.metadata.post_processors = applied;
         generated.metadata.processing_time_ms =
             start_time.elapsed().as_millis().min(u64::MAX as u128) as u64;
+        #[cfg(feature = "todo-validation")]
+        if self.deterministic_ids {
+            let key = format!("{}\n{}", template_id, generated.content);
+            generated.id = crate::utils::generate_seeded_content_id(0, &key);
+        }
+        #[cfg(feature = "todo-validation")]
+        if self.validate_todos {
+            generated.validation = todo_list_from_output(&generated.content).map(|list| {
+                crate::validators::todo::TodoValidator::new().validate_todo_list(&list)
+            });
+        }
 
         info!(
             "Generated content for template {} in {:?}",
             template_id,
             start_time.elapsed()
         );
+        report(GenerationPhase::Complete);
 
         Ok(generated)
     }
 
+    /// Apply schema defaults to the input and validate it for rendering
+    fn prepare_input(
+        &self,
+        template: &TemplateDefinition,
+        input_json: &mut serde_json::Value,
+    ) -> Result<()> {
+        schema::apply_defaults(&template.input_schema, input_json);
+        validate_requirement_entries(input_json)?;
+        match self.input_schemas.get(&template.id) {
+            Some(compiled) if compiled.schema() == &template.input_schema => {
+                compiled.validate(input_json, self.validation_mode)?;
+            }
+            // Templates resolved through `extends` carry a merged schema
+            _ => schema::validate_input(&template.input_schema, input_json, self.validation_mode)?,
+        }
+        if let Some(rules) = &template.validation.structure_rules {
+            ensure_requirements(rules, input_json)?;
+        }
+        Ok(())
+    }
+
+    /// Render a template straight into `writer`
+    ///
+    /// Input is prepared and validated as in [`TemplateEngine::generate`],
+    /// but the output is streamed instead of collected, so very large
+    /// outputs are never held in memory. For the same reason post-processors,
+    /// output validation, the output size cap and the generation quota do not
+    /// apply, and no [`GeneratedContent`] is produced.
+    pub fn render_to_writer<T, W>(&self, template_id: &str, input: &T, writer: &mut W) -> Result<()>
+    where
+        T: Serialize,
+        W: std::io::Write,
+    {
+        debug!("Rendering template {} to writer", template_id);
+
+        let template = self.template_for_generation(template_id)?;
+        let mut input_json = serde_json::to_value(input)?;
+        self.prepare_input(&template, &mut input_json)?;
+        self.handlebars
+            .render_to_write(&template.id, &self.render_data(&input_json), writer)
+            .map_err(TemplateError::from)?;
+        Ok(())
+    }
+
+    /// Re-run the input of previously generated content through another template
+    ///
+    /// Uses the stored `input_data`, so the same data can be rendered in a
+    /// different format or style without re-supplying it.
+    pub async fn retemplate(
+        &self,
+        content: &GeneratedContent,
+        new_template_id: &str,
+    ) -> Result<GeneratedContent> {
+        self.generate(new_template_id, &content.input_data).await
+    }
+
+    /// Render into a size-capped buffer, aborting once `limit` is exceeded
+    fn render_capped(
+        &self,
+        template_id: &str,
+        data: &serde_json::Value,
+        limit: usize,
+    ) -> Result<String> {
+        let mut writer = CappedWriter {
+            buffer: Vec::new(),
+            limit,
+            attempted: 0,
+        };
+
+        if let Err(err) = self
+            .handlebars
+            .render_to_write(template_id, data, &mut writer)
+        {
+            return Err(if writer.attempted > limit {
+                TemplateError::size_limit(writer.attempted, limit)
+            } else {
+                TemplateError::from(err)
+            }
+            .into());
+        }
+
+        String::from_utf8(writer.buffer).map_err(|e| Error::Internal(e.to_string()))
+    }
+
+    /// Build render data by merging the global context into the input
+    ///
+    /// Inputs that are not JSON objects are rendered unchanged.
+    fn render_data<'a>(&self, input: &'a serde_json::Value) -> Cow<'a, serde_json::Value> {
+        let (Some(serde_json::Value::Object(global)), serde_json::Value::Object(fields)) =
+            (&self.global_context, input)
+        else {
+            return Cow::Borrowed(input);
+        };
+
+        let mut context = global.clone();
+        if let Some(serde_json::Value::Object(local)) = fields.get(GLOBAL_CONTEXT_KEY) {
+            context.extend(local.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+
+        let mut data = fields.clone();
+        data.insert(
+            GLOBAL_CONTEXT_KEY.to_string(),
+            serde_json::Value::Object(context),
+        );
+        Cow::Owned(serde_json::Value::Object(data))
+    }
+
     /// Get list of available templates
     pub fn list_templates(&self) -> Vec<&str> {
         self.templates.keys().map(String::as_str).collect()
@@ -131,6 +778,50 @@ impl TemplateEngine {
         self.templates.get(template_id)
     }
 
+    /// Resolve a template's `extends` chain into a single flattened definition
+    ///
+    /// Ancestors are merged root first, so each child overrides its parent
+    /// (see [`merge_definitions`]). Fails when the chain references an
+    /// unregistered template or loops back on itself.
+    pub fn resolve_inheritance(&self, template_id: &str) -> Result<TemplateDefinition> {
+        let template = self
+            .templates
+            .get(template_id)
+            .ok_or_else(|| TemplateError::not_found(template_id))?;
+
+        let mut chain = vec![template];
+        while let Some(parent_id) = &chain[chain.len() - 1].extends {
+            if chain.iter().any(|t| &t.id == parent_id) {
+                let mut cycle: Vec<&str> = chain.iter().map(|t| t.id.as_str()).collect();
+                cycle.push(parent_id);
+                return Err(TemplateError::inheritance(format!(
+                    "Inheritance cycle: {}",
+                    cycle.join(" -> ")
+                ))
+                .into());
+            }
+            let parent = self.templates.get(parent_id).ok_or_else(|| {
+                TemplateError::inheritance(format!(
+                    "Template '{}' extends unregistered template '{}'",
+                    chain[chain.len() - 1].id,
+                    parent_id
+                ))
+            })?;
+            chain.push(parent);
+        }
+
+        let mut ancestors = chain.into_iter().rev();
+        let mut resolved = ancestors
+            .next()
+            .cloned()
+            .unwrap_or_else(|| template.clone());
+        for child in ancestors {
+            resolved = merge_definitions(&resolved, child);
+        }
+        resolved.extends = None;
+        Ok(resolved)
+    }
+
     /// Enable quality proxy integration
     #[cfg(feature = "quality-proxy")]
     pub fn enable_quality_proxy(&mut self, proxy: Arc<crate::quality::QualityProxy>) {
@@ -154,6 +845,7 @@ fn create_todo_list_template() -> TemplateDefinition {
         "todo_list",
         "1.0.0",
         r#"# Todo List Template
+# Granularity: {{granularity}}
 todos:
 {{#each requirements}}
   - id: "todo_{{@index}}"
@@ -252,6 +944,7 @@ todos:
             min_task_detail_chars: Some(10),
             max_task_detail_chars: Some(100),
             custom_rules: HashMap::new(),
+            zero_satd: false,
         }),
         structure_rules: Some(StructureRules {
             max_items: Some(50),
@@ -261,7 +954,7 @@ todos:
             required_elements: vec!["todos".to_string()],
             forbidden_elements: Vec::new(),
         }),
-        custom_validators: vec!["todo_validator".to_string()],
+        custom_validators: Vec::new(),
         min_length: Some(10),
         max_length: Some(50000),
     };
@@ -274,12 +967,238 @@ fn create_base_template() -> TemplateDefinition {
     let template = TemplateDefinition::new(
         "base",
         "1.0.0",
-        "# Base template for inheritance\n{{> content}}",
+        "# Base template for inheritance\n{{#> content}}{{/content}}",
+    );
+
+    template
+}
+
+/// Create the built-in validation report template
+///
+/// Renders a serialized `TodoValidationResult` as a Markdown report.
+fn create_validation_report_template() -> TemplateDefinition {
+    let mut template = TemplateDefinition::new(
+        "validation_report",
+        "1.0.0",
+        include_str!("../../templates/validation-report.hbs"),
     );
 
+    template.metadata.description =
+        "Render a todo validation result as a Markdown report".to_string();
+    template.metadata.tags = vec!["validation".to_string(), "report".to_string()];
+
+    template.input_schema = serde_json::json!({
+        "type": "object",
+        "required": ["is_valid", "issues", "metrics", "suggestions"],
+        "properties": {
+            "is_valid": {"type": "boolean"},
+            "issues": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["severity", "category", "message"]
+                }
+            },
+            "metrics": {"type": "object", "required": ["total_count"]},
+            "suggestions": {"type": "array", "items": {"type": "string"}}
+        }
+    });
+
+    template.output_schema.format = "markdown".to_string();
+    template.output_schema.structure =
+        "Markdown report with summary, issues and suggestions sections".to_string();
+
     template
 }
 
+/// Attach the path being read to an I/O error
+fn io_error_at(path: &Path, err: &std::io::Error) -> Error {
+    std::io::Error::new(err.kind(), format!("{}: {}", path.display(), err)).into()
+}
+
+/// Count the items produced by a generation for quota purposes
+fn count_generated_items(content: &str) -> usize {
+    count_primary_items(content, "todos").unwrap_or(1)
+}
+
+/// Output buffer that refuses writes past a byte limit
+struct CappedWriter {
+    buffer: Vec<u8>,
+    limit: usize,
+    /// Bytes written plus the rejected write, once the limit is crossed
+    attempted: usize,
+}
+
+impl std::io::Write for CappedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.attempted = self.buffer.len() + buf.len();
+        if self.attempted > self.limit {
+            return Err(std::io::Error::other("output size limit exceeded"));
+        }
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Count the items in the primary array of rendered output
+///
+/// The primary array is the document itself when it is a sequence, or the
+/// sequence stored under `key` when it is a mapping. Returns `None` when the
+/// output has no such array.
+fn count_primary_items(content: &str, key: &str) -> Option<usize> {
+    match serde_yaml::from_str::<serde_yaml::Value>(content) {
+        Ok(serde_yaml::Value::Sequence(items)) => Some(items.len()),
+        Ok(serde_yaml::Value::Mapping(mapping)) => match mapping.get(key)? {
+            serde_yaml::Value::Sequence(items) => Some(items.len()),
+            // An empty block (`todos:`) parses as null
+            serde_yaml::Value::Null => Some(0),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Check that `requirements`, when present, is an array of non-empty strings
+///
+/// Inputs built from raw JSON bypass `TodoInput`'s typing, so the offending
+/// index is reported before rendering.
+fn validate_requirement_entries(input: &serde_json::Value) -> Result<()> {
+    let Some(requirements) = input.get("requirements") else {
+        return Ok(());
+    };
+    let Some(entries) = requirements.as_array() else {
+        return Err(ValidationError::invalid_value(
+            "requirements".to_string(),
+            format!("expected an array of strings, found {}", requirements),
+        )
+        .into());
+    };
+
+    for (idx, entry) in entries.iter().enumerate() {
+        let reason = match entry {
+            serde_json::Value::String(text) if text.trim().is_empty() => {
+                "requirement must not be empty".to_string()
+            }
+            serde_json::Value::String(_) => continue,
+            other => format!("expected a string, found {}", other),
+        };
+        return Err(
+            ValidationError::invalid_value(format!("requirements[{}]", idx), reason).into(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Reject empty `requirements` up front when the output needs at least one item
+///
+/// Gives a clearer failure than the structure error the empty render would
+/// otherwise produce.
+fn ensure_requirements(rules: &StructureRules, input: &serde_json::Value) -> Result<()> {
+    let needs_items = rules.min_items.is_some_and(|min| min > 0);
+    let empty = input
+        .get("requirements")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(Vec::is_empty);
+
+    if needs_items && empty {
+        return Err(Error::invalid_input("requirements cannot be empty"));
+    }
+    Ok(())
+}
+
+/// Check rendered output against the template's structure rules
+///
+/// The output is parsed as YAML (which also accepts JSON) and checked with
+/// [`StructureValidator`]. Outputs that are not a mapping or sequence are
+/// not checked.
+fn validate_structure(rules: &StructureRules, content: &str, mode: ValidationMode) -> Result<()> {
+    let Ok(document @ (serde_json::Value::Object(_) | serde_json::Value::Array(_))) =
+        serde_yaml::from_str::<serde_json::Value>(content)
+    else {
+        return Ok(());
+    };
+
+    let violations =
+        mode.collect(StructureValidator::new(rules.clone()).iter_violations(&document));
+    if violations.is_empty() {
+        return Ok(());
+    }
+    let reasons: Vec<String> = violations.iter().map(ToString::to_string).collect();
+    Err(ValidationError::structure(reasons.join("; ")).into())
+}
+
+/// Reject output containing self-admitted technical debt markers
+fn validate_no_satd(content: &str, mode: ValidationMode) -> Result<()> {
+    let findings = mode.collect(iter_satd(content));
+    if findings.is_empty() {
+        return Ok(());
+    }
+    let details: Vec<String> = findings.iter().map(ToString::to_string).collect();
+    Err(ValidationError::Constraint {
+        constraint: "zero_satd".to_string(),
+        details: details.join("; "),
+    }
+    .into())
+}
+
+/// Validate rendered output against the template's output schema
+///
+/// JSON and YAML outputs must parse as their declared format; the parsed
+/// document is then checked against the schema when one is declared. Other
+/// formats are not checked.
+fn validate_output(
+    output_schema: &OutputSchema,
+    content: &str,
+    mode: ValidationMode,
+) -> Result<()> {
+    let document = match output_schema.format.as_str() {
+        "json" => serde_json::from_str::<serde_json::Value>(content).map_err(|e| e.to_string()),
+        "yaml" => serde_yaml::from_str::<serde_json::Value>(content).map_err(|e| e.to_string()),
+        _ => return Ok(()),
+    }
+    .map_err(|e| TemplateError::SchemaValidation {
+        errors: vec![format!(
+            "$: output is not valid {}: {}",
+            output_schema.format, e
+        )],
+    })?;
+
+    output_schema.schema.as_ref().map_or(Ok(()), |schema| {
+        schema::ensure_valid_with(schema, &document, mode)
+    })
+}
+
+/// Read the `todos` list of rendered YAML or JSON output into a todo list
+///
+/// Each entry is overlaid on the defaults of a fresh todo, so output only
+/// needs the fields it cares about. Returns `None` when the output has no
+/// `todos` list or an entry does not fit the todo model.
+#[cfg(feature = "todo-validation")]
+fn todo_list_from_output(content: &str) -> Option<crate::models::todo::TodoList> {
+    use crate::models::todo::{Todo, TodoList};
+
+    let document: serde_json::Value = serde_yaml::from_str(content).ok()?;
+    let entries = document.get("todos")?.as_array()?;
+
+    let mut list = TodoList::new();
+    for entry in entries {
+        let fields = entry.as_object()?;
+        let content = fields.get("content").and_then(|c| c.as_str()).unwrap_or("");
+        let mut todo = serde_json::to_value(Todo::new(content)).ok()?;
+        if let Some(defaults) = todo.as_object_mut() {
+            defaults.extend(fields.clone());
+        }
+        list.add_todo(serde_json::from_value(todo).ok()?);
+    }
+    list.update_metadata();
+    Some(list)
+}
+
 // Handlebars helper functions
 
 fn uppercase_helper(
@@ -340,22 +1259,1030 @@ mod tests {
 
         assert!(engine.templates.contains_key("todo_list"));
         assert!(engine.templates.contains_key("base"));
+        assert!(engine.templates.contains_key("validation_report"));
     }
 
     #[tokio::test]
-    async fn test_simple_template_generation() {
+    async fn test_deterministic_renders_do_not_diff() {
         let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        let input = json!({"project_name": "Demo", "requirements": ["login", "logout"]});
 
-        // Register a simple test template
-        let template = TemplateDefinition::new("test", "1.0.0", "Hello {{name}}!");
-        engine.register_template(template).unwrap();
+        let first = engine.generate("todo_list", &input).await.unwrap();
+        let second = engine.generate("todo_list", &input).await.unwrap();
+        assert!(first.diff(&second).is_none());
 
-        // Generate content
-        let input = json!({"name": "World"});
-        let result = engine.generate("test", input).await.unwrap();
+        let changed = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Demo", "requirements": ["login", "signup"]}),
+            )
+            .await
+            .unwrap();
+        let diff = first.diff(&changed).unwrap();
+        assert_eq!(diff.lines.len(), 2);
+        let rendered = diff.to_string();
+        assert!(rendered.contains("content: \"Implement logout\""));
+        assert!(rendered.contains("content: \"Implement signup\""));
+    }
 
-        assert_eq!(result.content, "Hello World!");
-        assert_eq!(result.template_id, "test");
+    #[tokio::test]
+    async fn test_input_schema_defaults_applied() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+
+        let defaulted = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Demo", "requirements": ["login"]}),
+            )
+            .await
+            .unwrap();
+        assert!(defaulted.content.contains("# Granularity: high\n"));
+        assert_eq!(defaulted.input_data["granularity"], "high");
+
+        let explicit = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Demo", "requirements": ["login"], "granularity": "low"}),
+            )
+            .await
+            .unwrap();
+        assert!(explicit.content.contains("# Granularity: low\n"));
+    }
+
+    #[cfg(feature = "todo-validation")]
+    #[tokio::test]
+    async fn test_validation_report_template() {
+        use crate::models::todo::{Todo, TodoList};
+        use crate::validators::todo::TodoValidator;
+
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("stuff");
+        todo.id = "vague".to_string();
+        list.add_todo(todo);
+        let result = TodoValidator::new().validate_todo_list(&list);
+
+        let report = engine
+            .generate("validation_report", &result)
+            .await
+            .unwrap()
+            .content;
+
+        assert!(report.starts_with("# Validation Report\n\n## Summary\n"));
+        assert!(report.contains("- **Status**: failed"));
+        assert!(report.contains("- **Total todos**: 1"));
+        assert!(report.contains(&format!("- **Issues found**: {}", result.issues.len())));
+        assert!(report.contains("\n## Issues\n"));
+        assert!(report.contains("- **Error** [Actionability] `vague`: "));
+        assert!(report.contains("\n## Suggestions\n"));
+        assert!(!report.contains("&#x27;"));
+
+        // A stored result renders the same report
+        let stored = serde_json::to_string(&result).unwrap();
+        let restored: crate::validators::todo::TodoValidationResult =
+            serde_json::from_str(&stored).unwrap();
+        let rerendered = engine
+            .generate("validation_report", &restored)
+            .await
+            .unwrap()
+            .content;
+        assert_eq!(rerendered, report);
+
+        let mut clean = result.clone();
+        clean.issues.clear();
+        clean.suggestions.clear();
+        let report = engine
+            .generate("validation_report", &clean)
+            .await
+            .unwrap()
+            .content;
+        assert!(report.contains("No issues found."));
+        assert!(report.contains("No suggestions."));
+    }
+
+    #[tokio::test]
+    async fn test_load_templates_from_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        for (file, id) in [("greet.yaml", "greet"), ("farewell.yml", "farewell")] {
+            let template = TemplateDefinition::new(id, "1.0.0", "Hello {{name}}!");
+            std::fs::write(
+                dir.path().join(file),
+                serde_yaml::to_string(&template).unwrap(),
+            )
+            .unwrap();
+        }
+        std::fs::write(dir.path().join("notes.txt"), "not a template").unwrap();
+        std::fs::create_dir(dir.path().join("nested.yaml")).unwrap();
+
+        let mut engine = TemplateEngine::new();
+        let loaded = engine.load_templates_from_dir(dir.path()).await.unwrap();
+
+        assert_eq!(loaded, 2);
+        assert!(engine.get_template("greet").is_some());
+        let result = engine
+            .generate("farewell", json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Hello Ada!");
+    }
+
+    #[tokio::test]
+    async fn test_load_templates_from_dir_strips_bom() {
+        let dir = tempfile::tempdir().unwrap();
+        let template = TemplateDefinition::new("bom", "1.0.0", "Hello {{name}}!");
+        let source = format!("\u{feff}{}", serde_yaml::to_string(&template).unwrap());
+        std::fs::write(dir.path().join("bom.yaml"), source).unwrap();
+
+        let mut engine = TemplateEngine::new();
+        assert_eq!(engine.load_templates_from_dir(dir.path()).await.unwrap(), 1);
+
+        let result = engine
+            .generate("bom", json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Hello Ada!");
+    }
+
+    #[tokio::test]
+    async fn test_load_templates_from_dir_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("broken.yaml"), "id: [unclosed").unwrap();
+
+        let err = TemplateEngine::new()
+            .load_templates_from_dir(dir.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("broken.yaml"));
+
+        let dir = tempfile::tempdir().unwrap();
+        let oversized = std::fs::File::create(dir.path().join("huge.yaml")).unwrap();
+        oversized
+            .set_len(crate::MAX_TEMPLATE_SIZE as u64 + 1)
+            .unwrap();
+
+        let err = TemplateEngine::new()
+            .load_templates_from_dir(dir.path())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Template(TemplateError::InvalidDefinition { .. })
+        ));
+        assert!(err.to_string().contains("huge.yaml"));
+        assert!(err
+            .to_string()
+            .contains(&format!("exceeds limit {}", crate::MAX_TEMPLATE_SIZE)));
+
+        let err = TemplateEngine::new()
+            .load_templates_from_dir(dir.path().join("missing"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Io(_)));
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_inheritance() {
+        let mut engine = TemplateEngine::new();
+
+        let mut base = TemplateDefinition::new("base_greeting", "1.0.0", "Hi {{name}}");
+        base.metadata
+            .parameters
+            .insert("tone".to_string(), json!("formal"));
+        base.input_schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {"name": {"type": "string"}}
+        });
+        let mut middle = TemplateDefinition::new("friendly", "1.0.0", "Hey {{name}}");
+        middle.extends = Some("base_greeting".to_string());
+        middle
+            .metadata
+            .parameters
+            .insert("tone".to_string(), json!("casual"));
+        let mut leaf = TemplateDefinition::new("leaf", "1.0.0", "Yo {{name}}");
+        leaf.extends = Some("friendly".to_string());
+
+        engine.register_template(base).unwrap();
+        engine.register_template(middle).unwrap();
+        engine.register_template(leaf).unwrap();
+
+        let resolved = engine.resolve_inheritance("leaf").unwrap();
+        assert_eq!(resolved.id, "leaf");
+        assert!(resolved.extends.is_none());
+        assert_eq!(resolved.metadata.parameters["tone"], json!("casual"));
+        assert_eq!(resolved.input_schema["required"], json!(["name"]));
+
+        // Generation enforces the inherited input schema
+        let err = engine.generate("leaf", json!({})).await.unwrap_err();
+        assert!(err.to_string().contains("missing required property 'name'"));
+    }
+
+    #[test]
+    fn test_resolve_inheritance_errors() {
+        let mut engine = TemplateEngine::new();
+
+        let mut orphan = TemplateDefinition::new("orphan", "1.0.0", "Hello");
+        orphan.extends = Some("missing".to_string());
+        engine.register_template(orphan).unwrap();
+
+        let err = engine.resolve_inheritance("orphan").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Template(TemplateError::InheritanceError { .. })
+        ));
+        assert!(err.to_string().contains("unregistered template 'missing'"));
+
+        for (id, parent) in [("a", "b"), ("b", "a")] {
+            let mut template = TemplateDefinition::new(id, "1.0.0", "Hello");
+            template.extends = Some(parent.to_string());
+            engine.register_template(template).unwrap();
+        }
+        let err = engine.resolve_inheritance("a").unwrap_err();
+        assert!(err.to_string().contains("Inheritance cycle: a -> b -> a"));
+    }
+
+    #[tokio::test]
+    async fn test_simple_template_generation() {
+        let mut engine = TemplateEngine::new();
+
+        // Register a simple test template
+        let template = TemplateDefinition::new("test", "1.0.0", "Hello {{name}}!");
+        engine.register_template(template).unwrap();
+
+        // Generate content
+        let input = json!({"name": "World"});
+        let result = engine.generate("test", input).await.unwrap();
+
+        assert_eq!(result.content, "Hello World!");
+        assert_eq!(result.template_id, "test");
+    }
+
+    #[tokio::test]
+    async fn test_generate_validates_schemas() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+
+        let err = engine
+            .generate("todo_list", json!({"project_name": "Schema"}))
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required property 'requirements'"));
+
+        let long_requirement = "x".repeat(120);
+        let err = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Schema", "requirements": [long_requirement]}),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("$.todos[0].content"));
+    }
+
+    #[tokio::test]
+    async fn test_post_processors_applied() {
+        #[derive(Debug)]
+        struct Uppercase;
+
+        impl PostProcessor for Uppercase {
+            fn name(&self) -> &'static str {
+                "uppercase"
+            }
+
+            fn process(&self, content: &mut String) -> Result<()> {
+                *content = content.to_uppercase();
+                Ok(())
+            }
+        }
+
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new("test", "1.0.0", "Hello {{name}}!"))
+            .unwrap();
+        engine.add_post_processor(Box::new(Uppercase));
+
+        let result = engine
+            .generate("test", json!({"name": "World"}))
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "HELLO WORLD!");
+        assert_eq!(result.metadata.post_processors, vec!["uppercase"]);
+    }
+
+    #[tokio::test]
+    async fn test_render_to_writer_matches_generate() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        let input = json!({"project_name": "Demo", "requirements": ["login", "logout"]});
+
+        let mut buffer = Vec::new();
+        engine
+            .render_to_writer("todo_list", &input, &mut buffer)
+            .unwrap();
+        let generated = engine.generate("todo_list", &input).await.unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), generated.content);
+
+        let err = engine
+            .render_to_writer("todo_list", &json!({}), &mut Vec::new())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Template(TemplateError::SchemaValidation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_zero_satd_gate() {
+        use crate::template::definition::QualityGateRules;
+
+        let mut template = TemplateDefinition::new("test", "1.0.0", "{{note}}");
+        template.validation.quality_gates = Some(QualityGateRules {
+            zero_satd: true,
+            ..QualityGateRules::default()
+        });
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        let err = engine
+            .generate("test", json!({"note": "Ship it\n# FIXME: add retries"}))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Validation(ValidationError::Constraint { ref constraint, .. })
+                if constraint == "zero_satd"
+        ));
+        assert!(err.to_string().contains("FIXME at line 2, column 3"));
+
+        assert!(engine
+            .generate("test", json!({"note": "Build the TODOlist view"}))
+            .await
+            .is_ok());
+    }
+
+    #[cfg(feature = "schema-validation")]
+    #[test]
+    fn test_invalid_input_schema_rejected_at_registration() {
+        let mut template = TemplateDefinition::new("test", "1.0.0", "{{name}}");
+        template.input_schema = json!({
+            "type": "object",
+            "properties": {"name": {"type": "string", "pattern": "("}}
+        });
+
+        let mut engine = TemplateEngine::new();
+        let err = engine.register_template(template).unwrap_err();
+        assert!(err.to_string().contains("Invalid input schema"));
+        assert!(engine.get_template("test").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_custom_validators_run() {
+        #[derive(Debug)]
+        struct ProviderNeedsKey;
+
+        impl TemplateValidator for ProviderNeedsKey {
+            fn validate(&self, input: &serde_json::Value, _output: &str) -> Result<()> {
+                if input["provider"] == "anthropic" && input.get("api_key").is_none() {
+                    return Err(Error::invalid_input(
+                        "provider 'anthropic' requires an api_key",
+                    ));
+                }
+                Ok(())
+            }
+        }
+
+        let mut template = TemplateDefinition::new("test", "1.0.0", "Using {{provider}}");
+        template.validation.custom_validators = vec!["provider_needs_key".to_string()];
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        let err = engine
+            .generate("test", json!({"provider": "local"}))
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("no validator registered as 'provider_needs_key'"));
+
+        engine.register_validator("provider_needs_key", Box::new(ProviderNeedsKey));
+
+        let err = engine
+            .generate("test", json!({"provider": "anthropic"}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("requires an api_key"));
+
+        let result = engine
+            .generate("test", json!({"provider": "anthropic", "api_key": "k"}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Using anthropic");
+        assert!(engine
+            .generate("test", json!({"provider": "local"}))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_generation_quota() {
+        let mut engine = TemplateEngine::new().with_generation_quota(3);
+        engine.load_builtin_templates().await.unwrap();
+
+        let input = json!({
+            "project_name": "Quota",
+            "requirements": ["login", "logout"]
+        });
+
+        engine.generate("todo_list", &input).await.unwrap();
+        assert_eq!(engine.generated_items(), 2);
+
+        let err = engine.generate("todo_list", &input).await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Template(TemplateError::QuotaExceeded {
+                used: 2,
+                requested: 2,
+                limit: 3
+            })
+        ));
+        assert_eq!(engine.generated_items(), 2);
+
+        let single = json!({"project_name": "Quota", "requirements": ["audit"]});
+        engine.generate("todo_list", &single).await.unwrap();
+        assert!(engine.generate("todo_list", &single).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_non_string_requirements_rejected() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+
+        let err = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Raw", "requirements": ["login", 42]}),
+            )
+            .await
+            .unwrap_err();
+        match err {
+            Error::Validation(ValidationError::InvalidValue { field, reason }) => {
+                assert_eq!(field, "requirements[1]");
+                assert_eq!(reason, "expected a string, found 42");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let err = engine
+            .generate(
+                "todo_list",
+                json!({"project_name": "Raw", "requirements": ["login", "  "]}),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'requirements[1]'"));
+    }
+
+    #[tokio::test]
+    async fn test_output_size_cap() {
+        let mut engine = TemplateEngine::new().with_max_output_bytes(64);
+        engine
+            .register_template(TemplateDefinition::new(
+                "repeat",
+                "1.0.0",
+                "{{#each items}}- {{this}}\n{{/each}}",
+            ))
+            .unwrap();
+
+        assert!(engine
+            .generate("repeat", json!({"items": ["a", "b"]}))
+            .await
+            .is_ok());
+
+        let items: Vec<String> = (0..1000).map(|i| format!("item {}", i)).collect();
+        let err = engine
+            .generate("repeat", json!({ "items": items }))
+            .await
+            .unwrap_err();
+        match err {
+            Error::Template(TemplateError::SizeLimit { size, limit }) => {
+                assert_eq!(limit, 64);
+                assert!(size > 64 && size < 128);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retemplate_reuses_input() {
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new(
+                "plain",
+                "1.0.0",
+                "{{#each tasks}}{{this}};{{/each}}",
+            ))
+            .unwrap();
+        engine
+            .register_template(TemplateDefinition::new(
+                "bullets",
+                "1.0.0",
+                "{{#each tasks}}- {{this}}\n{{/each}}",
+            ))
+            .unwrap();
+
+        let input = json!({"tasks": ["Write spec", "Ship it"]});
+        let plain = engine.generate("plain", &input).await.unwrap();
+        let bullets = engine.retemplate(&plain, "bullets").await.unwrap();
+
+        assert_eq!(bullets.template_id, "bullets");
+        assert_eq!(bullets.content, "- Write spec\n- Ship it\n");
+        assert_eq!(bullets.input_data, input);
+
+        assert!(engine.retemplate(&plain, "missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_global_context() {
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new(
+                "greeting",
+                "1.0.0",
+                "Hello {{name}} from {{_ctx.org}} ({{_ctx.region}})",
+            ))
+            .unwrap();
+        engine.set_global_context(json!({"org": "Acme", "region": "eu"}));
+
+        let result = engine
+            .generate("greeting", json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Hello Ada from Acme (eu)");
+        assert!(result.input_data.get(GLOBAL_CONTEXT_KEY).is_none());
+
+        // Per-call values win on collisions
+        let result = engine
+            .generate("greeting", json!({"name": "Ada", "_ctx": {"region": "us"}}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Hello Ada from Acme (us)");
+    }
+
+    #[tokio::test]
+    async fn test_undeclared_format_is_not_parsed() {
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new(
+                "notes",
+                "1.0.0",
+                "Note: {{topic}}: see below\n- [ ] follow up",
+            ))
+            .unwrap();
+
+        let result = engine
+            .generate("notes", json!({"topic": "release"}))
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Note: release: see below\n- [ ] follow up");
+    }
+
+    #[tokio::test]
+    async fn test_output_must_match_declared_format() {
+        let mut template = TemplateDefinition::new("mislabeled", "1.0.0", "name: {{name}}\n");
+        template.output_schema.format = "json".to_string();
+
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        let err = engine
+            .generate("mislabeled", json!({"name": "Ada"}))
+            .await
+            .unwrap_err();
+        match err {
+            Error::Template(TemplateError::SchemaValidation { errors }) => {
+                assert!(errors[0].starts_with("$: output is not valid json"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let mut template = TemplateDefinition::new("labeled", "1.0.0", r#"{"name": "{{name}}"}"#);
+        template.output_schema.format = "json".to_string();
+        engine.register_template(template).unwrap();
+        assert!(engine
+            .generate("labeled", json!({"name": "Ada"}))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_structure_rules_enforced() {
+        let mut template = TemplateDefinition::new(
+            "capped",
+            "1.0.0",
+            "todos:\n{{#each items}}  - content: {{this}}\n{{/each}}",
+        );
+        template.validation.structure_rules = Some(StructureRules {
+            max_items: Some(2),
+            min_items: Some(1),
+            ..StructureRules::default()
+        });
+
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        assert!(engine
+            .generate("capped", json!({"items": ["a", "b"]}))
+            .await
+            .is_ok());
+
+        let err = engine
+            .generate("capped", json!({"items": ["a", "b", "c"]}))
+            .await
+            .unwrap_err();
+        match err {
+            Error::Validation(ValidationError::StructureError { reason }) => {
+                assert!(reason.contains("exceeding max_items 2"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let err = engine
+            .generate("capped", json!({"items": []}))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("below min_items 1"));
+    }
+
+    #[tokio::test]
+    async fn test_structure_rules_check_elements() {
+        let mut template = TemplateDefinition::new(
+            "report",
+            "1.0.0",
+            "{{#if summary}}summary: {{summary}}\n{{/if}}{{#if debug}}debug: true\n{{/if}}todos:\n  - content: a\n",
+        );
+        template.validation.structure_rules = Some(StructureRules {
+            required_elements: vec!["todos".to_string(), "summary".to_string()],
+            forbidden_elements: vec!["debug".to_string()],
+            ..StructureRules::default()
+        });
+
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        assert!(engine
+            .generate("report", json!({"summary": "ok"}))
+            .await
+            .is_ok());
+
+        let err = engine.generate("report", json!({})).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required element 'summary'"));
+
+        let err = engine
+            .generate("report", json!({"summary": "ok", "debug": true}))
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("contains forbidden element 'debug'"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_batch() {
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new("greet", "1.0.0", "Hello {{name}}!"))
+            .unwrap();
+
+        let inputs = vec![json!({"name": "Ada"}), json!({"name": "Grace"})];
+        let results = engine
+            .generate_batch("greet", inputs.clone())
+            .await
+            .unwrap();
+        let contents: Vec<&str> = results.iter().map(|g| g.content.as_str()).collect();
+        assert_eq!(contents, vec!["Hello Ada!", "Hello Grace!"]);
+        assert_eq!(results[1].input_data, json!({"name": "Grace"}));
+
+        // Same output as generating each input on its own
+        for (input, batched) in inputs.iter().zip(&results) {
+            let single = engine.generate("greet", input).await.unwrap();
+            assert_eq!(single.content, batched.content);
+            assert_eq!(single.input_data, batched.input_data);
+        }
+
+        let err = engine
+            .generate_batch("missing", vec![json!({"name": "Ada"})])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Template(TemplateError::NotFound { .. })
+        ));
+        assert!(engine
+            .generate_batch("greet", Vec::<serde_json::Value>::new())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validation_mode() {
+        let mut template = TemplateDefinition::new("greeting", "1.0.0", "Hello {{name}}");
+        template.input_schema = json!({
+            "type": "object",
+            "required": ["name", "team"],
+            "properties": {"count": {"type": "integer"}}
+        });
+        let mut engine = TemplateEngine::new();
+        engine.register_template(template).unwrap();
+
+        let input = json!({"count": "three"});
+        let schema_errors = |err: Error| match err {
+            Error::Template(TemplateError::SchemaValidation { errors }) => errors,
+            other => panic!("unexpected error: {other:?}"),
+        };
+
+        let err = engine.generate("greeting", &input).await.unwrap_err();
+        assert_eq!(schema_errors(err).len(), 3);
+
+        engine.set_validation_mode(ValidationMode::FailFast);
+        let err = engine.generate("greeting", &input).await.unwrap_err();
+        assert_eq!(
+            schema_errors(err),
+            vec!["$: missing required property 'name'"]
+        );
+    }
+
+    #[cfg(feature = "todo-validation")]
+    #[tokio::test]
+    async fn test_generate_as_typed_output() {
+        use crate::models::todo::{TodoList, TodoPriority, TodoStatus};
+
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        let input = json!({
+            "project_name": "Shop",
+            "requirements": ["checkout flow", "order history"]
+        });
+
+        let list: TodoList = engine.generate_as("todo_list", &input).await.unwrap();
+        assert_eq!(list.todos.len(), 2);
+        assert_eq!(list.todos[0].id, "todo_0");
+        assert_eq!(list.todos[1].content, "Implement order history");
+        assert_eq!(list.todos[1].status, TodoStatus::Pending);
+        assert_eq!(list.todos[1].priority, TodoPriority::Medium);
+        assert_eq!(list.todos[1].estimated_hours, Some(4.0));
+        assert_eq!(list.todos[1].tags, vec!["implementation"]);
+
+        // Output that does not fit the target type is a serialization error
+        let err = engine
+            .generate_as::<Vec<String>, _>("todo_list", &input)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Serialization(_)));
+    }
+
+    #[tokio::test]
+    async fn test_generate_with_progress() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        let input = json!({
+            "project_name": "Shop",
+            "requirements": ["Add checkout flow"]
+        });
+
+        let mut updates = Vec::new();
+        let generated = engine
+            .generate_with_progress("todo_list", &input, |progress| updates.push(progress))
+            .await
+            .unwrap();
+        assert!(generated.content.contains("Implement Add checkout flow"));
+
+        let phases: Vec<GenerationPhase> = updates.iter().map(|u| u.phase).collect();
+        assert_eq!(
+            phases,
+            vec![
+                GenerationPhase::TemplateResolved,
+                GenerationPhase::Rendered,
+                GenerationPhase::Validated,
+                GenerationPhase::Complete,
+            ]
+        );
+        assert!(updates.windows(2).all(|w| w[0].elapsed <= w[1].elapsed));
+
+        // A failing render stops after the template is resolved
+        let mut phases = Vec::new();
+        let result = engine
+            .generate_with_progress("todo_list", json!({}), |progress| {
+                phases.push(progress.phase);
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(phases, vec![GenerationPhase::TemplateResolved]);
+    }
+
+    #[tokio::test]
+    async fn test_templates_include_partials() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+
+        // The base template renders on its own with an empty content block
+        let base = engine.generate("base", &json!({})).await.unwrap();
+        assert_eq!(base.content, "# Base template for inheritance\n");
+
+        // A child fills the block inline and includes the base by its ID
+        engine
+            .register_template(TemplateDefinition::new(
+                "page",
+                "1.0.0",
+                "{{#*inline \"content\"}}Hello {{name}}{{/inline}}{{> base}}",
+            ))
+            .unwrap();
+        let page = engine
+            .generate("page", &json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(page.content, "# Base template for inheritance\nHello Ada");
+
+        // A registered partial fills the block for every template
+        engine
+            .register_partial("content", "Shared {{name}}")
+            .unwrap();
+        engine
+            .register_template(TemplateDefinition::new("shared", "1.0.0", "{{> base}}"))
+            .unwrap();
+        let shared = engine
+            .generate("shared", &json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(
+            shared.content,
+            "# Base template for inheritance\nShared Ada"
+        );
+    }
+
+    #[cfg(feature = "todo-validation")]
+    #[tokio::test]
+    async fn test_generate_embeds_todo_validation() {
+        use crate::validators::todo::IssueSeverity;
+
+        let mut engine = TemplateEngine::new();
+        engine
+            .register_template(TemplateDefinition::new(
+                "sprint",
+                "1.0.0",
+                "todos:\n  - id: \"checkout\"\n    content: \"Implement checkout flow\"\n    estimated_hours: 4.0\n  - id: \"vague\"\n    content: \"{{task}}\"\n",
+            ))
+            .unwrap();
+        let input = json!({"task": "stuff"});
+
+        let generated = engine.generate("sprint", &input).await.unwrap();
+        assert!(generated.validation.is_none());
+
+        engine.set_validate_todos(true);
+        let generated = engine.generate("sprint", &input).await.unwrap();
+        let validation = generated.validation.unwrap();
+        assert!(!validation.is_valid);
+        assert_eq!(validation.metrics.total_count, 2);
+        let has_error = |id: &str| {
+            validation.issues.iter().any(|issue| {
+                issue.severity == IssueSeverity::Error && issue.todo_id.as_deref() == Some(id)
+            })
+        };
+        assert!(has_error("vague"));
+        assert!(!has_error("checkout"));
+
+        // Output without a todo list is left unvalidated
+        engine
+            .register_template(TemplateDefinition::new("note", "1.0.0", "Hello {{task}}"))
+            .unwrap();
+        let generated = engine.generate("note", &input).await.unwrap();
+        assert!(generated.validation.is_none());
+    }
+
+    #[cfg(feature = "todo-validation")]
+    #[tokio::test]
+    async fn test_deterministic_ids() {
+        let mut engine = TemplateEngine::new();
+        engine.load_builtin_templates().await.unwrap();
+        let input = json!({
+            "project_name": "Shop",
+            "requirements": ["Add checkout flow", "Send receipts"]
+        });
+
+        let first = engine.generate("todo_list", &input).await.unwrap();
+        let second = engine.generate("todo_list", &input).await.unwrap();
+        assert_ne!(first.id, second.id);
+
+        engine.set_deterministic_ids(true);
+        let first = engine.generate("todo_list", &input).await.unwrap();
+        let second = engine.generate("todo_list", &input).await.unwrap();
+        assert_eq!(first.id, second.id);
+        assert_eq!(first.content, second.content);
+        assert!(uuid::Uuid::parse_str(&first.id).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_template_cache_reuses_unchanged_body() {
+        let mut engine = TemplateEngine::new();
+        let template = TemplateDefinition::new("greet", "1.0.0", "Hello {{name}}!");
+
+        engine.register_template(template.clone()).unwrap();
+        engine.register_template(template.clone()).unwrap();
+        assert_eq!(
+            engine.template_cache_stats(),
+            TemplateCacheStats { hits: 1, misses: 1 }
+        );
+
+        let mut updated = template;
+        updated.prompt_template = "Hi {{name}}!".to_string();
+        engine.register_template(updated).unwrap();
+        assert_eq!(engine.template_cache_stats().misses, 2);
+
+        let generated = engine
+            .generate("greet", json!({"name": "Ada"}))
+            .await
+            .unwrap();
+        assert_eq!(generated.content, "Hi Ada!");
+    }
+
+    #[test]
+    fn test_reject_nonreproducible_templates() {
+        let mut engine = TemplateEngine::new();
+        let template = TemplateDefinition::new("stamped", "1.0.0", "Generated at {{now}}");
+
+        // Allowed by default
+        assert!(engine.register_template(template.clone()).is_ok());
+
+        let mut engine = TemplateEngine::new();
+        engine.set_reject_nonreproducible(true);
+        let err = engine.register_template(template).unwrap_err();
+        assert!(err.to_string().contains("now"));
+        assert!(engine.get_template("stamped").is_none());
+
+        let template = TemplateDefinition::new("plain", "1.0.0", "Hello {{name}}!");
+        assert!(engine.register_template(template).is_ok());
+    }
+
+    fn reverse_helper(
+        h: &handlebars::Helper,
+        _: &Handlebars,
+        _: &handlebars::Context,
+        _: &mut handlebars::RenderContext,
+        out: &mut dyn handlebars::Output,
+    ) -> handlebars::HelperResult {
+        let param = h.param(0).and_then(|v| v.value().as_str()).unwrap_or("");
+        out.write(&param.chars().rev().collect::<String>())?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_custom_helper() {
+        let mut engine = TemplateEngine::new();
+        engine.register_helper("reverse", Box::new(reverse_helper));
+        engine
+            .register_template(TemplateDefinition::new(
+                "mirror",
+                "1.0.0",
+                "{{reverse name}} {{upper name}}",
+            ))
+            .unwrap();
+
+        let generated = engine
+            .generate("mirror", json!({"name": "pdmt"}))
+            .await
+            .unwrap();
+        assert_eq!(generated.content, "tmdp PDMT");
+    }
+
+    #[test]
+    fn test_register_helper_checked_protects_builtins() {
+        let mut engine = TemplateEngine::new();
+        assert!(engine
+            .register_helper_checked("upper", Box::new(reverse_helper))
+            .is_ok());
+
+        let mut engine = TemplateEngine::new();
+        engine.set_reject_nonreproducible(true);
+        let err = engine
+            .register_helper_checked("upper", Box::new(reverse_helper))
+            .unwrap_err();
+        assert!(err.to_string().contains("built-in"));
+        assert!(engine
+            .register_helper_checked("each", Box::new(reverse_helper))
+            .is_err());
+        assert!(engine
+            .register_helper_checked("reverse", Box::new(reverse_helper))
+            .is_ok());
     }
 
     #[test]
@@ -1,3 +1,163 @@
 //! Template inheritance system
+//!
+//! Flattens a template that `extends` a parent into a single definition.
+//! Child values override the parent's: scalars come from the child, optional
+//! settings fall back to the parent when the child leaves them unset, maps are
+//! overlaid and lists are concatenated without duplicates.
 
-// Placeholder for template inheritance functionality
+use crate::template::definition::{OutputSchema, TemplateDefinition, ValidationRules};
+use serde_json::Value;
+
+/// Merge a child template over its parent
+///
+/// The result carries the child's identity and body and has no `extends`.
+pub fn merge_definitions(
+    parent: &TemplateDefinition,
+    child: &TemplateDefinition,
+) -> TemplateDefinition {
+    let mut merged = child.clone();
+    merged.extends = None;
+
+    let mut parameters = parent.metadata.parameters.clone();
+    parameters.extend(child.metadata.parameters.clone());
+    merged.metadata.parameters = parameters;
+    merged.metadata.tags = union(&parent.metadata.tags, &child.metadata.tags);
+    merged.metadata.author = child
+        .metadata
+        .author
+        .clone()
+        .or_else(|| parent.metadata.author.clone());
+
+    merged.input_schema = merge_input_schema(&parent.input_schema, &child.input_schema);
+    merged.output_schema = merge_output_schema(&parent.output_schema, &child.output_schema);
+    merged.validation = merge_validation(&parent.validation, &child.validation);
+    merged.required_parameters = union(&parent.required_parameters, &child.required_parameters);
+
+    #[cfg(feature = "quality-proxy")]
+    {
+        merged.quality_enforcement = child
+            .quality_enforcement
+            .clone()
+            .or_else(|| parent.quality_enforcement.clone());
+    }
+
+    merged
+}
+
+fn merge_output_schema(parent: &OutputSchema, child: &OutputSchema) -> OutputSchema {
+    OutputSchema {
+        format: child.format.clone(),
+        structure: child.structure.clone(),
+        schema: child.schema.clone().or_else(|| parent.schema.clone()),
+        example: child.example.clone().or_else(|| parent.example.clone()),
+    }
+}
+
+fn merge_validation(parent: &ValidationRules, child: &ValidationRules) -> ValidationRules {
+    ValidationRules {
+        deterministic_only: child.deterministic_only,
+        required_fields: union(&parent.required_fields, &child.required_fields),
+        optional_fields: union(&parent.optional_fields, &child.optional_fields),
+        quality_gates: child
+            .quality_gates
+            .clone()
+            .or_else(|| parent.quality_gates.clone()),
+        structure_rules: child
+            .structure_rules
+            .clone()
+            .or_else(|| parent.structure_rules.clone()),
+        custom_validators: union(&parent.custom_validators, &child.custom_validators),
+        min_length: child.min_length.or(parent.min_length),
+        max_length: child.max_length.or(parent.max_length),
+    }
+}
+
+/// Overlay the child's input schema keys, merging `properties` and `required`
+fn merge_input_schema(parent: &Value, child: &Value) -> Value {
+    let (Value::Object(parent_map), Value::Object(child_map)) = (parent, child) else {
+        return child.clone();
+    };
+
+    let mut merged = parent_map.clone();
+    for (key, value) in child_map {
+        let combined = match (key.as_str(), merged.get(key), value) {
+            ("properties", Some(Value::Object(inherited)), Value::Object(own)) => {
+                let mut properties = inherited.clone();
+                properties.extend(own.clone());
+                Value::Object(properties)
+            }
+            ("required", Some(Value::Array(inherited)), Value::Array(own)) => {
+                Value::Array(union(inherited, own))
+            }
+            _ => value.clone(),
+        };
+        merged.insert(key.clone(), combined);
+    }
+    Value::Object(merged)
+}
+
+/// Parent items followed by new child items, without duplicates
+fn union<T: Clone + PartialEq>(parent: &[T], child: &[T]) -> Vec<T> {
+    let mut combined = parent.to_vec();
+    for item in child {
+        if !combined.contains(item) {
+            combined.push(item.clone());
+        }
+    }
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template::definition::StructureRules;
+    use serde_json::json;
+
+    #[test]
+    fn test_child_overrides_parent() {
+        let mut parent = TemplateDefinition::new("base", "1.0.0", "Parent {{name}}");
+        parent
+            .metadata
+            .parameters
+            .insert("max_tokens".to_string(), json!(100));
+        parent.metadata.tags = vec!["shared".to_string()];
+        parent.input_schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {"name": {"type": "string"}}
+        });
+        parent.validation.required_fields = vec!["todos".to_string()];
+        parent.validation.structure_rules = Some(StructureRules::default());
+        parent.output_schema.example = Some("todos: []".to_string());
+
+        let mut child = TemplateDefinition::new("child", "2.0.0", "Child {{name}}");
+        child.extends = Some("base".to_string());
+        child
+            .metadata
+            .parameters
+            .insert("max_tokens".to_string(), json!(50));
+        child.metadata.tags = vec!["child".to_string(), "shared".to_string()];
+        child.input_schema = json!({
+            "type": "object",
+            "required": ["count"],
+            "properties": {"count": {"type": "integer"}}
+        });
+        child.validation.required_fields = vec!["metadata".to_string()];
+        child.validation.min_length = None;
+
+        let merged = merge_definitions(&parent, &child);
+
+        assert_eq!(merged.id, "child");
+        assert_eq!(merged.prompt_template, "Child {{name}}");
+        assert!(merged.extends.is_none());
+        assert_eq!(merged.metadata.parameters["max_tokens"], json!(50));
+        assert_eq!(merged.metadata.parameters["temperature"], json!(0.0));
+        assert_eq!(merged.metadata.tags, vec!["shared", "child"]);
+        assert_eq!(merged.input_schema["required"], json!(["name", "count"]));
+        assert!(merged.input_schema["properties"]["name"].is_object());
+        assert_eq!(merged.validation.required_fields, vec!["todos", "metadata"]);
+        assert!(merged.validation.structure_rules.is_some());
+        assert_eq!(merged.validation.min_length, parent.validation.min_length);
+        assert_eq!(merged.output_schema.example.as_deref(), Some("todos: []"));
+    }
+}
@@ -0,0 +1,193 @@
+//! Template bundles
+//!
+//! Packages several templates and the partials they share into one
+//! versioned YAML file, so a team can distribute a standard template set.
+
+use crate::error::{Result, TemplateError};
+use crate::template::definition::TemplateDefinition;
+use crate::template::engine::TemplateEngine;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// A versioned bundle of templates and partials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateManifest {
+    /// Version of the bundle (semantic versioning)
+    pub version: String,
+
+    /// Templates in the bundle
+    pub templates: Vec<TemplateDefinition>,
+
+    /// Partials shared by the templates, as `(name, body)` pairs
+    #[serde(default)]
+    pub partials: Vec<(String, String)>,
+}
+
+impl TemplateManifest {
+    /// Create an empty manifest
+    pub fn new<S: Into<String>>(version: S) -> Self {
+        Self {
+            version: version.into(),
+            templates: Vec::new(),
+            partials: Vec::new(),
+        }
+    }
+
+    /// Add a template
+    #[must_use]
+    pub fn with_template(mut self, template: TemplateDefinition) -> Self {
+        self.templates.push(template);
+        self
+    }
+
+    /// Add a partial
+    #[must_use]
+    pub fn with_partial<S: Into<String>>(mut self, name: S, body: S) -> Self {
+        self.partials.push((name.into(), body.into()));
+        self
+    }
+
+    /// Check the version, every template and every partial
+    ///
+    /// Template IDs and partial names must be unique and partial bodies must
+    /// compile.
+    pub fn validate(&self) -> Result<()> {
+        if self.version.trim().is_empty() {
+            return Err(
+                TemplateError::invalid_definition("Manifest version cannot be empty").into(),
+            );
+        }
+
+        let mut ids = HashSet::new();
+        for template in &self.templates {
+            template.validate()?;
+            if !ids.insert(template.id.as_str()) {
+                return Err(TemplateError::invalid_definition(format!(
+                    "Manifest contains template '{}' more than once",
+                    template.id
+                ))
+                .into());
+            }
+        }
+
+        let mut names = HashSet::new();
+        for (name, body) in &self.partials {
+            if name.trim().is_empty() {
+                return Err(
+                    TemplateError::invalid_definition("Partial name cannot be empty").into(),
+                );
+            }
+            if !names.insert(name.as_str()) {
+                return Err(TemplateError::invalid_definition(format!(
+                    "Manifest contains partial '{}' more than once",
+                    name
+                ))
+                .into());
+            }
+            handlebars::Template::compile(body).map_err(|err| {
+                TemplateError::invalid_definition(format!("Partial '{}': {}", name, err))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the manifest as YAML
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        tokio::fs::write(path, serde_yaml::to_string(self)?).await?;
+        Ok(())
+    }
+
+    /// Read and validate a manifest written by [`TemplateManifest::save`]
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let source = tokio::fs::read_to_string(path).await?;
+        let manifest: Self =
+            serde_yaml::from_str(crate::utils::strip_bom(&source)).map_err(|err| {
+                TemplateError::invalid_definition(format!(
+                    "Failed to parse {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+}
+
+impl TemplateEngine {
+    /// Register every partial and template in a manifest
+    ///
+    /// Partials are registered first so templates can use them. Returns the
+    /// number of templates registered.
+    pub fn register_manifest(&mut self, manifest: TemplateManifest) -> Result<usize> {
+        manifest.validate()?;
+
+        for (name, body) in &manifest.partials {
+            self.register_partial(name, body)?;
+        }
+        let count = manifest.templates.len();
+        for template in manifest.templates {
+            self.register_template(template)?;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_manifest_round_trip() {
+        let manifest = TemplateManifest::new("1.0.0")
+            .with_partial("signature", "-- {{team}}")
+            .with_template(TemplateDefinition::new(
+                "welcome",
+                "1.0.0",
+                "Welcome {{name}}!\n{{> signature}}",
+            ))
+            .with_template(TemplateDefinition::new(
+                "farewell",
+                "1.0.0",
+                "Goodbye {{name}}!\n{{> signature}}",
+            ));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle.yaml");
+        manifest.save(&path).await.unwrap();
+        let loaded = TemplateManifest::load(&path).await.unwrap();
+
+        assert_eq!(loaded.version, "1.0.0");
+        assert_eq!(loaded.templates.len(), 2);
+        assert_eq!(loaded.partials, manifest.partials);
+
+        let mut engine = TemplateEngine::new();
+        assert_eq!(engine.register_manifest(loaded).unwrap(), 2);
+        let generated = engine
+            .generate("farewell", json!({"name": "Ada", "team": "Platform"}))
+            .await
+            .unwrap();
+        assert_eq!(generated.content, "Goodbye Ada!\n-- Platform");
+    }
+
+    #[tokio::test]
+    async fn test_manifest_load_validates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle.yaml");
+
+        let duplicate = TemplateManifest::new("1.0.0")
+            .with_template(TemplateDefinition::new("a", "1.0.0", "A"))
+            .with_template(TemplateDefinition::new("a", "1.0.0", "A again"));
+        duplicate.save(&path).await.unwrap();
+        let err = TemplateManifest::load(&path).await.unwrap_err();
+        assert!(err.to_string().contains("template 'a' more than once"));
+
+        let broken = TemplateManifest::new("1.0.0").with_partial("footer", "{{#if}}");
+        broken.save(&path).await.unwrap();
+        let err = TemplateManifest::load(&path).await.unwrap_err();
+        assert!(err.to_string().contains("Partial 'footer'"));
+    }
+}
@@ -5,4 +5,7 @@
 pub mod definition;
 pub mod engine;
 pub mod inheritance;
+pub mod manifest;
+pub mod postprocess;
 pub mod schema;
+pub mod validator;
@@ -0,0 +1,20 @@
+//! Generation post-processing
+//!
+//! Custom transforms applied to rendered output before it is returned.
+
+use crate::error::Result;
+use std::fmt::Debug;
+
+/// Transform applied to rendered template output
+///
+/// Post-processors registered with
+/// [`TemplateEngine::add_post_processor`](crate::template::engine::TemplateEngine::add_post_processor)
+/// run in registration order after rendering, before output schema
+/// validation. Returning an error aborts the generation.
+pub trait PostProcessor: Debug + Send + Sync {
+    /// Name recorded in the generation metadata
+    fn name(&self) -> &str;
+
+    /// Transform the rendered content in place
+    fn process(&self, content: &mut String) -> Result<()>;
+}
@@ -1,3 +1,680 @@
 //! Template schema validation
+//!
+//! Lightweight JSON Schema validator covering the draft 7 subset used by
+//! template input and output schemas: `type`, `required`, `properties`,
+//! `enum`, `minLength`/`maxLength`, `minimum`/`maximum` and `items`.
+//! Unsupported keywords are ignored. Property `default` values can be
+//! filled into a document with [`apply_defaults`].
 
-// Placeholder for future schema validation functionality
+use crate::error::TemplateError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// A single schema violation at a specific location in the document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaViolation {
+    /// Path to the offending value (e.g. `$.todos[0].content`)
+    pub path: String,
+
+    /// Description of the violation
+    pub message: String,
+}
+
+impl fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validate a document against a schema, returning every violation found
+pub fn validate(schema: &Value, instance: &Value) -> Vec<SchemaViolation> {
+    validate_with(schema, instance, ValidationMode::CollectAll)
+}
+
+/// Validate a document against a schema, returning the violations `mode` reports
+///
+/// In [`ValidationMode::FailFast`] the walk stops at the first violation.
+pub fn validate_with(
+    schema: &Value,
+    instance: &Value,
+    mode: ValidationMode,
+) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    validate_at(schema, instance, "$", mode, &mut violations);
+    mode.collect(violations)
+}
+
+/// Check that a schema's supported keywords are well-formed
+///
+/// Returns one violation per malformed keyword, with the path into the
+/// schema itself (e.g. `$.properties.name.type`). Such keywords would
+/// otherwise be silently ignored by [`validate`]; templates are checked at
+/// definition validation so mistakes surface at registration instead.
+pub fn check_schema(schema: &Value) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    check_schema_at(schema, "$", &mut violations);
+    violations
+}
+
+/// Fill in absent object properties that declare a `default`
+///
+/// Walks `properties` of object schemas, including nested objects present
+/// in the document. Properties that are present, even as `null`, are left
+/// unchanged. Non-object documents are not touched.
+pub fn apply_defaults(schema: &Value, instance: &mut Value) {
+    let (Some(Value::Object(properties)), Value::Object(fields)) =
+        (schema.get("properties"), instance)
+    else {
+        return;
+    };
+
+    for (name, property) in properties {
+        match fields.get_mut(name) {
+            Some(value) => apply_defaults(property, value),
+            None => {
+                if let Some(default) = property.get("default") {
+                    fields.insert(name.clone(), default.clone());
+                }
+            }
+        }
+    }
+}
+
+/// How many problems validation reports before failing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationMode {
+    /// Aggregate every problem found into a single error
+    #[default]
+    CollectAll,
+    /// Fail with the first problem found
+    FailFast,
+}
+
+impl ValidationMode {
+    /// Collect the problems this mode reports
+    ///
+    /// In [`FailFast`](Self::FailFast) only the first problem is taken, so a
+    /// lazy iterator is not advanced past it.
+    pub fn collect<T>(self, problems: impl IntoIterator<Item = T>) -> Vec<T> {
+        match self {
+            Self::CollectAll => problems.into_iter().collect(),
+            Self::FailFast => problems.into_iter().take(1).collect(),
+        }
+    }
+
+    /// Whether validation can stop, given the problems found so far
+    const fn is_done<T>(self, problems: &[T]) -> bool {
+        matches!(self, Self::FailFast) && !problems.is_empty()
+    }
+}
+
+/// Validate a document against a schema, failing with all violations
+pub fn ensure_valid(schema: &Value, instance: &Value) -> crate::Result<()> {
+    ensure_valid_with(schema, instance, ValidationMode::CollectAll)
+}
+
+/// Validate a document against a schema, reporting violations per `mode`
+pub fn ensure_valid_with(
+    schema: &Value,
+    instance: &Value,
+    mode: ValidationMode,
+) -> crate::Result<()> {
+    let violations = validate_with(schema, instance, mode);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(TemplateError::SchemaValidation {
+            errors: violations.iter().map(ToString::to_string).collect(),
+        }
+        .into())
+    }
+}
+
+/// Validate template input against its input schema
+///
+/// Compiles `schema` for a single use; see [`CompiledSchema`] for the
+/// validation performed and to reuse the compiled form across calls.
+pub fn validate_input(schema: &Value, input: &Value, mode: ValidationMode) -> crate::Result<()> {
+    CompiledSchema::compile(schema)?.validate(input, mode)
+}
+
+/// Template input schema prepared once for repeated validation
+///
+/// The built-in subset validator runs first so common violations keep their
+/// wording. With the `schema-validation` feature, input that passes is then
+/// checked by the full `jsonschema` validator, enforcing keywords such as
+/// `pattern`, `minItems` or `$ref` that the subset ignores; that validator is
+/// built in [`compile`](Self::compile), so schemas it rejects fail there
+/// rather than on every validation.
+#[derive(Debug, Clone)]
+pub struct CompiledSchema {
+    schema: Value,
+    #[cfg(feature = "schema-validation")]
+    validator: jsonschema::Validator,
+}
+
+impl CompiledSchema {
+    /// Prepare a schema for validation
+    pub fn compile(schema: &Value) -> crate::Result<Self> {
+        Ok(Self {
+            schema: schema.clone(),
+            #[cfg(feature = "schema-validation")]
+            validator: jsonschema::validator_for(schema).map_err(|err| {
+                TemplateError::invalid_definition(format!("Invalid input schema: {}", err))
+            })?,
+        })
+    }
+
+    /// The schema this was compiled from
+    pub const fn schema(&self) -> &Value {
+        &self.schema
+    }
+
+    /// Validate input, reporting violations per `mode`
+    ///
+    /// In [`ValidationMode::FailFast`] only the first violation is reported.
+    pub fn validate(&self, input: &Value, mode: ValidationMode) -> crate::Result<()> {
+        ensure_valid_with(&self.schema, input, mode)?;
+
+        #[cfg(feature = "schema-validation")]
+        {
+            let errors: Vec<String> = match mode {
+                ValidationMode::CollectAll => self
+                    .validator
+                    .iter_errors(input)
+                    .map(|err| format!("{}: {}", json_path(err.instance_path()), err))
+                    .collect(),
+                ValidationMode::FailFast => self
+                    .validator
+                    .validate(input)
+                    .err()
+                    .map(|err| format!("{}: {}", json_path(err.instance_path()), err))
+                    .into_iter()
+                    .collect(),
+            };
+            if !errors.is_empty() {
+                return Err(TemplateError::SchemaValidation { errors }.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Render a `jsonschema` location in the `$.field[0]` notation used here
+#[cfg(feature = "schema-validation")]
+fn json_path(location: &jsonschema::paths::Location) -> String {
+    use jsonschema::paths::LocationSegment;
+    use std::fmt::Write;
+
+    let mut path = String::from("$");
+    for segment in location {
+        match segment {
+            LocationSegment::Property(name) => {
+                path.push('.');
+                path.push_str(&name);
+            }
+            LocationSegment::Index(idx) => write!(path, "[{}]", idx).unwrap(),
+        }
+    }
+    path
+}
+
+fn validate_at(
+    schema: &Value,
+    instance: &Value,
+    path: &str,
+    mode: ValidationMode,
+    out: &mut Vec<SchemaViolation>,
+) {
+    let Some(schema) = schema.as_object() else {
+        return;
+    };
+    if mode.is_done(out) {
+        return;
+    }
+
+    let mut push = |message: String| {
+        out.push(SchemaViolation {
+            path: path.to_string(),
+            message,
+        });
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|name| matches_type(name, instance)) {
+            push(format!(
+                "expected type {}, found {}",
+                allowed.join(" or "),
+                type_name(instance)
+            ));
+            // Remaining keywords assume the declared type
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = schema.get("enum") {
+        if !options.contains(instance) {
+            let options: Vec<String> = options.iter().map(ToString::to_string).collect();
+            push(format!(
+                "value {} is not one of [{}]",
+                instance,
+                options.join(", ")
+            ));
+        }
+    }
+
+    if let Value::String(text) = instance {
+        let length = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if length < min {
+                push(format!(
+                    "string length {} is less than minLength {}",
+                    length, min
+                ));
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if length > max {
+                push(format!(
+                    "string length {} exceeds maxLength {}",
+                    length, max
+                ));
+            }
+        }
+    }
+
+    if let Some(number) = instance.as_f64() {
+        if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+            if number < min {
+                push(format!("value {} is less than minimum {}", number, min));
+            }
+        }
+        if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+            if number > max {
+                push(format!("value {} exceeds maximum {}", number, max));
+            }
+        }
+    }
+
+    if let Value::Object(fields) = instance {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for field in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(field) {
+                    push(format!("missing required property '{}'", field));
+                }
+            }
+        }
+        if let Some(Value::Object(properties)) = schema.get("properties") {
+            for (name, property_schema) in properties {
+                if mode.is_done(out) {
+                    return;
+                }
+                if let Some(value) = fields.get(name) {
+                    let child = format!("{}.{}", path, name);
+                    validate_at(property_schema, value, &child, mode, out);
+                }
+            }
+        }
+    }
+
+    if let (Value::Array(elements), Some(item_schema)) = (instance, schema.get("items")) {
+        for (idx, element) in elements.iter().enumerate() {
+            if mode.is_done(out) {
+                return;
+            }
+            let child = format!("{}[{}]", path, idx);
+            validate_at(item_schema, element, &child, mode, out);
+        }
+    }
+}
+
+fn check_schema_at(schema: &Value, path: &str, out: &mut Vec<SchemaViolation>) {
+    let Some(schema) = schema.as_object() else {
+        out.push(SchemaViolation {
+            path: path.to_string(),
+            message: format!("schema must be an object, found {}", type_name(schema)),
+        });
+        return;
+    };
+
+    let mut push = |keyword: &str, message: String| {
+        out.push(SchemaViolation {
+            path: format!("{}.{}", path, keyword),
+            message,
+        });
+    };
+
+    if let Some(expected) = schema.get("type") {
+        let names: Vec<&Value> = match expected {
+            Value::Array(names) => names.iter().collect(),
+            other => vec![other],
+        };
+        for name in names {
+            match name.as_str() {
+                Some(name) if TYPE_NAMES.contains(&name) => {}
+                Some(name) => push("type", format!("unknown type '{}'", name)),
+                None => push(
+                    "type",
+                    format!("type names must be strings, found {}", name),
+                ),
+            }
+        }
+    }
+    if let Some(required) = schema.get("required") {
+        let all_strings = required
+            .as_array()
+            .is_some_and(|names| names.iter().all(Value::is_string));
+        if !all_strings {
+            push("required", "must be an array of property names".to_string());
+        }
+    }
+    if schema
+        .get("enum")
+        .is_some_and(|options| !options.is_array())
+    {
+        push("enum", "must be an array".to_string());
+    }
+    for keyword in ["minLength", "maxLength"] {
+        if schema.get(keyword).is_some_and(|n| n.as_u64().is_none()) {
+            push(keyword, "must be a non-negative integer".to_string());
+        }
+    }
+    for keyword in ["minimum", "maximum"] {
+        if schema.get(keyword).is_some_and(|n| !n.is_number()) {
+            push(keyword, "must be a number".to_string());
+        }
+    }
+
+    match schema.get("properties") {
+        Some(Value::Object(properties)) => {
+            for (name, property_schema) in properties {
+                let child = format!("{}.properties.{}", path, name);
+                check_schema_at(property_schema, &child, out);
+            }
+        }
+        Some(_) => push("properties", "must be an object".to_string()),
+        None => {}
+    }
+    if let Some(item_schema) = schema.get("items") {
+        check_schema_at(item_schema, &format!("{}.items", path), out);
+    }
+}
+
+/// Type names understood by the `type` keyword
+const TYPE_NAMES: [&str; 7] = [
+    "object", "array", "string", "number", "integer", "boolean", "null",
+];
+
+fn matches_type(name: &str, instance: &Value) -> bool {
+    match name {
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => {
+            instance.is_i64()
+                || instance.is_u64()
+                || instance.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "boolean" => instance.is_boolean(),
+        "null" => instance.is_null(),
+        _ => true,
+    }
+}
+
+const fn type_name(instance: &Value) -> &'static str {
+    match instance {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(violations: &[SchemaViolation]) -> Vec<&str> {
+        violations.iter().map(|v| v.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_apply_defaults() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "granularity": {"type": "string", "default": "high"},
+                "format": {"type": "string", "default": "yaml"},
+                "options": {
+                    "type": "object",
+                    "properties": {"retries": {"type": "integer", "default": 3}}
+                },
+                "owner": {"type": "string"}
+            }
+        });
+
+        let mut input = json!({"format": "json", "options": {}});
+        apply_defaults(&schema, &mut input);
+        assert_eq!(
+            input,
+            json!({"granularity": "high", "format": "json", "options": {"retries": 3}})
+        );
+
+        let mut scalar = json!("text");
+        apply_defaults(&schema, &mut scalar);
+        assert_eq!(scalar, json!("text"));
+    }
+
+    #[test]
+    fn test_check_schema() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "tags": {"type": "array", "items": {"type": ["string", "null"]}}
+            }
+        });
+        assert!(check_schema(&schema).is_empty());
+
+        let schema = json!({
+            "type": "object",
+            "required": "name",
+            "properties": {
+                "name": {"type": "strng", "maxLength": -1},
+                "tags": {"type": "array", "items": "string"}
+            }
+        });
+        let violations = check_schema(&schema);
+        assert_eq!(
+            paths(&violations),
+            vec![
+                "$.required",
+                "$.properties.name.type",
+                "$.properties.name.maxLength",
+                "$.properties.tags.items"
+            ]
+        );
+        assert_eq!(violations[1].message, "unknown type 'strng'");
+    }
+
+    #[test]
+    fn test_type_keyword() {
+        let schema = json!({"type": "string"});
+        assert!(validate(&schema, &json!("ok")).is_empty());
+        assert_eq!(
+            validate(&schema, &json!(42))[0].message,
+            "expected type string, found number"
+        );
+
+        let schema = json!({"type": "integer"});
+        assert!(validate(&schema, &json!(3)).is_empty());
+        assert_eq!(validate(&schema, &json!(3.5)).len(), 1);
+
+        let schema = json!({"type": ["string", "null"]});
+        assert!(validate(&schema, &json!(null)).is_empty());
+        assert_eq!(validate(&schema, &json!(true)).len(), 1);
+    }
+
+    #[test]
+    fn test_required_and_properties_keywords() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {"name": {"type": "string"}, "age": {"type": "number"}}
+        });
+        assert!(validate(&schema, &json!({"name": "Ada", "age": 36})).is_empty());
+
+        let violations = validate(&schema, &json!({"age": "old"}));
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[0].to_string(),
+            "$: missing required property 'name'"
+        );
+        assert_eq!(violations[1].path, "$.age");
+    }
+
+    #[test]
+    fn test_enum_keyword() {
+        let schema = json!({"enum": ["low", "medium", "high"]});
+        assert!(validate(&schema, &json!("medium")).is_empty());
+        assert_eq!(
+            validate(&schema, &json!("urgent"))[0].message,
+            r#"value "urgent" is not one of ["low", "medium", "high"]"#
+        );
+    }
+
+    #[test]
+    fn test_length_keywords() {
+        let schema = json!({"minLength": 3, "maxLength": 5});
+        assert!(validate(&schema, &json!("abcd")).is_empty());
+        assert!(validate(&schema, &json!("ab"))[0]
+            .message
+            .contains("minLength 3"));
+        assert!(validate(&schema, &json!("abcdef"))[0]
+            .message
+            .contains("maxLength 5"));
+    }
+
+    #[test]
+    fn test_range_keywords() {
+        let schema = json!({"minimum": 0.5, "maximum": 40});
+        assert!(validate(&schema, &json!(8)).is_empty());
+        assert!(validate(&schema, &json!(0.25))[0]
+            .message
+            .contains("minimum 0.5"));
+        assert!(validate(&schema, &json!(41))[0]
+            .message
+            .contains("maximum 40"));
+    }
+
+    #[test]
+    fn test_items_keyword() {
+        let schema = json!({
+            "type": "array",
+            "items": {"type": "object", "required": ["id"]}
+        });
+        assert!(validate(&schema, &json!([{"id": "a"}, {"id": "b"}])).is_empty());
+
+        let violations = validate(&schema, &json!([{"id": "a"}, {}, "x"]));
+        assert_eq!(paths(&violations), vec!["$[1]", "$[2]"]);
+    }
+
+    #[cfg(feature = "schema-validation")]
+    #[test]
+    fn test_validate_input_full_schema() {
+        let schema = json!({
+            "type": "object",
+            "required": ["requirements"],
+            "properties": {
+                "requirements": {"type": "array", "minItems": 1},
+                "project_name": {"type": "string", "pattern": "^[A-Z]"}
+            }
+        });
+        assert!(validate_input(
+            &schema,
+            &json!({"requirements": ["a"]}),
+            ValidationMode::CollectAll
+        )
+        .is_ok());
+
+        let err = validate_input(&schema, &json!({}), ValidationMode::CollectAll).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("missing required property 'requirements'"));
+
+        // Keywords outside the built-in subset are enforced with paths
+        let err = validate_input(
+            &schema,
+            &json!({"requirements": [], "project_name": "lower"}),
+            ValidationMode::CollectAll,
+        )
+        .unwrap_err();
+        match err {
+            crate::Error::Template(TemplateError::SchemaValidation { errors }) => {
+                assert_eq!(errors.len(), 2);
+                assert!(errors.iter().any(|e| e.starts_with("$.requirements: ")));
+                assert!(errors.iter().any(|e| e.starts_with("$.project_name: ")));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "schema-validation")]
+    #[test]
+    fn test_compile_rejects_invalid_schema() {
+        let err = CompiledSchema::compile(&json!({"type": "string", "pattern": "("})).unwrap_err();
+        assert!(err.to_string().contains("Invalid input schema"));
+    }
+
+    #[test]
+    fn test_ensure_valid_reports_all_errors() {
+        let schema = json!({"type": "object", "required": ["a", "b"]});
+        assert!(ensure_valid(&schema, &json!({"a": 1, "b": 2})).is_ok());
+
+        let err = ensure_valid(&schema, &json!({})).unwrap_err();
+        match err {
+            crate::Error::Template(TemplateError::SchemaValidation { errors }) => {
+                assert_eq!(errors.len(), 2);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fail_fast_stops_at_first_problem() {
+        let problems = std::iter::once("first").chain(std::iter::from_fn(|| -> Option<&str> {
+            panic!("fail-fast advanced past the first problem")
+        }));
+        assert_eq!(ValidationMode::FailFast.collect(problems), vec!["first"]);
+
+        let schema = json!({"type": "array", "items": {"type": "string"}});
+        let violations = validate_with(&schema, &json!([1, 2, 3]), ValidationMode::FailFast);
+        assert_eq!(paths(&violations), vec!["$[0]"]);
+    }
+
+    #[test]
+    fn test_fail_fast_reports_first_violation() {
+        let schema = json!({"type": "object", "required": ["a", "b"]});
+
+        let err = ensure_valid_with(&schema, &json!({}), ValidationMode::FailFast).unwrap_err();
+        match err {
+            crate::Error::Template(TemplateError::SchemaValidation { errors }) => {
+                assert_eq!(errors, vec!["$: missing required property 'a'"]);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Custom template validators
+//!
+//! Named checks that templates opt into through
+//! [`ValidationRules::custom_validators`](crate::template::definition::ValidationRules::custom_validators),
+//! for rules that span several fields and cannot be expressed in a schema.
+
+use crate::error::Result;
+use serde_json::Value;
+use std::fmt::Debug;
+
+/// Check run on a generation's input and rendered output
+///
+/// Validators are registered under a name with
+/// [`TemplateEngine::register_validator`](crate::template::engine::TemplateEngine::register_validator)
+/// and run for templates listing that name in `custom_validators`, after
+/// output schema and structure validation. Returning an error aborts the
+/// generation.
+pub trait TemplateValidator: Debug + Send + Sync {
+    /// Check the input (with schema defaults applied) and the rendered output
+    fn validate(&self, input: &Value, output: &str) -> Result<()>;
+}
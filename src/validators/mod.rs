@@ -5,4 +5,10 @@
 #[cfg(feature = "todo-validation")]
 pub mod todo;
 
+#[cfg(feature = "todo-validation")]
+pub mod suggestions;
+
+#[cfg(feature = "todo-validation")]
+pub mod streaming;
+
 pub mod structure;
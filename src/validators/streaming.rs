@@ -0,0 +1,221 @@
+//! Streaming todo validation
+//!
+//! Checks each todo's text as it is read from generated YAML, without
+//! building a [`TodoList`](crate::models::todo::TodoList). Only per-todo text
+//! checks (actionability, content length and specificity) are applied; graph
+//! checks need the full list.
+//!
+//! This is not incremental parsing. `serde_yaml` loads every YAML event of
+//! the input before the first todo is visited, so memory use grows with the
+//! input size just as it does for a full parse.
+
+use crate::validators::todo::{IssueSeverity, TodoValidator, ValidationIssue};
+use serde::de::{DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+use std::fmt;
+
+/// Result of streaming validation
+#[derive(Debug, Clone)]
+pub struct StreamingValidationResult {
+    /// Whether no error-level issues were found
+    pub is_valid: bool,
+
+    /// Number of todos visited
+    pub todo_count: usize,
+
+    /// Issues found, in document order
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl TodoValidator {
+    /// Validate generated todo YAML without building a `TodoList`
+    ///
+    /// Accepts either a mapping with a top-level `todos` sequence or a bare
+    /// sequence of todos. Todos are checked in document order, after the
+    /// whole input has been parsed (see the [module docs](self)). Todos
+    /// without an `id` are reported as `#<index>`.
+    pub fn validate_yaml_streaming(&self, yaml: &str) -> crate::Result<StreamingValidationResult> {
+        let mut collector = Collector {
+            validator: self,
+            todo_count: 0,
+            issues: Vec::new(),
+        };
+        let yaml = crate::utils::strip_bom(yaml);
+        Document(&mut collector).deserialize(serde_yaml::Deserializer::from_str(yaml))?;
+
+        let is_valid = !collector
+            .issues
+            .iter()
+            .any(|issue| issue.severity == IssueSeverity::Error);
+
+        Ok(StreamingValidationResult {
+            is_valid,
+            todo_count: collector.todo_count,
+            issues: collector.issues,
+        })
+    }
+}
+
+/// The fields of a todo that streaming validation inspects
+#[derive(Deserialize)]
+struct TodoRecord {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    content: String,
+}
+
+/// Accumulates issues as todos are read
+struct Collector<'v> {
+    validator: &'v TodoValidator,
+    todo_count: usize,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Collector<'_> {
+    fn record(&mut self, todo: TodoRecord) {
+        let id = todo.id.unwrap_or_else(|| format!("#{}", self.todo_count));
+        self.validator
+            .validate_content(&id, &todo.content, &mut self.issues);
+        self.todo_count += 1;
+    }
+}
+
+/// Root of the document: a `todos` mapping or a bare sequence
+struct Document<'c, 'v>(&'c mut Collector<'v>);
+
+impl<'de> DeserializeSeed<'de> for Document<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Document<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a mapping with a `todos` sequence or a sequence of todos")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<(), A::Error> {
+        TodoSequence(self.0).visit_seq(seq)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "todos" {
+                map.next_value_seed(TodoSequence(&mut *self.0))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A sequence of todos, validated element by element
+struct TodoSequence<'c, 'v>(&'c mut Collector<'v>);
+
+impl<'de> DeserializeSeed<'de> for TodoSequence<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for TodoSequence<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence of todos")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(todo) = seq.next_element::<TodoRecord>()? {
+            self.0.record(todo);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::{Todo, TodoList};
+    use crate::validators::todo::IssueCategory;
+    use std::fmt::Write;
+
+    fn content_for(i: usize) -> String {
+        match i % 10 {
+            0 => format!("stuff number {}", i),
+            1 => format!("Fix #{}", i),
+            _ => format!("Implement feature number {}", i),
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_full_validation() {
+        let count = 2_000;
+
+        let mut yaml = String::from("project: Large\ntodos:\n");
+        let mut list = TodoList::new();
+        for i in 0..count {
+            let content = content_for(i);
+            write!(
+                yaml,
+                "  - id: \"todo_{i}\"\n    content: \"{content}\"\n    status: pending\n    tags: [generated]\n"
+            )
+            .unwrap();
+
+            let mut todo = Todo::new(content);
+            todo.id = format!("todo_{}", i);
+            list.add_todo(todo);
+        }
+
+        let validator = TodoValidator::new();
+        let streamed = validator.validate_yaml_streaming(&yaml).unwrap();
+        let full = validator.validate_todo_list(&list);
+
+        let text_issues = full
+            .issues
+            .iter()
+            .filter(|i| {
+                i.category == IssueCategory::Actionability
+                    || i.message.starts_with("Todo content too")
+            })
+            .count();
+
+        assert_eq!(streamed.todo_count, count);
+        assert!(!streamed.is_valid);
+        assert_eq!(streamed.issues.len(), text_issues);
+        assert_eq!(
+            streamed
+                .issues
+                .iter()
+                .filter(|i| i.category == IssueCategory::Actionability)
+                .count(),
+            count / 10
+        );
+    }
+
+    #[test]
+    fn test_streaming_bare_sequence_and_missing_ids() {
+        let yaml = "- content: Implement login form\n- content: misc\n";
+
+        let result = TodoValidator::new().validate_yaml_streaming(yaml).unwrap();
+
+        assert_eq!(result.todo_count, 2);
+        assert!(!result.issues.is_empty());
+        let ids: Vec<Option<&str>> = result.issues.iter().map(|i| i.todo_id.as_deref()).collect();
+        assert_eq!(ids, vec![Some("#1"); 2]);
+    }
+
+    #[test]
+    fn test_streaming_rejects_malformed_yaml() {
+        let validator = TodoValidator::new();
+        assert!(validator.validate_yaml_streaming("todos: 42").is_err());
+    }
+}
@@ -1,3 +1,352 @@
 //! General structure validation
+//!
+//! Checks parsed generated output against a template's [`StructureRules`]:
+//! item counts on the primary array, required top-level elements and
+//! forbidden elements anywhere in the document. Raw output can also be
+//! scanned for self-admitted technical debt (SATD) markers.
 
-// Placeholder for general structure validation
+use crate::template::definition::StructureRules;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Key holding the primary array when no required element is declared
+const DEFAULT_ITEMS_KEY: &str = "todos";
+
+/// Markers of self-admitted technical debt, matched case-insensitively
+const SATD_TOKENS: &[&str] = &["todo", "fixme", "hack", "xxx"];
+
+/// A self-admitted technical debt marker found in generated output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SatdFinding {
+    /// 1-based line number
+    pub line: usize,
+
+    /// 1-based column of the marker's first character
+    pub column: usize,
+
+    /// The marker as written in the output
+    pub token: String,
+}
+
+impl fmt::Display for SatdFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.token, self.line, self.column
+        )
+    }
+}
+
+/// Find TODO, FIXME, HACK and XXX markers in generated output
+///
+/// Matching ignores case but only counts whole words, so `TODOlist` or
+/// `todo_id` are not reported. Columns are counted in characters.
+pub fn detect_satd(content: &str) -> Vec<SatdFinding> {
+    iter_satd(content).collect()
+}
+
+/// Lazily find SATD markers, line by line, as reported by [`detect_satd`]
+///
+/// Lines after the one holding the first marker are only scanned as the
+/// iterator is advanced, so taking the first finding stays cheap.
+pub fn iter_satd(content: &str) -> impl Iterator<Item = SatdFinding> + '_ {
+    content
+        .lines()
+        .enumerate()
+        .flat_map(|(line_index, line)| satd_in_line(line_index + 1, line))
+}
+
+/// SATD markers on a single line, numbered `line_number`
+fn satd_in_line(line_number: usize, line: &str) -> Vec<SatdFinding> {
+    let mut findings = Vec::new();
+    let mut column = 0;
+    let mut rest = line;
+    while !rest.is_empty() {
+        let word_len = rest.find(|c: char| !is_word_char(c)).unwrap_or(rest.len());
+        if word_len == 0 {
+            let skipped = rest.chars().next().map_or(1, char::len_utf8);
+            rest = &rest[skipped..];
+            column += 1;
+            continue;
+        }
+
+        let word = &rest[..word_len];
+        if SATD_TOKENS
+            .iter()
+            .any(|token| word.eq_ignore_ascii_case(token))
+        {
+            findings.push(SatdFinding {
+                line: line_number,
+                column: column + 1,
+                token: word.to_string(),
+            });
+        }
+        column += word.chars().count();
+        rest = &rest[word_len..];
+    }
+    findings
+}
+
+/// Whether a character can be part of a word for SATD matching
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// A single structural rule violation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StructureViolation {
+    /// The primary array has fewer items than `min_items`
+    TooFewItems {
+        /// Key of the primary array
+        key: String,
+        /// Number of items found
+        count: usize,
+        /// Configured minimum
+        min: usize,
+    },
+    /// The primary array has more items than `max_items`
+    TooManyItems {
+        /// Key of the primary array
+        key: String,
+        /// Number of items found
+        count: usize,
+        /// Configured maximum
+        max: usize,
+    },
+    /// A required top-level element is missing
+    MissingElement {
+        /// Name of the missing element
+        element: String,
+    },
+    /// A forbidden element is present
+    ForbiddenElement {
+        /// Name of the forbidden element
+        element: String,
+    },
+}
+
+impl fmt::Display for StructureViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewItems { key, count, min } => write!(
+                f,
+                "Output has {} items in '{}', below min_items {}",
+                count, key, min
+            ),
+            Self::TooManyItems { key, count, max } => write!(
+                f,
+                "Output has {} items in '{}', exceeding max_items {}",
+                count, key, max
+            ),
+            Self::MissingElement { element } => {
+                write!(f, "Output is missing required element '{}'", element)
+            }
+            Self::ForbiddenElement { element } => {
+                write!(f, "Output contains forbidden element '{}'", element)
+            }
+        }
+    }
+}
+
+/// Validator for the structure of parsed generated output
+#[derive(Debug, Clone)]
+pub struct StructureValidator {
+    rules: StructureRules,
+}
+
+impl StructureValidator {
+    /// Create a validator for the given rules
+    pub const fn new(rules: StructureRules) -> Self {
+        Self { rules }
+    }
+
+    /// Check a parsed document and return every violation found
+    ///
+    /// The primary array is the document itself when it is an array, or the
+    /// array under the first required element (`todos` when none is
+    /// declared); item counts are only checked when it exists. Required
+    /// elements must be top-level keys of an object document. Forbidden
+    /// elements are reported when they appear as a key at any depth.
+    pub fn validate(&self, document: &Value) -> Vec<StructureViolation> {
+        self.iter_violations(document).collect()
+    }
+
+    /// Lazily check a parsed document, in the order [`validate`](Self::validate) reports
+    ///
+    /// Later checks, such as the forbidden-element search through the whole
+    /// document, only run as the iterator is advanced.
+    pub fn iter_violations<'a>(
+        &'a self,
+        document: &'a Value,
+    ) -> impl Iterator<Item = StructureViolation> + 'a {
+        let mut counts = Vec::new();
+        self.check_item_counts(document, &mut counts);
+
+        let missing = self
+            .rules
+            .required_elements
+            .iter()
+            .filter(move |element| {
+                document
+                    .as_object()
+                    .is_some_and(|map| !map.contains_key(*element))
+            })
+            .map(|element| StructureViolation::MissingElement {
+                element: element.clone(),
+            });
+
+        let forbidden = self
+            .rules
+            .forbidden_elements
+            .iter()
+            .filter(move |element| contains_key(document, element))
+            .map(|element| StructureViolation::ForbiddenElement {
+                element: element.clone(),
+            });
+
+        counts.into_iter().chain(missing).chain(forbidden)
+    }
+
+    fn check_item_counts(&self, document: &Value, violations: &mut Vec<StructureViolation>) {
+        let key = self
+            .rules
+            .required_elements
+            .first()
+            .map_or(DEFAULT_ITEMS_KEY, String::as_str);
+        let count = match document {
+            Value::Array(items) => items.len(),
+            Value::Object(map) => match map.get(key) {
+                Some(Value::Array(items)) => items.len(),
+                // An empty block (`todos:`) parses as null
+                Some(Value::Null) => 0,
+                _ => return,
+            },
+            _ => return,
+        };
+
+        if let Some(max) = self.rules.max_items.filter(|max| count > *max) {
+            violations.push(StructureViolation::TooManyItems {
+                key: key.to_string(),
+                count,
+                max,
+            });
+        }
+        if let Some(min) = self.rules.min_items.filter(|min| count < *min) {
+            violations.push(StructureViolation::TooFewItems {
+                key: key.to_string(),
+                count,
+                min,
+            });
+        }
+    }
+}
+
+/// Whether `key` appears as an object key anywhere in `value`
+fn contains_key(value: &Value, key: &str) -> bool {
+    match value {
+        Value::Object(map) => map.contains_key(key) || map.values().any(|v| contains_key(v, key)),
+        Value::Array(items) => items.iter().any(|v| contains_key(v, key)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rules() -> StructureRules {
+        StructureRules {
+            max_items: Some(2),
+            min_items: Some(1),
+            required_elements: vec!["todos".to_string(), "metadata".to_string()],
+            forbidden_elements: vec!["password".to_string()],
+            ..StructureRules::default()
+        }
+    }
+
+    #[test]
+    fn test_detect_satd() {
+        let content =
+            "todos:\n  - content: \"Fix login\" # TODO: real copy\n  # fixme, Hack and XXX\n";
+        let findings = detect_satd(content);
+        assert_eq!(
+            findings[0],
+            SatdFinding {
+                line: 2,
+                column: 28,
+                token: "TODO".to_string(),
+            }
+        );
+        let tokens: Vec<&str> = findings.iter().map(|f| f.token.as_str()).collect();
+        assert_eq!(tokens, vec!["TODO", "fixme", "Hack", "XXX"]);
+        assert_eq!(findings[0].to_string(), "TODO at line 2, column 28");
+    }
+
+    #[test]
+    fn test_detect_satd_ignores_partial_words() {
+        let content = "todos:\n  - id: todo_1\n    content: Build TODOlist view for hackers\n";
+        assert!(detect_satd(content).is_empty());
+        assert!(detect_satd("").is_empty());
+    }
+
+    #[test]
+    fn test_valid_document_has_no_violations() {
+        let document = json!({"todos": [{"content": "a"}], "metadata": {}});
+        assert!(StructureValidator::new(rules())
+            .validate(&document)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_item_count_limits() {
+        let validator = StructureValidator::new(rules());
+
+        let too_many = json!({"todos": [1, 2, 3], "metadata": {}});
+        assert_eq!(
+            validator.validate(&too_many),
+            vec![StructureViolation::TooManyItems {
+                key: "todos".to_string(),
+                count: 3,
+                max: 2,
+            }]
+        );
+
+        let too_few = json!({"todos": null, "metadata": {}});
+        assert_eq!(
+            validator.validate(&too_few)[0].to_string(),
+            "Output has 0 items in 'todos', below min_items 1"
+        );
+
+        // A bare array is the primary array itself
+        assert_eq!(validator.validate(&json!([])).len(), 1);
+    }
+
+    #[test]
+    fn test_missing_required_element() {
+        let document = json!({"todos": [1]});
+        assert_eq!(
+            StructureValidator::new(rules()).validate(&document),
+            vec![StructureViolation::MissingElement {
+                element: "metadata".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_forbidden_element_at_any_depth() {
+        let document = json!({
+            "todos": [{"content": "a", "config": {"password": "hunter2"}}],
+            "metadata": {}
+        });
+        assert_eq!(
+            StructureValidator::new(rules()).validate(&document),
+            vec![StructureViolation::ForbiddenElement {
+                element: "password".to_string(),
+            }]
+        );
+    }
+}
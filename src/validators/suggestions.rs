@@ -0,0 +1,79 @@
+//! Validation suggestion text
+//!
+//! Pluggable providers for the improvement suggestions attached to validation results.
+
+use std::fmt::Debug;
+
+/// Kind of list-level improvement suggestion produced by the todo validator
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum SuggestionKind {
+    /// Some todos do not start with an action verb
+    NonActionable {
+        /// Number of non-actionable todos
+        count: usize,
+    },
+    /// Some todos exceed the complexity limit
+    ExcessiveComplexity {
+        /// Configured maximum complexity
+        max_complexity: u8,
+    },
+    /// Some todos are missing time estimates
+    MissingEstimates {
+        /// Number of todos without an estimate
+        count: usize,
+    },
+    /// The dependency graph contains cycles
+    CircularDependencies,
+    /// No todo declares any dependency
+    NoDependencies,
+    /// The overall quality score is below the acceptable threshold
+    LowQuality {
+        /// Overall quality score (0.0 to 1.0)
+        score: f32,
+    },
+}
+
+/// Source of suggestion text for validation results
+///
+/// Implement this to override, translate, or extend the default suggestions,
+/// for example to link to internal documentation. Returning `None` omits the
+/// suggestion from the result.
+pub trait SuggestionProvider: Debug + Send + Sync {
+    /// Produce the suggestion text for the given kind
+    fn suggest(&self, kind: &SuggestionKind) -> Option<String>;
+}
+
+/// Built-in English suggestion text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultSuggestionProvider;
+
+impl SuggestionProvider for DefaultSuggestionProvider {
+    fn suggest(&self, kind: &SuggestionKind) -> Option<String> {
+        let text = match kind {
+            SuggestionKind::NonActionable { count } => format!(
+                "Make {} todos more actionable by starting with action verbs (implement, create, add, etc.)",
+                count
+            ),
+            SuggestionKind::ExcessiveComplexity { max_complexity } => format!(
+                "Break down complex tasks (complexity > {}) into smaller, focused subtasks",
+                max_complexity
+            ),
+            SuggestionKind::MissingEstimates { count } => format!(
+                "Add time estimates to {} todos for better project planning",
+                count
+            ),
+            SuggestionKind::CircularDependencies => {
+                "Remove circular dependencies to enable proper task ordering".to_string()
+            }
+            SuggestionKind::NoDependencies => {
+                "Consider adding dependencies between related tasks for better sequencing"
+                    .to_string()
+            }
+            SuggestionKind::LowQuality { .. } => {
+                "Overall todo list quality could be improved - focus on specific, actionable tasks with realistic estimates".to_string()
+            }
+        };
+        Some(text)
+    }
+}
@@ -3,17 +3,46 @@
 //! Specialized validators for todo list content with quality enforcement.
 
 // Validation error types used in validator implementation
-use crate::models::todo::{Todo, TodoList, TodoQualityConfig};
+use crate::error::TodoValidationError;
+use crate::models::todo::{
+    meaningful_token_count, starts_with_any_verb, ComplexityModel, EstimateUnit, Todo, TodoInput,
+    TodoList, TodoPriority, TodoQualityConfig, TodoQualityGates, TodoStatus, DEFAULT_ACTION_VERBS,
+};
+use crate::utils::count_as_f32;
+use crate::validators::suggestions::{
+    DefaultSuggestionProvider, SuggestionKind, SuggestionProvider,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Minimum number of estimated todos before uniform estimates are flagged
+const UNIFORM_ESTIMATE_MIN_TODOS: usize = 5;
+
+/// Minimum number of estimated todos before over-decomposition is flagged
+const OVER_DECOMPOSITION_MIN_TODOS: usize = 5;
+
+/// Estimate variance (in hours squared) below which estimates count as uniform
+const UNIFORM_ESTIMATE_MAX_VARIANCE: f32 = 0.01;
 
 /// Validator for todo list content
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct TodoValidator {
     config: TodoQualityConfig,
+    suggestion_provider: Arc<dyn SuggestionProvider>,
+    tag_vocabulary: Option<Vec<String>>,
+    action_verbs: Option<Vec<String>>,
+    complexity_model: ComplexityModel,
+    score_weights: QualityScoreWeights,
 }
 
 /// Validation result with details
-#[derive(Debug, Clone)]
+///
+/// Serializes to the input expected by the built-in `validation_report`
+/// template, and deserializes so a stored result can be rendered again
+/// without re-running validation. The nested issue and metric types derive
+/// the same traits for that reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TodoValidationResult {
     /// Whether validation passed
     pub is_valid: bool,
@@ -26,10 +55,22 @@ pub struct TodoValidationResult {
 
     /// Suggestions for improvement
     pub suggestions: Vec<String>,
+
+    /// Overall quality score (0.0-1.0), see [`TodoValidator::quality_score`]
+    #[serde(default)]
+    pub quality_score: f32,
+
+    /// Quality gate outcome for each todo, keyed by todo ID
+    #[serde(default)]
+    pub quality_gates: HashMap<String, TodoQualityGates>,
+
+    /// Per-todo issues keyed by a fingerprint of the todo, for incremental runs
+    #[serde(skip)]
+    todo_issues: HashMap<u64, Vec<ValidationIssue>>,
 }
 
 /// Individual validation issue
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationIssue {
     /// Issue severity
     pub severity: IssueSeverity,
@@ -37,6 +78,10 @@ pub struct ValidationIssue {
     /// Issue category
     pub category: IssueCategory,
 
+    /// Structured kind and payload, for issues that map to a [`TodoValidationError`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<IssueCode>,
+
     /// Todo ID (if applicable)
     pub todo_id: Option<String>,
 
@@ -48,7 +93,7 @@ pub struct ValidationIssue {
 }
 
 /// Severity levels for validation issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssueSeverity {
     /// Must be fixed
     Error,
@@ -59,7 +104,7 @@ pub enum IssueSeverity {
 }
 
 /// Categories of validation issues
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssueCategory {
     /// Actionability issues
     Actionability,
@@ -77,8 +122,45 @@ pub enum IssueCategory {
     QualityGate,
 }
 
+/// Machine-readable kind of a validation issue, with the values it reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueCode {
+    /// Content does not start with an action verb
+    NotActionable {
+        /// Todo content
+        content: String,
+    },
+    /// Todo has no time estimate
+    MissingEstimate,
+    /// Todos depend on each other in a loop
+    CircularDependency {
+        /// IDs along the cycle
+        cycle: Vec<String>,
+    },
+    /// Todo depends on an ID that is not in the list
+    DependencyNotFound {
+        /// Missing dependency ID
+        dependency: String,
+    },
+    /// Todo complexity score is above the configured maximum
+    ComplexityExceeded {
+        /// Computed complexity score
+        score: u8,
+        /// Maximum allowed score
+        max: u8,
+    },
+    /// List has more todos than allowed
+    CountLimit {
+        /// Actual count
+        count: usize,
+        /// Maximum allowed
+        limit: usize,
+    },
+}
+
 /// Todo list quality metrics
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct TodoMetrics {
     /// Total number of todos
     pub total_count: usize,
@@ -106,10 +188,14 @@ pub struct TodoMetrics {
 
     /// Dependency graph metrics
     pub dependency_metrics: DependencyMetrics,
+
+    /// Share of todos (0.0-1.0) passing all their quality gates
+    #[serde(default)]
+    pub quality_gate_pass_rate: f32,
 }
 
 /// Dependency graph metrics
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DependencyMetrics {
     /// Number of todos with dependencies
     pub todos_with_dependencies: usize,
@@ -123,40 +209,222 @@ pub struct DependencyMetrics {
     /// Whether graph has cycles
     pub has_cycles: bool,
 
-    /// Critical path length
+    /// Number of todos on the hours-weighted critical path
     pub critical_path_length: usize,
 }
 
+/// Relative weight of each metric in the overall quality score
+///
+/// Weights are normalized to sum to 1.0 when the score is computed, so only
+/// their proportions matter.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QualityScoreWeights {
+    /// Weight of the share of actionable todos
+    pub actionability: f32,
+
+    /// Weight of the share of todos with a proper content length
+    pub length: f32,
+
+    /// Weight of the share of todos with reasonable complexity
+    pub complexity: f32,
+
+    /// Weight of the share of estimated todos, when estimates are required
+    pub estimates: f32,
+
+    /// Weight of the dependency graph being free of cycles
+    pub dependencies: f32,
+}
+
+impl Default for QualityScoreWeights {
+    fn default() -> Self {
+        Self {
+            actionability: 0.3,
+            length: 0.2,
+            complexity: 0.2,
+            estimates: 0.2,
+            dependencies: 0.1,
+        }
+    }
+}
+
+impl QualityScoreWeights {
+    /// Scale the weights to sum to 1.0
+    ///
+    /// Negative and non-finite weights count as zero; when nothing is left
+    /// the default weights are used.
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        let clamp = |weight: f32| {
+            if weight.is_finite() {
+                weight.max(0.0)
+            } else {
+                0.0
+            }
+        };
+        let weights = [
+            clamp(self.actionability),
+            clamp(self.length),
+            clamp(self.complexity),
+            clamp(self.estimates),
+            clamp(self.dependencies),
+        ];
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return Self::default();
+        }
+        Self {
+            actionability: weights[0] / total,
+            length: weights[1] / total,
+            complexity: weights[2] / total,
+            estimates: weights[3] / total,
+            dependencies: weights[4] / total,
+        }
+    }
+}
+
 impl TodoValidator {
     /// Create a new todo validator with default configuration
     pub fn new() -> Self {
-        Self {
-            config: TodoQualityConfig::default(),
-        }
+        Self::with_config(TodoQualityConfig::default())
     }
 
     /// Create a validator with custom configuration
     pub fn with_config(config: TodoQualityConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            suggestion_provider: Arc::new(DefaultSuggestionProvider),
+            tag_vocabulary: None,
+            action_verbs: None,
+            complexity_model: ComplexityModel::default(),
+            score_weights: QualityScoreWeights::default(),
+        }
+    }
+
+    /// Score todo complexity with a custom model
+    ///
+    /// Used for both the per-todo complexity check and the list metrics.
+    #[must_use]
+    pub fn with_complexity_model(mut self, model: ComplexityModel) -> Self {
+        self.complexity_model = model;
+        self
+    }
+
+    /// Weight the metrics of the overall quality score differently
+    #[must_use]
+    pub const fn with_quality_score_weights(mut self, weights: QualityScoreWeights) -> Self {
+        self.score_weights = weights;
+        self
+    }
+
+    /// Restrict tags to a controlled vocabulary
+    ///
+    /// Tags outside the vocabulary are reported as warnings suggesting the
+    /// closest allowed tag, so variants like "front-end" and "frontend" do not
+    /// fragment tag-based aggregation.
+    #[must_use]
+    pub fn with_tag_vocabulary<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tag_vocabulary = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Use these verbs instead of [`DEFAULT_ACTION_VERBS`] for actionability
+    ///
+    /// For localized or domain-specific lists. Matching ignores case.
+    ///
+    /// [`DEFAULT_ACTION_VERBS`]: crate::models::todo::DEFAULT_ACTION_VERBS
+    #[must_use]
+    pub fn with_action_verbs<I, S>(mut self, verbs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.action_verbs = Some(verbs.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether task text starts with one of the configured action verbs
+    fn is_actionable(&self, content: &str) -> bool {
+        self.action_verbs.as_ref().map_or_else(
+            || starts_with_any_verb(content, DEFAULT_ACTION_VERBS),
+            |verbs| starts_with_any_verb(content, verbs),
+        )
+    }
+
+    /// Use a custom provider for improvement suggestion text
+    #[must_use]
+    pub fn with_suggestion_provider(mut self, provider: Arc<dyn SuggestionProvider>) -> Self {
+        self.suggestion_provider = provider;
+        self
     }
 
     /// Validate a complete todo list
     pub fn validate_todo_list(&self, todo_list: &TodoList) -> TodoValidationResult {
+        self.validate_reusing(todo_list, None)
+    }
+
+    /// Revalidate a list, reusing per-todo issues from a previous run
+    ///
+    /// Todos identical to ones validated in `previous` keep their earlier
+    /// issues; changed or new todos are validated again. Structure, dependency
+    /// and other list-wide checks always run in full, so the result matches
+    /// [`TodoValidator::validate_todo_list`] as long as `previous` came from a
+    /// validator with the same configuration. A deserialized result carries no
+    /// per-todo history, so every todo is revalidated.
+    pub fn validate_incremental(
+        &self,
+        todo_list: &TodoList,
+        previous: &TodoValidationResult,
+    ) -> TodoValidationResult {
+        self.validate_reusing(todo_list, Some(previous))
+    }
+
+    fn validate_reusing(
+        &self,
+        todo_list: &TodoList,
+        previous: Option<&TodoValidationResult>,
+    ) -> TodoValidationResult {
         let mut issues = Vec::new();
 
         // Validate overall structure
         self.validate_structure(todo_list, &mut issues);
 
         // Validate individual todos
+        let unit = todo_list.metadata.estimate_unit;
+        let mut todo_issues = HashMap::with_capacity(todo_list.todos.len());
         for todo in &todo_list.todos {
-            self.validate_todo(todo, &mut issues);
+            let fingerprint = todo_fingerprint(todo, unit);
+            let own = previous
+                .and_then(|previous| previous.todo_issues.get(&fingerprint))
+                .cloned()
+                .unwrap_or_else(|| {
+                    let mut own = Vec::new();
+                    self.validate_todo(todo, unit, &mut own);
+                    own
+                });
+            issues.extend(own.iter().cloned());
+            todo_issues.insert(fingerprint, own);
         }
 
         // Validate dependencies
         self.validate_dependencies(todo_list, &mut issues);
+        Self::validate_priority_inversions(todo_list, &mut issues);
+        self.validate_priority_depth(todo_list, &mut issues);
+        self.validate_critical_path_length(todo_list, &mut issues);
+        Self::validate_estimate_variance(todo_list, &mut issues);
+        self.validate_over_decomposition(todo_list, &mut issues);
+        self.validate_tag_vocabulary(todo_list, &mut issues);
+
+        // Derive quality gates from the issues found
+        let quality_gates = quality_gates_from_issues(todo_list, &issues);
 
         // Calculate metrics
-        let metrics = self.calculate_metrics(todo_list);
+        let mut metrics = self.calculate_metrics(todo_list);
+        metrics.quality_gate_pass_rate = gate_pass_rate(&quality_gates);
+        let quality_score = self.quality_score(&metrics);
 
         // Generate suggestions
         let suggestions = self.generate_suggestions(&issues, &metrics);
@@ -171,24 +439,91 @@ impl TodoValidator {
             issues,
             metrics,
             suggestions,
+            quality_score,
+            quality_gates,
+            todo_issues,
         }
     }
 
-    /// Validate individual todo
-    fn validate_todo(&self, todo: &Todo, issues: &mut Vec<ValidationIssue>) {
+    /// Validate a generated todo list against the input it was generated from
+    ///
+    /// Runs [`TodoValidator::validate_todo_list`] and additionally flags todos
+    /// whose time estimates do not match `input.include_estimates`.
+    pub fn validate_for_input(
+        &self,
+        todo_list: &TodoList,
+        input: &TodoInput,
+    ) -> TodoValidationResult {
+        let mut result = self.validate_todo_list(todo_list);
+        self.validate_estimate_presence(todo_list, input.include_estimates, &mut result.issues);
+        result.quality_gates = quality_gates_from_issues(todo_list, &result.issues);
+        result.metrics.quality_gate_pass_rate = gate_pass_rate(&result.quality_gates);
+        result
+    }
+
+    /// Flag estimates that contradict whether estimates were requested
+    fn validate_estimate_presence(
+        &self,
+        todo_list: &TodoList,
+        include_estimates: bool,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        for todo in &todo_list.todos {
+            let (message, suggestion) = match (include_estimates, todo.estimated_hours) {
+                (false, Some(hours)) => (
+                    format!(
+                        "Todo has a {:.1}h estimate but estimates were not requested",
+                        hours
+                    ),
+                    "Remove the estimate or enable include_estimates",
+                ),
+                // Already reported as an error when estimates are required
+                (true, None) if !self.config.require_time_estimates => (
+                    "Todo is missing a time estimate although estimates were requested".to_string(),
+                    "Add estimated_hours or disable include_estimates",
+                ),
+                _ => continue,
+            };
+
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::TimeEstimate,
+                code: None,
+                todo_id: Some(todo.id.clone()),
+                message,
+                suggestion: Some(suggestion.to_string()),
+            });
+        }
+    }
+
+    /// Validate the text of a single todo (actionability, length and specificity)
+    pub(crate) fn validate_content(
+        &self,
+        todo_id: &str,
+        content: &str,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
         // Check actionability
-        if !todo.is_actionable() {
+        if !self.is_actionable(content) {
+            let suggestion = self.action_verbs.as_ref().map_or_else(
+                || "Start with verbs like 'implement', 'create', 'add', 'fix', etc.".to_string(),
+                |verbs| {
+                    let verbs: Vec<String> = verbs.iter().map(|v| format!("'{}'", v)).collect();
+                    format!("Start with one of: {}", verbs.join(", "))
+                },
+            );
             issues.push(ValidationIssue {
                 severity: IssueSeverity::Error,
                 category: IssueCategory::Actionability,
-                todo_id: Some(todo.id.clone()),
+                code: Some(IssueCode::NotActionable {
+                    content: content.to_string(),
+                }),
+                todo_id: Some(todo_id.to_string()),
                 message: format!(
                     "Todo '{}' is not actionable - should start with action verb",
-                    todo.content
-                ),
-                suggestion: Some(
-                    "Start with verbs like 'implement', 'create', 'add', 'fix', etc.".to_string(),
+                    content
                 ),
+                suggestion: Some(suggestion),
             });
         }
 
@@ -196,14 +531,15 @@ impl TodoValidator {
         let min_chars = self.config.min_task_detail_chars.unwrap_or(10);
         let max_chars = self.config.max_task_detail_chars.unwrap_or(100);
 
-        if todo.content.len() < min_chars {
+        if content.len() < min_chars {
             issues.push(ValidationIssue {
                 severity: IssueSeverity::Warning,
                 category: IssueCategory::Completeness,
-                todo_id: Some(todo.id.clone()),
+                code: None,
+                todo_id: Some(todo_id.to_string()),
                 message: format!(
                     "Todo content too short: {} chars (min {})",
-                    todo.content.len(),
+                    content.len(),
                     min_chars
                 ),
                 suggestion: Some(
@@ -212,27 +548,73 @@ impl TodoValidator {
             });
         }
 
-        if todo.content.len() > max_chars {
+        if content.len() > max_chars {
             issues.push(ValidationIssue {
                 severity: IssueSeverity::Warning,
                 category: IssueCategory::Completeness,
-                todo_id: Some(todo.id.clone()),
+                code: None,
+                todo_id: Some(todo_id.to_string()),
                 message: format!(
                     "Todo content too long: {} chars (max {})",
-                    todo.content.len(),
+                    content.len(),
                     max_chars
                 ),
                 suggestion: Some("Break this into smaller, more focused tasks".to_string()),
             });
         }
 
+        // Check that the text says enough, not just that it is long enough
+        if let Some(min_tokens) = self.config.min_meaningful_tokens {
+            let tokens = meaningful_token_count(content);
+            if tokens < min_tokens {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Warning,
+                    category: IssueCategory::Completeness,
+                    code: None,
+                    todo_id: Some(todo_id.to_string()),
+                    message: format!(
+                        "Todo content too vague: {} meaningful words (min {})",
+                        tokens, min_tokens
+                    ),
+                    suggestion: Some(
+                        "Name the component, behavior or outcome the task is about".to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Allowed `(min, max)` estimate for the list's unit
+    ///
+    /// The configured hour bounds apply to hour estimates; other units use
+    /// their own default range.
+    fn estimate_bounds(&self, unit: EstimateUnit) -> (f32, f32) {
+        let (min, max) = unit.default_bounds();
+        match unit {
+            EstimateUnit::Hours => (
+                self.config.min_estimated_hours.unwrap_or(min),
+                self.config.max_estimated_hours.unwrap_or(max),
+            ),
+            EstimateUnit::Points | EstimateUnit::Days => (min, max),
+        }
+    }
+
+    /// Validate individual todo
+    fn validate_todo(&self, todo: &Todo, unit: EstimateUnit, issues: &mut Vec<ValidationIssue>) {
+        // Check actionability and content length
+        self.validate_content(&todo.id, &todo.content, issues);
+
         // Check complexity
         if let Some(max_complexity) = self.config.max_complexity_per_task {
-            let complexity = todo.complexity_score();
+            let complexity = todo.complexity_score_with(&self.complexity_model);
             if complexity > max_complexity {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::Complexity,
+                    code: Some(IssueCode::ComplexityExceeded {
+                        score: complexity,
+                        max: max_complexity,
+                    }),
                     todo_id: Some(todo.id.clone()),
                     message: format!(
                         "Todo complexity {} exceeds maximum {}",
@@ -248,6 +630,7 @@ impl TodoValidator {
             issues.push(ValidationIssue {
                 severity: IssueSeverity::Error,
                 category: IssueCategory::TimeEstimate,
+                code: Some(IssueCode::MissingEstimate),
                 todo_id: Some(todo.id.clone()),
                 message: "Todo missing time estimate".to_string(),
                 suggestion: Some(
@@ -256,18 +639,19 @@ impl TodoValidator {
             });
         }
 
-        if let Some(hours) = todo.estimated_hours {
-            let min_hours = self.config.min_estimated_hours.unwrap_or(0.5);
-            let max_hours = self.config.max_estimated_hours.unwrap_or(40.0);
+        if let Some(estimate) = todo.estimated_hours {
+            let (min_estimate, max_estimate) = self.estimate_bounds(unit);
+            let suffix = unit.suffix();
 
-            if hours < min_hours {
+            if estimate < min_estimate {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Warning,
                     category: IssueCategory::TimeEstimate,
+                    code: None,
                     todo_id: Some(todo.id.clone()),
                     message: format!(
-                        "Time estimate {:.1}h seems too low (min {:.1}h)",
-                        hours, min_hours
+                        "Time estimate {:.1}{} seems too low (min {:.1}{})",
+                        estimate, suffix, min_estimate, suffix
                     ),
                     suggestion: Some(
                         "Consider if this task really needs so little time".to_string(),
@@ -275,20 +659,78 @@ impl TodoValidator {
                 });
             }
 
-            if hours > max_hours {
+            if estimate > max_estimate {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::TimeEstimate,
+                    code: None,
                     todo_id: Some(todo.id.clone()),
                     message: format!(
-                        "Time estimate {:.1}h exceeds maximum {:.1}h",
-                        hours, max_hours
+                        "Time estimate {:.1}{} exceeds maximum {:.1}{}",
+                        estimate, suffix, max_estimate, suffix
                     ),
                     suggestion: Some("Break this large task into smaller chunks".to_string()),
                 });
             }
         }
 
+        // Check estimates follow the configured granule
+        if let (Some(hours), Some(granule)) = (todo.estimated_hours, self.config.estimate_granule) {
+            let steps = hours / granule;
+            if granule > 0.0 && (steps - steps.round()).abs() > 1e-3 {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Info,
+                    category: IssueCategory::TimeEstimate,
+                    code: None,
+                    todo_id: Some(todo.id.clone()),
+                    message: format!("Time estimate {}h is not a multiple of {}h", hours, granule),
+                    suggestion: Some(format!(
+                        "Round to {}h or use TodoList::round_estimates",
+                        (steps.round() * granule).max(granule)
+                    )),
+                });
+            }
+        }
+
+        // Check estimate confidence is a valid probability
+        if let Some(confidence) = todo.estimate_confidence {
+            if !(0.0..=1.0).contains(&confidence) {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Error,
+                    category: IssueCategory::TimeEstimate,
+                    code: None,
+                    todo_id: Some(todo.id.clone()),
+                    message: format!(
+                        "Estimate confidence {} is outside the range 0.0-1.0",
+                        confidence
+                    ),
+                    suggestion: Some("Set estimate_confidence between 0.0 and 1.0".to_string()),
+                });
+            }
+        }
+
+        Self::validate_links(todo, issues);
+        self.validate_custom_fields(todo, issues);
+
+        // Check that blocked todos explain why
+        if todo.status == TodoStatus::Blocked
+            && todo
+                .blocked_reason
+                .as_deref()
+                .is_none_or(|reason| reason.trim().is_empty())
+        {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Error,
+                category: IssueCategory::Completeness,
+                code: None,
+                todo_id: Some(todo.id.clone()),
+                message: "Blocked todo has no blocked reason".to_string(),
+                suggestion: Some(
+                    "Set blocked_reason to describe what is preventing progress".to_string(),
+                ),
+            });
+        }
+
         // Check for generic or vague language
         if self.config.require_specific_actions {
             let generic_words = [
@@ -306,6 +748,7 @@ impl TodoValidator {
                     issues.push(ValidationIssue {
                         severity: IssueSeverity::Warning,
                         category: IssueCategory::Completeness,
+                        code: None,
                         todo_id: Some(todo.id.clone()),
                         message: format!("Todo contains generic language: '{}'", word),
                         suggestion: Some(
@@ -318,6 +761,53 @@ impl TodoValidator {
         }
     }
 
+    /// Flag custom fields nested or sized beyond the configured limits
+    fn validate_custom_fields(&self, todo: &Todo, issues: &mut Vec<ValidationIssue>) {
+        if let Some(max_depth) = self.config.max_custom_field_depth {
+            let mut names: Vec<&String> = todo.custom_fields.keys().collect();
+            names.sort();
+            for name in names {
+                let depth = json_depth(&todo.custom_fields[name]);
+                if depth > max_depth {
+                    issues.push(ValidationIssue {
+                        severity: IssueSeverity::Warning,
+                        category: IssueCategory::Structure,
+                        code: None,
+                        todo_id: Some(todo.id.clone()),
+                        message: format!(
+                            "Custom field '{}' is nested {} levels deep (max {})",
+                            name, depth, max_depth
+                        ),
+                        suggestion: Some(
+                            "Flatten the value or link to the data instead of embedding it"
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
+        }
+
+        if let Some(max_bytes) = self.config.max_custom_fields_bytes {
+            let size = serde_json::to_string(&todo.custom_fields).map_or(0, |json| json.len());
+            if size > max_bytes {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Warning,
+                    category: IssueCategory::Structure,
+                    code: None,
+                    todo_id: Some(todo.id.clone()),
+                    message: format!(
+                        "Custom fields take {} bytes serialized (max {})",
+                        size, max_bytes
+                    ),
+                    suggestion: Some(
+                        "Move large payloads out of the todo and reference them by link"
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
     /// Validate overall structure
     fn validate_structure(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
         let count = todo_list.todos.len();
@@ -328,6 +818,10 @@ impl TodoValidator {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::Structure,
+                    code: Some(IssueCode::CountLimit {
+                        count,
+                        limit: max_todos,
+                    }),
                     todo_id: None,
                     message: format!("Todo count {} exceeds maximum {}", count, max_todos),
                     suggestion: Some("Split into multiple smaller todo lists".to_string()),
@@ -339,6 +833,7 @@ impl TodoValidator {
             issues.push(ValidationIssue {
                 severity: IssueSeverity::Error,
                 category: IssueCategory::Structure,
+                code: None,
                 todo_id: None,
                 message: "Todo list is empty".to_string(),
                 suggestion: Some("Add at least one todo item".to_string()),
@@ -352,6 +847,7 @@ impl TodoValidator {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::Structure,
+                    code: None,
                     todo_id: Some(todo.id.clone()),
                     message: format!("Duplicate todo ID: {}", todo.id),
                     suggestion: Some("Ensure all todo IDs are unique".to_string()),
@@ -367,6 +863,7 @@ impl TodoValidator {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Warning,
                     category: IssueCategory::Structure,
+                    code: None,
                     todo_id: Some(todo.id.clone()),
                     message: format!("Duplicate todo content with ID: {}", other_id),
                     suggestion: Some(
@@ -377,6 +874,30 @@ impl TodoValidator {
         }
     }
 
+    /// Flag large lists with no sequencing at all
+    fn validate_flat_list(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let Some(max_flat) = self.config.max_flat_list_size else {
+            return;
+        };
+
+        let total = todo_list.todos.len();
+        if total > max_flat && todo_list.todos.iter().all(|t| t.dependencies.is_empty()) {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::Dependencies,
+                code: None,
+                todo_id: None,
+                message: format!(
+                    "None of the {} todos have dependencies (flat list above {})",
+                    total, max_flat
+                ),
+                suggestion: Some(
+                    "Model the order of work by adding dependencies between todos".to_string(),
+                ),
+            });
+        }
+    }
+
     /// Validate dependencies
     fn validate_dependencies(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
         if !self.config.require_dependency_graph {
@@ -385,6 +906,8 @@ impl TodoValidator {
 
         let todo_ids: HashSet<String> = todo_list.todos.iter().map(|t| t.id.clone()).collect();
 
+        self.validate_flat_list(todo_list, issues);
+
         // Check for invalid dependency references
         for todo in &todo_list.todos {
             for dep_id in &todo.dependencies {
@@ -392,6 +915,9 @@ impl TodoValidator {
                     issues.push(ValidationIssue {
                         severity: IssueSeverity::Error,
                         category: IssueCategory::Dependencies,
+                        code: Some(IssueCode::DependencyNotFound {
+                            dependency: dep_id.clone(),
+                        }),
                         todo_id: Some(todo.id.clone()),
                         message: format!("Dependency '{}' not found", dep_id),
                         suggestion: Some(
@@ -408,6 +934,9 @@ impl TodoValidator {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::Dependencies,
+                    code: Some(IssueCode::CircularDependency {
+                        cycle: cycle.clone(),
+                    }),
                     todo_id: None,
                     message: format!("Circular dependency detected: {}", cycle.join(" -> ")),
                     suggestion: Some(
@@ -423,78 +952,341 @@ impl TodoValidator {
                 issues.push(ValidationIssue {
                     severity: IssueSeverity::Error,
                     category: IssueCategory::Dependencies,
+                    code: None,
                     todo_id: Some(todo.id.clone()),
                     message: "Todo depends on itself".to_string(),
                     suggestion: Some("Remove self-dependency".to_string()),
                 });
             }
         }
-    }
-
-    /// Calculate quality metrics
-    fn calculate_metrics(&self, todo_list: &TodoList) -> TodoMetrics {
-        let total_count = todo_list.todos.len();
-        let mut actionable_count = 0;
-        let mut proper_length_count = 0;
-        let mut estimated_count = 0;
-        let mut reasonable_complexity_count = 0;
-        let mut total_complexity = 0u32;
-        let mut total_length = 0usize;
-        let mut total_estimated_hours = 0.0f32;
-
-        let min_chars = self.config.min_task_detail_chars.unwrap_or(10);
-        let max_chars = self.config.max_task_detail_chars.unwrap_or(100);
-        let max_complexity = self.config.max_complexity_per_task.unwrap_or(8);
 
-        for todo in &todo_list.todos {
-            if todo.is_actionable() {
-                actionable_count += 1;
+        // Check that completed todos do not depend on unfinished work
+        let statuses: HashMap<&str, TodoStatus> = todo_list
+            .todos
+            .iter()
+            .map(|t| (t.id.as_str(), t.status))
+            .collect();
+        for todo in todo_list
+            .todos
+            .iter()
+            .filter(|t| t.status == TodoStatus::Completed)
+        {
+            for dep_id in &todo.dependencies {
+                if let Some(&dep_status) = statuses.get(dep_id.as_str()) {
+                    if dep_status != TodoStatus::Completed && dep_status != TodoStatus::Cancelled {
+                        issues.push(ValidationIssue {
+                            severity: IssueSeverity::Error,
+                            category: IssueCategory::Dependencies,
+                            code: None,
+                            todo_id: Some(todo.id.clone()),
+                            message: format!(
+                                "Completed todo depends on '{}' which is still {}",
+                                dep_id, dep_status
+                            ),
+                            suggestion: Some(
+                                "Reopen this todo or update the status of its dependency"
+                                    .to_string(),
+                            ),
+                        });
+                    }
+                }
             }
+        }
 
-            if todo.has_valid_length(min_chars, max_chars) {
-                proper_length_count += 1;
+        // Check for duplicate dependency entries
+        for todo in &todo_list.todos {
+            let mut seen_deps = HashSet::new();
+            let mut reported = HashSet::new();
+            for dep_id in &todo.dependencies {
+                if !seen_deps.insert(dep_id.as_str()) && reported.insert(dep_id.as_str()) {
+                    issues.push(ValidationIssue {
+                        severity: IssueSeverity::Info,
+                        category: IssueCategory::Dependencies,
+                        code: None,
+                        todo_id: Some(todo.id.clone()),
+                        message: format!("Dependency '{}' is listed more than once", dep_id),
+                        suggestion: Some(
+                            "Remove duplicate entries with Todo::dedup_dependencies".to_string(),
+                        ),
+                    });
+                }
             }
+        }
+    }
 
-            if todo.estimated_hours.is_some() {
-                estimated_count += 1;
-                total_estimated_hours += todo.estimated_hours.unwrap_or(0.0);
-            }
+    /// Flag links whose URL is not an http(s) address
+    fn validate_links(todo: &Todo, issues: &mut Vec<ValidationIssue>) {
+        for link in todo.links.iter().filter(|link| !link.has_web_url()) {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Error,
+                category: IssueCategory::Completeness,
+                code: None,
+                todo_id: Some(todo.id.clone()),
+                message: format!("Link '{}' has invalid URL '{}'", link.title, link.url),
+                suggestion: Some("Use an http:// or https:// URL".to_string()),
+            });
+        }
+    }
 
-            let complexity = todo.complexity_score();
-            total_complexity += complexity as u32;
+    /// Flag lists where every estimate is (nearly) the same placeholder value
+    fn validate_estimate_variance(todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let estimates: Vec<f32> = todo_list
+            .todos
+            .iter()
+            .filter_map(|t| t.estimated_hours)
+            .collect();
+        if estimates.len() < UNIFORM_ESTIMATE_MIN_TODOS {
+            return;
+        }
 
-            if complexity <= max_complexity {
-                reasonable_complexity_count += 1;
-            }
+        let count = count_as_f32(estimates.len());
+        let mean = estimates.iter().sum::<f32>() / count;
+        let variance = estimates.iter().map(|h| (h - mean).powi(2)).sum::<f32>() / count;
 
-            total_length += todo.content.len();
+        if variance < UNIFORM_ESTIMATE_MAX_VARIANCE {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Info,
+                category: IssueCategory::TimeEstimate,
+                code: None,
+                todo_id: None,
+                message: format!(
+                    "All {} estimates are about {:.1}h; the plan may not have been estimated",
+                    estimates.len(),
+                    mean
+                ),
+                suggestion: Some("Estimate each todo individually based on its scope".to_string()),
+            });
         }
+    }
 
-        let avg_complexity = if total_count > 0 {
-            total_complexity as f32 / total_count as f32
-        } else {
-            0.0
-        };
-
-        let avg_task_length = if total_count > 0 {
-            total_length as f32 / total_count as f32
-        } else {
-            0.0
+    /// Flag plans where most todos are too small to be worth tracking
+    fn validate_over_decomposition(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let (Some(min_useful), Some(max_fraction)) = (
+            self.config.min_useful_estimate_hours,
+            self.config.max_tiny_todo_fraction,
+        ) else {
+            return;
         };
+        if todo_list.metadata.estimate_unit != EstimateUnit::Hours {
+            return;
+        }
 
-        // Calculate dependency metrics
-        let dependency_metrics = self.calculate_dependency_metrics(todo_list);
+        let estimates: Vec<f32> = todo_list
+            .todos
+            .iter()
+            .filter_map(|t| t.estimated_hours)
+            .collect();
+        if estimates.len() < OVER_DECOMPOSITION_MIN_TODOS {
+            return;
+        }
 
-        TodoMetrics {
-            total_count,
-            actionable_count,
-            proper_length_count,
-            estimated_count,
-            reasonable_complexity_count,
-            avg_complexity,
+        let tiny = estimates.iter().filter(|&&h| h < min_useful).count();
+        if count_as_f32(tiny) / count_as_f32(estimates.len()) > max_fraction {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Info,
+                category: IssueCategory::Structure,
+                code: None,
+                todo_id: None,
+                message: format!(
+                    "{} of {} estimated todos are under {:.1}h; the plan may be over-decomposed",
+                    tiny,
+                    estimates.len(),
+                    min_useful
+                ),
+                suggestion: Some(
+                    "Consolidate closely related small todos into meaningful units of work"
+                        .to_string(),
+                ),
+            });
+        }
+    }
+
+    /// Flag tags outside the configured vocabulary
+    fn validate_tag_vocabulary(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let Some(vocabulary) = &self.tag_vocabulary else {
+            return;
+        };
+
+        for todo in &todo_list.todos {
+            for tag in todo.tags.iter().filter(|tag| !vocabulary.contains(tag)) {
+                let closest = vocabulary.iter().min_by_key(|allowed| {
+                    edit_distance(&tag.to_lowercase(), &allowed.to_lowercase())
+                });
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Warning,
+                    category: IssueCategory::Structure,
+                    code: None,
+                    todo_id: Some(todo.id.clone()),
+                    message: format!("Tag '{}' is not in the tag vocabulary", tag),
+                    suggestion: closest.map(|allowed| format!("Use the tag '{}' instead", allowed)),
+                });
+            }
+        }
+    }
+
+    /// Flag prerequisites with lower priority than the todos that depend on them
+    fn validate_priority_inversions(todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let priorities: HashMap<&str, TodoPriority> = todo_list
+            .todos
+            .iter()
+            .map(|t| (t.id.as_str(), t.priority))
+            .collect();
+        for todo in &todo_list.todos {
+            for dep_id in &todo.dependencies {
+                if let Some(&dep_priority) = priorities.get(dep_id.as_str()) {
+                    if dep_priority < todo.priority {
+                        issues.push(ValidationIssue {
+                            severity: IssueSeverity::Warning,
+                            category: IssueCategory::Dependencies,
+                            code: None,
+                            todo_id: Some(todo.id.clone()),
+                            message: format!(
+                                "Priority inversion: {} priority todo depends on '{}' with {} priority",
+                                todo.priority, dep_id, dep_priority
+                            ),
+                            suggestion: Some(
+                                "Raise the prerequisite's priority, e.g. with TodoList::resolve_priority_inversions"
+                                    .to_string(),
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Flag high-priority todos buried deep in the dependency chain
+    fn validate_priority_depth(&self, todo_list: &TodoList, issues: &mut Vec<ValidationIssue>) {
+        let Some(max_depth) = self.config.max_priority_depth else {
+            return;
+        };
+        // Depths are only meaningful for an acyclic graph
+        if todo_list.validate_dependencies().is_err() {
+            return;
+        }
+
+        let mut depth_cache = HashMap::new();
+        for todo in todo_list
+            .todos
+            .iter()
+            .filter(|t| t.priority >= TodoPriority::High)
+        {
+            let depth = self.calculate_todo_depth(&todo.id, todo_list, &mut depth_cache);
+            if depth > max_depth {
+                issues.push(ValidationIssue {
+                    severity: IssueSeverity::Warning,
+                    category: IssueCategory::Dependencies,
+                    code: None,
+                    todo_id: Some(todo.id.clone()),
+                    message: format!(
+                        "Todo with {} priority is at dependency depth {} (max {})",
+                        todo.priority, depth, max_depth
+                    ),
+                    suggestion: Some(
+                        "Re-sequence the work or split its prerequisites so it can start sooner"
+                            .to_string(),
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Flag lists whose longest dependency chain is too long to parallelize
+    fn validate_critical_path_length(
+        &self,
+        todo_list: &TodoList,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let Some(max_length) = self.config.max_critical_path_length else {
+            return;
+        };
+
+        let path = todo_list.critical_path();
+        if path.len() > max_length {
+            issues.push(ValidationIssue {
+                severity: IssueSeverity::Warning,
+                category: IssueCategory::Dependencies,
+                code: None,
+                todo_id: None,
+                message: format!(
+                    "Critical path has {} todos (max {}): {}",
+                    path.len(),
+                    max_length,
+                    path.join(" -> ")
+                ),
+                suggestion: Some(
+                    "Break dependencies that are not strictly required so more work can run in parallel"
+                        .to_string(),
+                ),
+            });
+        }
+    }
+
+    /// Calculate quality metrics
+    fn calculate_metrics(&self, todo_list: &TodoList) -> TodoMetrics {
+        let total_count = todo_list.todos.len();
+        let mut actionable_count = 0;
+        let mut proper_length_count = 0;
+        let mut estimated_count = 0;
+        let mut reasonable_complexity_count = 0;
+        let mut total_complexity = 0u32;
+        let mut total_length = 0usize;
+        let mut total_estimated_hours = 0.0f32;
+
+        let min_chars = self.config.min_task_detail_chars.unwrap_or(10);
+        let max_chars = self.config.max_task_detail_chars.unwrap_or(100);
+        let max_complexity = self.config.max_complexity_per_task.unwrap_or(8);
+
+        for todo in &todo_list.todos {
+            if self.is_actionable(&todo.content) {
+                actionable_count += 1;
+            }
+
+            if todo.has_valid_length(min_chars, max_chars) {
+                proper_length_count += 1;
+            }
+
+            if todo.estimated_hours.is_some() {
+                estimated_count += 1;
+                total_estimated_hours += todo.estimated_hours.unwrap_or(0.0);
+            }
+
+            let complexity = todo.complexity_score_with(&self.complexity_model);
+            total_complexity += complexity as u32;
+
+            if complexity <= max_complexity {
+                reasonable_complexity_count += 1;
+            }
+
+            total_length += todo.content.len();
+        }
+
+        let avg_complexity = if total_count > 0 {
+            total_complexity as f32 / total_count as f32
+        } else {
+            0.0
+        };
+
+        let avg_task_length = if total_count > 0 {
+            total_length as f32 / total_count as f32
+        } else {
+            0.0
+        };
+
+        // Calculate dependency metrics
+        let dependency_metrics = self.calculate_dependency_metrics(todo_list);
+
+        TodoMetrics {
+            total_count,
+            actionable_count,
+            proper_length_count,
+            estimated_count,
+            reasonable_complexity_count,
+            avg_complexity,
             avg_task_length,
             total_estimated_hours,
             dependency_metrics,
+            quality_gate_pass_rate: 0.0,
         }
     }
 
@@ -516,8 +1308,10 @@ impl TodoValidator {
         let (max_depth, critical_path_length) = if has_cycles {
             (0, 0)
         } else {
-            let depth = self.calculate_max_dependency_depth(todo_list);
-            (depth, depth)
+            (
+                self.calculate_max_dependency_depth(todo_list),
+                todo_list.critical_path().len(),
+            )
         };
 
         DependencyMetrics {
@@ -590,58 +1384,60 @@ impl TodoValidator {
         _issues: &[ValidationIssue],
         metrics: &TodoMetrics,
     ) -> Vec<String> {
-        let mut suggestions = Vec::new();
+        let mut kinds = Vec::new();
 
         // Actionability suggestions
         if metrics.actionable_count < metrics.total_count {
-            let non_actionable = metrics.total_count - metrics.actionable_count;
-            suggestions.push(format!(
-                "Make {} todos more actionable by starting with action verbs (implement, create, add, etc.)",
-                non_actionable
-            ));
+            kinds.push(SuggestionKind::NonActionable {
+                count: metrics.total_count - metrics.actionable_count,
+            });
         }
 
         // Complexity suggestions
         if metrics.reasonable_complexity_count < metrics.total_count {
-            suggestions.push(
-                "Break down complex tasks (complexity > 8) into smaller, focused subtasks"
-                    .to_string(),
-            );
+            kinds.push(SuggestionKind::ExcessiveComplexity {
+                max_complexity: self.config.max_complexity_per_task.unwrap_or(8),
+            });
         }
 
         // Time estimate suggestions
         if self.config.require_time_estimates && metrics.estimated_count < metrics.total_count {
-            let missing_estimates = metrics.total_count - metrics.estimated_count;
-            suggestions.push(format!(
-                "Add time estimates to {} todos for better project planning",
-                missing_estimates
-            ));
+            kinds.push(SuggestionKind::MissingEstimates {
+                count: metrics.total_count - metrics.estimated_count,
+            });
         }
 
         // Dependency suggestions
         if metrics.dependency_metrics.has_cycles {
-            suggestions
-                .push("Remove circular dependencies to enable proper task ordering".to_string());
+            kinds.push(SuggestionKind::CircularDependencies);
         }
 
         if metrics.dependency_metrics.todos_with_dependencies == 0 && metrics.total_count > 1 {
-            suggestions.push(
-                "Consider adding dependencies between related tasks for better sequencing"
-                    .to_string(),
-            );
+            kinds.push(SuggestionKind::NoDependencies);
         }
 
         // Overall quality suggestions
-        let quality_score = self.calculate_quality_score(metrics);
+        let quality_score = self.quality_score(metrics);
         if quality_score < 0.8 {
-            suggestions.push("Overall todo list quality could be improved - focus on specific, actionable tasks with realistic estimates".to_string());
+            kinds.push(SuggestionKind::LowQuality {
+                score: quality_score,
+            });
         }
 
-        suggestions
+        kinds
+            .iter()
+            .filter_map(|kind| self.suggestion_provider.suggest(kind))
+            .collect()
     }
 
-    /// Calculate overall quality score (0.0 to 1.0)
-    fn calculate_quality_score(&self, metrics: &TodoMetrics) -> f32 {
+    /// Overall quality score (0.0 to 1.0) for a list's metrics
+    ///
+    /// A weighted average of the actionable, properly sized, reasonably
+    /// complex and estimated shares of todos plus a cycle-free dependency
+    /// graph, using the normalized [`QualityScoreWeights`]. Estimates count
+    /// as complete when the configuration does not require them. An empty
+    /// list scores 0.0.
+    pub fn quality_score(&self, metrics: &TodoMetrics) -> f32 {
         if metrics.total_count == 0 {
             return 0.0;
         }
@@ -664,20 +1460,176 @@ impl TodoValidator {
         };
 
         // Weighted average
-        actionability_score * 0.3
-            + length_score * 0.2
-            + complexity_score * 0.2
-            + estimate_score * 0.2
-            + dependency_score * 0.1
+        let weights = self.score_weights.normalized();
+        actionability_score * weights.actionability
+            + length_score * weights.length
+            + complexity_score * weights.complexity
+            + estimate_score * weights.estimates
+            + dependency_score * weights.dependencies
     }
 }
 
+/// Nesting depth of a JSON value: 0 for scalars, 1 for a flat array or object
+fn json_depth(value: &serde_json::Value) -> usize {
+    let children: Box<dyn Iterator<Item = &serde_json::Value>> = match value {
+        serde_json::Value::Array(items) => Box::new(items.iter()),
+        serde_json::Value::Object(map) => Box::new(map.values()),
+        _ => return 0,
+    };
+    1 + children.map(json_depth).max().unwrap_or(0)
+}
+
+/// Levenshtein distance between two strings, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 impl Default for TodoValidator {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Quality gates for each todo: a gate fails when the todo has an error or
+/// warning in the matching category
+fn quality_gates_from_issues(
+    todo_list: &TodoList,
+    issues: &[ValidationIssue],
+) -> HashMap<String, TodoQualityGates> {
+    todo_list
+        .todos
+        .iter()
+        .map(|todo| {
+            let passes = |category: IssueCategory| {
+                !issues.iter().any(|issue| {
+                    issue.category == category
+                        && issue.severity != IssueSeverity::Info
+                        && issue.todo_id.as_deref() == Some(todo.id.as_str())
+                })
+            };
+            let gates = TodoQualityGates {
+                complexity_check: passes(IssueCategory::Complexity),
+                completeness_check: passes(IssueCategory::Completeness),
+                actionability_check: passes(IssueCategory::Actionability),
+                time_estimate_check: passes(IssueCategory::TimeEstimate),
+                custom_checks: HashMap::new(),
+            };
+            (todo.id.clone(), gates)
+        })
+        .collect()
+}
+
+/// Share of todos passing all four quality gates
+fn gate_pass_rate(gates: &HashMap<String, TodoQualityGates>) -> f32 {
+    if gates.is_empty() {
+        return 0.0;
+    }
+    let passing = gates
+        .values()
+        .filter(|g| {
+            g.complexity_check
+                && g.completeness_check
+                && g.actionability_check
+                && g.time_estimate_check
+        })
+        .count();
+    count_as_f32(passing) / count_as_f32(gates.len())
+}
+
+/// Hash of everything per-todo validation looks at
+fn todo_fingerprint(todo: &Todo, unit: EstimateUnit) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    unit.label().hash(&mut hasher);
+    serde_json::to_string(todo)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+impl TodoValidationResult {
+    /// Copy the computed quality gates onto the validated list's todos
+    ///
+    /// Custom checks already on a todo are kept. Todos without a computed
+    /// gate, such as ones added after validation, are left unchanged.
+    pub fn apply_quality_gates(&self, todo_list: &mut TodoList) {
+        for todo in &mut todo_list.todos {
+            if let Some(gates) = self.quality_gates.get(&todo.id) {
+                todo.quality_gates.complexity_check = gates.complexity_check;
+                todo.quality_gates.completeness_check = gates.completeness_check;
+                todo.quality_gates.actionability_check = gates.actionability_check;
+                todo.quality_gates.time_estimate_check = gates.time_estimate_check;
+            }
+        }
+    }
+
+    /// Complexity issues ordered by how far each todo exceeds the limit
+    ///
+    /// The worst offenders come first so they can be split up before the rest;
+    /// issues with equal overage keep their original order.
+    pub fn complexity_hotspots(&self) -> Vec<&ValidationIssue> {
+        let mut hotspots: Vec<(&ValidationIssue, u8)> = self
+            .issues
+            .iter()
+            .filter(|issue| issue.category == IssueCategory::Complexity)
+            .map(|issue| (issue, complexity_overage(issue)))
+            .collect();
+        hotspots.sort_by_key(|&(_, overage)| std::cmp::Reverse(overage));
+        hotspots.into_iter().map(|(issue, _)| issue).collect()
+    }
+}
+
+/// Amount by which a complexity issue's score exceeds its maximum, 0 if unknown
+const fn complexity_overage(issue: &ValidationIssue) -> u8 {
+    match &issue.code {
+        Some(IssueCode::ComplexityExceeded { score, max }) => score.saturating_sub(*max),
+        _ => 0,
+    }
+}
+
+impl ValidationIssue {
+    /// Convert an error-severity issue into the matching [`TodoValidationError`]
+    ///
+    /// Returns `None` for warnings and info issues, and for errors whose
+    /// [`code`](Self::code) has no corresponding `TodoValidationError` variant.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_error(&self) -> Option<TodoValidationError> {
+        if self.severity != IssueSeverity::Error {
+            return None;
+        }
+
+        let id = self.todo_id.clone().unwrap_or_default();
+        match self.code.clone()? {
+            IssueCode::NotActionable { content } => {
+                Some(TodoValidationError::NotActionable { content })
+            }
+            IssueCode::MissingEstimate => Some(TodoValidationError::MissingEstimate { id }),
+            IssueCode::CircularDependency { cycle } => {
+                Some(TodoValidationError::CircularDependency { cycle })
+            }
+            IssueCode::DependencyNotFound { dependency } => {
+                Some(TodoValidationError::DependencyNotFound { id, dependency })
+            }
+            IssueCode::CountLimit { count, limit } => {
+                Some(TodoValidationError::CountLimit { count, limit })
+            }
+            IssueCode::ComplexityExceeded { .. } => None,
+        }
+    }
+}
+
 impl std::fmt::Display for IssueSeverity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -705,6 +1657,7 @@ impl std::fmt::Display for IssueCategory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::todo::Link;
     // Test imports handled by parent module
 
     #[test]
@@ -715,7 +1668,7 @@ mod tests {
         todo.estimated_hours = Some(4.0);
 
         let mut issues = Vec::new();
-        validator.validate_todo(&todo, &mut issues);
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
 
         // Should have no errors for a well-formed todo
         assert_eq!(
@@ -733,7 +1686,7 @@ mod tests {
 
         let todo = Todo::new("User authentication stuff");
         let mut issues = Vec::new();
-        validator.validate_todo(&todo, &mut issues);
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
 
         assert!(issues
             .iter()
@@ -790,6 +1743,685 @@ mod tests {
             .any(|i| i.category == IssueCategory::Dependencies));
     }
 
+    #[test]
+    fn test_completed_todo_with_pending_dependency() {
+        let validator = TodoValidator::new();
+
+        let mut todo_list = TodoList::new();
+        let mut todo1 = Todo::new("Implement authentication system");
+        todo1.id = "task1".to_string();
+        todo1.estimated_hours = Some(4.0);
+
+        let mut todo2 = Todo::new("Create user interface");
+        todo2.id = "task2".to_string();
+        todo2.estimated_hours = Some(4.0);
+        todo2.status = TodoStatus::Completed;
+        todo2.dependencies = vec!["task1".to_string()];
+
+        todo_list.add_todo(todo1);
+        todo_list.add_todo(todo2);
+
+        let result = validator.validate_todo_list(&todo_list);
+        assert!(!result.is_valid);
+        assert!(result.issues.iter().any(|i| {
+            i.severity == IssueSeverity::Error
+                && i.category == IssueCategory::Dependencies
+                && i.todo_id.as_deref() == Some("task2")
+        }));
+
+        // Once the prerequisite is completed the list is consistent again
+        todo_list.todos[0].status = TodoStatus::Completed;
+        let result = validator.validate_todo_list(&todo_list);
+        assert!(result.is_valid);
+    }
+
+    #[test]
+    fn test_duplicate_dependency_detection() {
+        let validator = TodoValidator::new();
+
+        let mut todo_list = TodoList::new();
+        let mut todo1 = Todo::new("Implement authentication system");
+        todo1.id = "task1".to_string();
+        todo1.estimated_hours = Some(4.0);
+
+        let mut todo2 = Todo::new("Create user interface");
+        todo2.id = "task2".to_string();
+        todo2.estimated_hours = Some(4.0);
+        todo2.dependencies = vec!["task1".to_string(), "task1".to_string()];
+
+        todo_list.add_todo(todo1);
+        todo_list.add_todo(todo2);
+
+        let result = validator.validate_todo_list(&todo_list);
+        let duplicates: Vec<_> = result
+            .issues
+            .iter()
+            .filter(|i| i.severity == IssueSeverity::Info && i.message.contains("more than once"))
+            .collect();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].todo_id.as_deref(), Some("task2"));
+        assert!(result.is_valid);
+
+        todo_list.todos[1].dedup_dependencies();
+        assert_eq!(todo_list.todos[1].dependencies, vec!["task1".to_string()]);
+
+        let result = validator.validate_todo_list(&todo_list);
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.message.contains("more than once")));
+    }
+
+    #[test]
+    fn test_blocked_todo_requires_reason() {
+        let validator = TodoValidator::new();
+
+        let mut todo = Todo::new("Implement payment gateway integration");
+        todo.estimated_hours = Some(4.0);
+        todo.status = TodoStatus::Blocked;
+
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        assert!(issues
+            .iter()
+            .any(|i| i.severity == IssueSeverity::Error && i.message.contains("blocked reason")));
+
+        todo.block("Waiting for merchant account approval");
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        assert!(!issues.iter().any(|i| i.message.contains("blocked reason")));
+    }
+
+    #[test]
+    fn test_issue_into_error() {
+        let validator = TodoValidator::new();
+
+        let mut todo = Todo::new("User authentication stuff");
+        todo.id = "todo1".to_string();
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+
+        let actionability = issues
+            .iter()
+            .find(|i| i.category == IssueCategory::Actionability)
+            .unwrap();
+        match actionability.into_error() {
+            Some(TodoValidationError::NotActionable { content }) => {
+                assert_eq!(content, "User authentication stuff");
+            }
+            other => panic!("unexpected conversion: {:?}", other),
+        }
+
+        let missing_estimate = issues
+            .iter()
+            .find(|i| i.category == IssueCategory::TimeEstimate)
+            .unwrap();
+        assert!(matches!(
+            missing_estimate.into_error(),
+            Some(TodoValidationError::MissingEstimate { id }) if id == "todo1"
+        ));
+
+        // Conversion reads the structured code, not the display message
+        let mut reworded = missing_estimate.clone();
+        reworded.message = "Estimate required".to_string();
+        assert!(matches!(
+            reworded.into_error(),
+            Some(TodoValidationError::MissingEstimate { .. })
+        ));
+        reworded.code = None;
+        assert!(reworded.into_error().is_none());
+
+        // Warnings are not converted
+        let warning = issues
+            .iter()
+            .find(|i| i.severity == IssueSeverity::Warning)
+            .unwrap();
+        assert!(warning.into_error().is_none());
+    }
+
+    #[test]
+    fn test_priority_inversion_flagged() {
+        let validator = TodoValidator::new();
+
+        let mut todo_list = TodoList::new();
+        let mut cleanup = Todo::new("Refactor legacy config loader");
+        cleanup.id = "cleanup".to_string();
+        cleanup.priority = TodoPriority::Low;
+        cleanup.estimated_hours = Some(3.0);
+
+        let mut launch = Todo::new("Implement feature flag rollout");
+        launch.id = "launch".to_string();
+        launch.priority = TodoPriority::High;
+        launch.estimated_hours = Some(5.0);
+        launch.dependencies = vec!["cleanup".to_string()];
+
+        todo_list.add_todo(cleanup);
+        todo_list.add_todo(launch);
+
+        let result = validator.validate_todo_list(&todo_list);
+        let inversion = result
+            .issues
+            .iter()
+            .find(|i| i.message.starts_with("Priority inversion"))
+            .unwrap();
+        assert_eq!(inversion.todo_id.as_deref(), Some("launch"));
+        assert_eq!(inversion.severity, IssueSeverity::Warning);
+        assert!(inversion.message.contains("'cleanup' with low priority"));
+
+        todo_list.resolve_priority_inversions();
+        let result = validator.validate_todo_list(&todo_list);
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.message.starts_with("Priority inversion")));
+    }
+
+    #[test]
+    fn test_complexity_hotspots_ordered_by_overage() {
+        let config = TodoQualityConfig {
+            max_complexity_per_task: Some(1),
+            ..TodoQualityConfig::default()
+        };
+
+        let mut list = TodoList::new();
+        for (id, content) in [
+            ("refactor", "Refactor database layer"),
+            ("simple", "Write release notes"),
+            ("worst", "Integrate and optimize database security"),
+            ("analyze", "Analyze algorithm performance"),
+        ] {
+            let mut todo = Todo::new(content);
+            todo.id = id.to_string();
+            list.add_todo(todo);
+        }
+
+        let result = TodoValidator::with_config(config).validate_todo_list(&list);
+        let hotspots: Vec<&str> = result
+            .complexity_hotspots()
+            .iter()
+            .filter_map(|issue| issue.todo_id.as_deref())
+            .collect();
+
+        assert_eq!(hotspots, vec!["worst", "analyze", "refactor"]);
+        let worst = result.complexity_hotspots()[0];
+        let score = list.todos[2].complexity_score();
+        assert_eq!(
+            worst.code,
+            Some(IssueCode::ComplexityExceeded { score, max: 1 })
+        );
+        assert_eq!(complexity_overage(worst), score - 1);
+    }
+
+    #[test]
+    fn test_custom_complexity_model() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Install plumbing in the kitchen");
+        todo.id = "plumbing".to_string();
+        todo.estimated_hours = Some(4.0);
+        list.add_todo(todo);
+
+        let default_result = TodoValidator::new().validate_todo_list(&list);
+        assert!(default_result.complexity_hotspots().is_empty());
+
+        let model = ComplexityModel {
+            keywords: vec!["plumbing".to_string()],
+            keyword_weight: 9,
+            ..ComplexityModel::default()
+        };
+        let result = TodoValidator::new()
+            .with_complexity_model(model)
+            .validate_todo_list(&list);
+        assert_eq!(result.complexity_hotspots().len(), 1);
+        assert!((result.metrics.avg_complexity - 10.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_tag_vocabulary() {
+        let mut list = TodoList::new();
+        let mut todo = Todo::new("Implement login form layout");
+        todo.id = "login".to_string();
+        todo.tags = vec!["frontend".to_string(), "front-end".to_string()];
+        list.add_todo(todo);
+
+        let unrestricted = TodoValidator::new().validate_todo_list(&list);
+        assert!(!unrestricted
+            .issues
+            .iter()
+            .any(|i| i.message.contains("tag vocabulary")));
+
+        let result = TodoValidator::new()
+            .with_tag_vocabulary(["backend", "frontend", "infra"])
+            .validate_todo_list(&list);
+        let flagged: Vec<_> = result
+            .issues
+            .iter()
+            .filter(|i| i.message.contains("tag vocabulary"))
+            .collect();
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].severity, IssueSeverity::Warning);
+        assert_eq!(flagged[0].todo_id.as_deref(), Some("login"));
+        assert_eq!(
+            flagged[0].message,
+            "Tag 'front-end' is not in the tag vocabulary"
+        );
+        assert_eq!(
+            flagged[0].suggestion.as_deref(),
+            Some("Use the tag 'frontend' instead")
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("front-end", "frontend"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "ui"), 2);
+    }
+
+    #[test]
+    fn test_deep_critical_todo_flagged() {
+        let config = TodoQualityConfig {
+            max_priority_depth: Some(4),
+            ..TodoQualityConfig::default()
+        };
+        let validator = TodoValidator::with_config(config);
+
+        let mut todo_list = TodoList::new();
+        for level in 1..=5 {
+            let mut todo = Todo::new(format!("Implement stage {} of the pipeline", level));
+            todo.id = format!("stage_{}", level);
+            todo.estimated_hours = Some(2.0);
+            if level > 1 {
+                todo.dependencies = vec![format!("stage_{}", level - 1)];
+            }
+            todo.priority = match level {
+                5 => TodoPriority::Critical,
+                4 => TodoPriority::High,
+                _ => TodoPriority::Medium,
+            };
+            todo_list.add_todo(todo);
+        }
+
+        let result = validator.validate_todo_list(&todo_list);
+
+        let flagged: Vec<&ValidationIssue> = result
+            .issues
+            .iter()
+            .filter(|i| i.message.contains("dependency depth"))
+            .collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].todo_id.as_deref(), Some("stage_5"));
+        assert_eq!(flagged[0].severity, IssueSeverity::Warning);
+        assert_eq!(
+            flagged[0].message,
+            "Todo with critical priority is at dependency depth 5 (max 4)"
+        );
+
+        // Depth is unchecked unless configured
+        let result = TodoValidator::new().validate_todo_list(&todo_list);
+        assert!(!result
+            .issues
+            .iter()
+            .any(|i| i.message.contains("dependency depth")));
+    }
+
+    #[test]
+    fn test_validate_incremental_reuses_unchanged_todos() {
+        let validator = TodoValidator::new();
+        let mut todo_list = TodoList::new();
+        let mut stable = Todo::new("stuff");
+        stable.id = "stable".to_string();
+        let mut edited = Todo::new("things");
+        edited.id = "edited".to_string();
+        todo_list.add_todo(stable);
+        todo_list.add_todo(edited);
+
+        let mut previous = validator.validate_todo_list(&todo_list);
+        // Mark the cached issues so reuse is observable
+        for cached in previous.todo_issues.values_mut().flatten() {
+            cached.message = format!("cached: {}", cached.message);
+        }
+
+        todo_list.todos[1].content = "Implement checkout flow for guest users".to_string();
+        todo_list.todos[1].estimated_hours = Some(4.0);
+        let result = validator.validate_incremental(&todo_list, &previous);
+
+        let messages = |id: &str| -> Vec<&str> {
+            result
+                .issues
+                .iter()
+                .filter(|i| i.todo_id.as_deref() == Some(id))
+                .map(|i| i.message.as_str())
+                .collect()
+        };
+        assert!(!messages("stable").is_empty());
+        assert!(messages("stable").iter().all(|m| m.starts_with("cached: ")));
+        assert!(messages("edited")
+            .iter()
+            .all(|m| !m.starts_with("cached: ")));
+
+        // Apart from the reused issues, the result matches a full run
+        let full = validator.validate_todo_list(&todo_list);
+        let strip = |issues: &[ValidationIssue]| -> Vec<String> {
+            issues
+                .iter()
+                .map(|i| i.message.trim_start_matches("cached: ").to_string())
+                .collect()
+        };
+        assert_eq!(strip(&result.issues), strip(&full.issues));
+        assert_eq!(result.is_valid, full.is_valid);
+    }
+
+    #[test]
+    fn test_custom_action_verbs() {
+        let mut todo_list = TodoList::new();
+        let mut todo = Todo::new("Implementar autenticación de usuarios");
+        todo.estimated_hours = Some(4.0);
+        todo_list.add_todo(todo);
+        let mut todo = Todo::new("Crear esquema de base de datos");
+        todo.estimated_hours = Some(2.0);
+        todo_list.add_todo(todo);
+        let not_actionable = |result: &TodoValidationResult| {
+            result
+                .issues
+                .iter()
+                .filter(|i| i.category == IssueCategory::Actionability)
+                .count()
+        };
+
+        let spanish = ["implementar", "crear"];
+        assert!(todo_list
+            .todos
+            .iter()
+            .all(|t| t.is_actionable_with(&spanish)));
+        assert!(todo_list.todos.iter().any(|t| !t.is_actionable()));
+
+        let default_result = TodoValidator::new().validate_todo_list(&todo_list);
+        assert_eq!(not_actionable(&default_result), 1);
+
+        let validator = TodoValidator::new().with_action_verbs(spanish);
+        let result = validator.validate_todo_list(&todo_list);
+        assert_eq!(not_actionable(&result), 0);
+        assert_eq!(result.metrics.actionable_count, 2);
+
+        let mut issues = Vec::new();
+        validator.validate_content("t", "Implement login", &mut issues);
+        assert_eq!(
+            issues[0].suggestion.as_deref(),
+            Some("Start with one of: 'implementar', 'crear'")
+        );
+    }
+
+    #[test]
+    fn test_over_decomposition_flagged() {
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            min_useful_estimate_hours: Some(1.0),
+            max_tiny_todo_fraction: Some(0.5),
+            ..TodoQualityConfig::default()
+        });
+        let over_decomposed = |todo_list: &TodoList| {
+            validator
+                .validate_todo_list(todo_list)
+                .issues
+                .into_iter()
+                .find(|i| i.message.contains("over-decomposed"))
+        };
+
+        let mut todo_list = TodoList::new();
+        for (step, hours) in [0.25, 0.5, 0.75, 0.5, 0.25, 0.5, 0.75, 3.0]
+            .iter()
+            .enumerate()
+        {
+            let mut todo = Todo::new(format!("Update config value number {}", step));
+            todo.estimated_hours = Some(*hours);
+            todo_list.add_todo(todo);
+        }
+        let issue = over_decomposed(&todo_list).unwrap();
+        assert_eq!(issue.severity, IssueSeverity::Info);
+        assert_eq!(issue.category, IssueCategory::Structure);
+        assert_eq!(
+            issue.message,
+            "7 of 8 estimated todos are under 1.0h; the plan may be over-decomposed"
+        );
+        assert!(!TodoValidator::new()
+            .validate_todo_list(&todo_list)
+            .issues
+            .iter()
+            .any(|i| i.message.contains("over-decomposed")));
+
+        // A few small todos among larger ones are fine
+        for todo in todo_list.todos.iter_mut().take(5) {
+            todo.estimated_hours = Some(4.0);
+        }
+        assert!(over_decomposed(&todo_list).is_none());
+    }
+
+    #[test]
+    fn test_critical_path_length_limit() {
+        let mut todo_list = TodoList::new();
+        for step in 1..=6 {
+            let mut todo = Todo::new(format!("Implement step {} of the rollout", step));
+            todo.id = format!("step_{}", step);
+            todo.estimated_hours = Some(2.0);
+            if step > 1 {
+                todo.dependencies = vec![format!("step_{}", step - 1)];
+            }
+            todo_list.add_todo(todo);
+        }
+        let is_flagged = |result: &TodoValidationResult| {
+            result
+                .issues
+                .iter()
+                .any(|i| i.message.starts_with("Critical path has"))
+        };
+
+        // Off by default
+        assert!(!is_flagged(
+            &TodoValidator::new().validate_todo_list(&todo_list)
+        ));
+
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            max_critical_path_length: Some(4),
+            ..TodoQualityConfig::default()
+        });
+        let result = validator.validate_todo_list(&todo_list);
+        let issue = result
+            .issues
+            .iter()
+            .find(|i| i.message.starts_with("Critical path has"))
+            .unwrap();
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+        assert_eq!(issue.category, IssueCategory::Dependencies);
+        assert_eq!(
+            issue.message,
+            "Critical path has 6 todos (max 4): step_1 -> step_2 -> step_3 -> step_4 -> step_5 -> step_6"
+        );
+
+        let relaxed = TodoValidator::with_config(TodoQualityConfig {
+            max_critical_path_length: Some(6),
+            ..TodoQualityConfig::default()
+        });
+        assert!(!is_flagged(&relaxed.validate_todo_list(&todo_list)));
+    }
+
+    #[test]
+    fn test_estimate_granule() {
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            estimate_granule: Some(0.5),
+            ..TodoQualityConfig::default()
+        });
+
+        let mut todo = Todo::new("Implement audit log export");
+        todo.estimated_hours = Some(2.3);
+
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        let issue = issues
+            .iter()
+            .find(|i| i.message.contains("not a multiple"))
+            .unwrap();
+        assert_eq!(issue.severity, IssueSeverity::Info);
+        assert_eq!(
+            issue.message,
+            "Time estimate 2.3h is not a multiple of 0.5h"
+        );
+        assert!(issue.suggestion.as_ref().unwrap().contains("2.5h"));
+
+        todo.estimated_hours = Some(2.5);
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        assert!(!issues.iter().any(|i| i.message.contains("not a multiple")));
+    }
+
+    #[test]
+    fn test_estimate_confidence_range() {
+        let validator = TodoValidator::new();
+
+        let mut todo = Todo::new("Implement rate limiting middleware");
+        todo.estimated_hours = Some(4.0);
+        todo.estimate_confidence = Some(0.8);
+
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        assert!(!issues.iter().any(|i| i.message.contains("confidence")));
+
+        for invalid in [-0.1, 1.5, f32::NAN] {
+            todo.estimate_confidence = Some(invalid);
+            let mut issues = Vec::new();
+            validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+            assert!(issues
+                .iter()
+                .any(|i| i.severity == IssueSeverity::Error && i.message.contains("confidence")));
+        }
+    }
+
+    #[test]
+    fn test_estimates_match_input() {
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            require_time_estimates: false,
+            ..TodoQualityConfig::default()
+        });
+        let mut todo_list = TodoList::new();
+        let mut estimated = Todo::new("Implement export to CSV");
+        estimated.estimated_hours = Some(3.0);
+        todo_list.add_todo(estimated);
+        todo_list.add_todo(Todo::new("Implement import from CSV"));
+
+        let mismatches = |include_estimates| {
+            let input = TodoInput {
+                include_estimates,
+                ..TodoInput::default()
+            };
+            validator
+                .validate_for_input(&todo_list, &input)
+                .issues
+                .into_iter()
+                .filter(|i| i.message.contains("requested"))
+                .collect::<Vec<_>>()
+        };
+
+        let issues = mismatches(false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].todo_id.as_deref(),
+            Some(todo_list.todos[0].id.as_str())
+        );
+        assert_eq!(
+            issues[0].message,
+            "Todo has a 3.0h estimate but estimates were not requested"
+        );
+
+        let issues = mismatches(true);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].todo_id.as_deref(),
+            Some(todo_list.todos[1].id.as_str())
+        );
+    }
+
+    #[test]
+    fn test_link_urls_validated() {
+        let validator = TodoValidator::new();
+        let mut todo = Todo::new("Implement dashboard widgets");
+        todo.estimated_hours = Some(3.0);
+        todo.links = vec![
+            Link::new("Design doc", "https://docs.example.com/dashboard"),
+            Link::new("Ticket", "ftp://tracker.example.com/42"),
+            Link::new("Local", "file:///tmp/notes"),
+        ];
+
+        let mut issues = Vec::new();
+        validator.validate_todo(&todo, EstimateUnit::Hours, &mut issues);
+        let flagged: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.message.contains("invalid URL"))
+            .map(|i| i.message.as_str())
+            .collect();
+
+        assert_eq!(
+            flagged,
+            vec![
+                "Link 'Ticket' has invalid URL 'ftp://tracker.example.com/42'",
+                "Link 'Local' has invalid URL 'file:///tmp/notes'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_uniform_estimates_flagged() {
+        let validator = TodoValidator::new();
+        let uniform = |result: &TodoValidationResult| {
+            result.issues.iter().any(|i| {
+                i.severity == IssueSeverity::Info && i.message.starts_with("All 6 estimates")
+            })
+        };
+
+        let mut todo_list = TodoList::new();
+        for i in 0..6 {
+            let mut todo = Todo::new(format!("Implement report number {}", i));
+            todo.estimated_hours = Some(4.0);
+            todo_list.add_todo(todo);
+        }
+        assert!(uniform(&validator.validate_todo_list(&todo_list)));
+
+        for (todo, hours) in todo_list
+            .todos
+            .iter_mut()
+            .zip([1.0, 2.0, 3.5, 4.0, 6.0, 8.0])
+        {
+            todo.estimated_hours = Some(hours);
+        }
+        assert!(!uniform(&validator.validate_todo_list(&todo_list)));
+    }
+
+    #[test]
+    fn test_flat_list_warning() {
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            max_flat_list_size: Some(8),
+            ..TodoQualityConfig::default()
+        });
+        let mut todo_list = TodoList::new();
+        for i in 0..10 {
+            let mut todo = Todo::new(format!("Implement report number {}", i));
+            todo.estimated_hours = Some(2.0);
+            todo_list.add_todo(todo);
+        }
+
+        let flat_warning = |result: &TodoValidationResult| {
+            result.issues.iter().any(|i| {
+                i.severity == IssueSeverity::Warning && i.message.contains("flat list above 8")
+            })
+        };
+        assert!(flat_warning(&validator.validate_todo_list(&todo_list)));
+        assert!(!flat_warning(
+            &TodoValidator::new().validate_todo_list(&todo_list)
+        ));
+
+        let first_id = todo_list.todos[0].id.clone();
+        todo_list.todos[1].dependencies.push(first_id);
+        assert!(!flat_warning(&validator.validate_todo_list(&todo_list)));
+    }
+
     #[test]
     fn test_quality_metrics_calculation() {
         let validator = TodoValidator::new();
@@ -813,6 +2445,243 @@ mod tests {
         assert!((result.metrics.total_estimated_hours - 3.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_custom_suggestion_provider() {
+        #[derive(Debug)]
+        struct WikiProvider;
+
+        impl SuggestionProvider for WikiProvider {
+            fn suggest(&self, kind: &SuggestionKind) -> Option<String> {
+                match kind {
+                    SuggestionKind::MissingEstimates { count } => {
+                        Some(format!("Estimate {} todos - see wiki/estimating", count))
+                    }
+                    _ => DefaultSuggestionProvider.suggest(kind),
+                }
+            }
+        }
+
+        let validator = TodoValidator::new().with_suggestion_provider(Arc::new(WikiProvider));
+
+        let mut todo_list = TodoList::new();
+        todo_list.add_todo(Todo::new("Implement user login endpoint"));
+
+        let result = validator.validate_todo_list(&todo_list);
+
+        assert!(result
+            .suggestions
+            .contains(&"Estimate 1 todos - see wiki/estimating".to_string()));
+        assert!(!result
+            .suggestions
+            .iter()
+            .any(|s| s.contains("for better project planning")));
+    }
+
+    #[test]
+    fn test_vague_content_flagged() {
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            min_meaningful_tokens: Some(3),
+            ..TodoQualityConfig::default()
+        });
+        let is_vague = |content: &str| {
+            let mut issues = Vec::new();
+            validator.validate_content("t", content, &mut issues);
+            issues
+                .iter()
+                .any(|i| i.severity == IssueSeverity::Warning && i.message.contains("too vague"))
+        };
+
+        assert!(is_vague("Fix the bug now okay"));
+        assert!(!is_vague("Fix race condition in session cache eviction"));
+
+        // Unchecked by default
+        let mut issues = Vec::new();
+        TodoValidator::new().validate_content("t", "Fix the bug now okay", &mut issues);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_estimate_bounds_follow_unit() {
+        let validator = TodoValidator::new();
+        let mut todo_list = TodoList::new();
+        let mut todo = Todo::new("Implement checkout flow");
+        todo.estimated_hours = Some(8.0);
+        todo_list.add_todo(todo);
+        let mut large = Todo::new("Implement payment provider integration");
+        large.estimated_hours = Some(20.0);
+        todo_list.add_todo(large);
+
+        let in_hours = validator.validate_todo_list(&todo_list);
+        assert!(!in_hours
+            .issues
+            .iter()
+            .any(|i| i.category == IssueCategory::TimeEstimate));
+
+        todo_list.metadata.estimate_unit = EstimateUnit::Points;
+        todo_list.update_metadata();
+        let in_points = validator.validate_todo_list(&todo_list);
+        let estimate_issues: Vec<_> = in_points
+            .issues
+            .iter()
+            .filter(|i| i.category == IssueCategory::TimeEstimate)
+            .collect();
+
+        assert_eq!(estimate_issues.len(), 1);
+        assert_eq!(
+            estimate_issues[0].message,
+            "Time estimate 20.0pt exceeds maximum 13.0pt"
+        );
+    }
+
+    #[test]
+    fn test_quality_gates_follow_issues() {
+        let validator = TodoValidator::new();
+
+        let mut todo_list = TodoList::new();
+        let mut good = Todo::new("Implement user authentication endpoint");
+        good.id = "good".to_string();
+        good.estimated_hours = Some(4.0);
+        let mut vague = Todo::new("stuff");
+        vague.id = "vague".to_string();
+        vague.estimated_hours = Some(100.0);
+        todo_list.add_todo(good);
+        todo_list.add_todo(vague);
+
+        let result = validator.validate_todo_list(&todo_list);
+
+        let good_gates = &result.quality_gates["good"];
+        assert!(good_gates.actionability_check && good_gates.time_estimate_check);
+        assert!(good_gates.complexity_check && good_gates.completeness_check);
+        let vague_gates = &result.quality_gates["vague"];
+        assert!(!vague_gates.actionability_check);
+        assert!(!vague_gates.time_estimate_check);
+        assert!((result.metrics.quality_gate_pass_rate - 0.5).abs() < f32::EPSILON);
+
+        todo_list.todos[1]
+            .quality_gates
+            .custom_checks
+            .insert("reviewed".to_string(), true);
+        result.apply_quality_gates(&mut todo_list);
+        assert!(todo_list.todos[0].quality_gates.actionability_check);
+        assert!(!todo_list.todos[1].quality_gates.actionability_check);
+        assert!(!todo_list.todos[1].quality_gates.time_estimate_check);
+        assert_eq!(
+            todo_list.todos[1]
+                .quality_gates
+                .custom_checks
+                .get("reviewed"),
+            Some(&true)
+        );
+    }
+
+    #[test]
+    fn test_quality_score_weights() {
+        let mut todo_list = TodoList::new();
+        let mut good = Todo::new("Implement user authentication endpoint");
+        good.estimated_hours = Some(4.0);
+        todo_list.add_todo(good);
+        todo_list.add_todo(Todo::new("stuff"));
+
+        let default = TodoValidator::new().validate_todo_list(&todo_list);
+        let metrics = &default.metrics;
+        assert!(default.quality_score > 0.0 && default.quality_score < 1.0);
+
+        // Only actionability counts: half the todos are actionable
+        let actionability_only =
+            TodoValidator::new().with_quality_score_weights(QualityScoreWeights {
+                actionability: 1.0,
+                length: 0.0,
+                complexity: 0.0,
+                estimates: 0.0,
+                dependencies: 0.0,
+            });
+        assert!((actionability_only.quality_score(metrics) - 0.5).abs() < f32::EPSILON);
+
+        // Only the cycle-free graph counts
+        let dependencies_only =
+            TodoValidator::new().with_quality_score_weights(QualityScoreWeights {
+                actionability: 0.0,
+                length: 0.0,
+                complexity: 0.0,
+                estimates: 0.0,
+                dependencies: 1.0,
+            });
+        assert!((dependencies_only.quality_score(metrics) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_quality_score_weights_normalized() {
+        let doubled = QualityScoreWeights {
+            actionability: 0.6,
+            length: 0.4,
+            complexity: 0.4,
+            estimates: 0.4,
+            dependencies: 0.2,
+        };
+        let normalized = doubled.normalized();
+        let default = QualityScoreWeights::default();
+        assert!((normalized.actionability - default.actionability).abs() < 1e-6);
+        assert!((normalized.dependencies - default.dependencies).abs() < 1e-6);
+
+        let mut todo_list = TodoList::new();
+        todo_list.add_todo(Todo::new("Implement user authentication endpoint"));
+        todo_list.add_todo(Todo::new("stuff"));
+        let metrics = TodoValidator::new().validate_todo_list(&todo_list).metrics;
+        let scaled = TodoValidator::new()
+            .with_quality_score_weights(doubled)
+            .quality_score(&metrics);
+        assert!((scaled - TodoValidator::new().quality_score(&metrics)).abs() < 1e-6);
+
+        let zero = QualityScoreWeights {
+            actionability: 0.0,
+            length: 0.0,
+            complexity: 0.0,
+            estimates: 0.0,
+            dependencies: 0.0,
+        };
+        assert_eq!(zero.normalized(), default);
+    }
+
+    #[test]
+    fn test_oversized_custom_fields_flagged() {
+        let mut todo = Todo::new("Implement audit log export endpoint");
+        todo.id = "audit".to_string();
+        todo.custom_fields.insert(
+            "payload".to_string(),
+            serde_json::json!({"a": {"b": {"c": {"d": {"e": {"f": 1}}}}}}),
+        );
+        todo.custom_fields
+            .insert("ticket".to_string(), serde_json::json!({"id": 42}));
+
+        // Unchecked unless configured
+        let mut issues = Vec::new();
+        TodoValidator::new().validate_custom_fields(&todo, &mut issues);
+        assert!(issues.is_empty());
+
+        let validator = TodoValidator::with_config(TodoQualityConfig {
+            max_custom_field_depth: Some(5),
+            ..TodoQualityConfig::default()
+        });
+        let mut issues = Vec::new();
+        validator.validate_custom_fields(&todo, &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Custom field 'payload' is nested 6 levels deep (max 5)"
+        );
+        assert_eq!(issues[0].category, IssueCategory::Structure);
+
+        let small = TodoValidator::with_config(TodoQualityConfig {
+            max_custom_fields_bytes: Some(32),
+            ..TodoQualityConfig::default()
+        });
+        let mut issues = Vec::new();
+        small.validate_custom_fields(&todo, &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.starts_with("Custom fields take "));
+        assert!(issues[0].message.ends_with(" bytes serialized (max 32)"));
+    }
+
     #[test]
     fn test_suggestion_generation() {
         let validator = TodoValidator::new();
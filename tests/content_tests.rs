@@ -96,6 +96,10 @@ fn test_content_format_parsing() {
         ContentFormat::from_str("markdown").unwrap(),
         ContentFormat::Markdown
     );
+    assert_eq!(
+        ContentFormat::from_str("toml").unwrap(),
+        ContentFormat::Toml
+    );
     assert!(ContentFormat::from_str("unknown").is_err());
 
     assert_eq!(ContentFormat::Yaml.to_string(), "yaml");
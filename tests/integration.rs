@@ -15,8 +15,10 @@ async fn test_basic_todo_generation() {
         project_context: None,
         quality_config: None,
         max_todos: Some(10),
+        max_requirements: None,
         include_estimates: true,
         default_priority: None,
+        id_scheme: pdmt::models::todo::TodoIdScheme::default(),
     };
 
     let result = engine.generate("todo_list", input).await.unwrap();
@@ -39,3 +41,25 @@ async fn test_deterministic_generation() {
 
     assert_eq!(result1.content, result2.content);
 }
+
+#[tokio::test]
+async fn test_empty_requirements_rejected() {
+    let mut engine = TemplateEngine::new();
+    engine.load_builtin_templates().await.unwrap();
+
+    let input = TodoInput {
+        project_name: "Empty".to_string(),
+        requirements: Vec::new(),
+        ..TodoInput::default()
+    };
+
+    let err = engine.generate("todo_list", input).await.unwrap_err();
+    assert!(matches!(
+        &err,
+        pdmt::Error::InvalidInput(message) if message == "requirements cannot be empty"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Invalid input: requirements cannot be empty"
+    );
+}
@@ -21,8 +21,10 @@ async fn test_template_engine_full_workflow() {
         project_context: None,
         quality_config: None,
         max_todos: Some(10),
+        max_requirements: None,
         include_estimates: true,
         default_priority: Some(pdmt::models::todo::TodoPriority::High),
+        id_scheme: pdmt::models::todo::TodoIdScheme::default(),
     };
 
     // Generate content
@@ -56,6 +58,25 @@ async fn test_custom_template_registration() {
     assert!(result.content.contains("95"));
 }
 
+#[tokio::test]
+async fn test_commented_template_output_is_byte_stable() {
+    let mut engine = TemplateEngine::new();
+
+    let mut template = TemplateDefinition::new(
+        "commented",
+        "1.0.0",
+        "{{!-- Loaded from a front-matter file --}}\n{{! owner: docs }}\nHello {{name}}!\n{{! footer }}\nBye",
+    );
+    template.strip_comments(true);
+    engine.register_template(template).unwrap();
+
+    let result = engine
+        .generate("commented", json!({"name": "Alice"}))
+        .await
+        .unwrap();
+    assert_eq!(result.content, "Hello Alice!\nBye");
+}
+
 #[test]
 fn test_template_definition_validation() {
     // Valid template
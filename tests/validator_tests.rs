@@ -14,6 +14,15 @@ fn test_validator_with_custom_config() {
         prevent_circular_dependencies: false,
         min_estimated_hours: Some(1.0),
         max_estimated_hours: Some(20.0),
+        max_priority_depth: None,
+        estimate_granule: None,
+        max_flat_list_size: None,
+        min_meaningful_tokens: None,
+        max_critical_path_length: None,
+        min_useful_estimate_hours: None,
+        max_tiny_todo_fraction: None,
+        max_custom_field_depth: None,
+        max_custom_fields_bytes: None,
     };
 
     let validator = TodoValidator::with_config(config);